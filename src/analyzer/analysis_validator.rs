@@ -0,0 +1,360 @@
+//! Structural validation of analysis JSON files
+//!
+//! Checks a deserialized [`CliAnalysis`] for internal consistency so that
+//! hand-edited analysis files can be gated in CI before test generation.
+
+use crate::types::analysis::{
+    count_options, count_subcommands, CliAnalysis, CliOption, OptionRelation, OptionType,
+};
+use crate::types::Subcommand;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A single problem found in an analysis file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalysisIssue {
+    /// JSON path of the offending value (e.g. `$.subcommands[0].options[2].long`)
+    pub path: String,
+
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl AnalysisIssue {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for AnalysisIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validate a CLI analysis and collect every problem found
+///
+/// Checks performed:
+/// - `metadata.total_subcommands` / `metadata.total_options` match the actual tree
+/// - `Subcommand.depth` equals the nesting level (0 = top-level)
+/// - No duplicate short/long flags within the same option scope
+/// - `Enum` options declare at least one value
+/// - `Numeric` options do not have `min > max`
+/// - Option relations only name options of their own command
+///
+/// # Examples
+///
+/// ```
+/// use cli_testing_specialist::analyzer::validate_analysis;
+/// use cli_testing_specialist::types::CliAnalysis;
+/// use std::path::PathBuf;
+///
+/// let analysis = CliAnalysis::new(
+///     PathBuf::from("/usr/bin/tool"),
+///     "tool".to_string(),
+///     String::new(),
+/// );
+/// assert!(validate_analysis(&analysis).is_empty());
+/// ```
+pub fn validate_analysis(analysis: &CliAnalysis) -> Vec<AnalysisIssue> {
+    let mut issues = Vec::new();
+
+    validate_metadata(analysis, &mut issues);
+    validate_options(&analysis.global_options, "$.global_options", &mut issues);
+    validate_relations(
+        &analysis.option_relations,
+        &analysis.global_options,
+        "$.option_relations",
+        &mut issues,
+    );
+    validate_subcommands(&analysis.subcommands, "$.subcommands", 0, &mut issues);
+
+    issues
+}
+
+/// Check metadata counters against the actual subcommand/option tree
+fn validate_metadata(analysis: &CliAnalysis, issues: &mut Vec<AnalysisIssue>) {
+    let actual_subcommands = count_subcommands(&analysis.subcommands);
+    if analysis.metadata.total_subcommands != actual_subcommands {
+        issues.push(AnalysisIssue::new(
+            "$.metadata.total_subcommands",
+            format!(
+                "declares {} subcommands but {} were found",
+                analysis.metadata.total_subcommands, actual_subcommands
+            ),
+        ));
+    }
+
+    let actual_options = analysis.global_options.len() + count_options(&analysis.subcommands);
+    if analysis.metadata.total_options != actual_options {
+        issues.push(AnalysisIssue::new(
+            "$.metadata.total_options",
+            format!(
+                "declares {} options but {} were found",
+                analysis.metadata.total_options, actual_options
+            ),
+        ));
+    }
+}
+
+/// Recursively validate subcommands (depth consistency and their options)
+fn validate_subcommands(
+    subcommands: &[Subcommand],
+    path: &str,
+    expected_depth: u8,
+    issues: &mut Vec<AnalysisIssue>,
+) {
+    for (index, subcommand) in subcommands.iter().enumerate() {
+        let sub_path = format!("{}[{}]", path, index);
+
+        if subcommand.depth != expected_depth {
+            issues.push(AnalysisIssue::new(
+                format!("{}.depth", sub_path),
+                format!(
+                    "subcommand '{}' has depth {} but is nested at depth {}",
+                    subcommand.name, subcommand.depth, expected_depth
+                ),
+            ));
+        }
+
+        validate_options(
+            &subcommand.options,
+            &format!("{}.options", sub_path),
+            issues,
+        );
+        validate_relations(
+            &subcommand.option_relations,
+            &subcommand.options,
+            &format!("{}.option_relations", sub_path),
+            issues,
+        );
+
+        validate_subcommands(
+            &subcommand.subcommands,
+            &format!("{}.subcommands", sub_path),
+            expected_depth.saturating_add(1),
+            issues,
+        );
+    }
+}
+
+/// Validate one option scope (duplicate flags and type constraints)
+fn validate_options(options: &[CliOption], path: &str, issues: &mut Vec<AnalysisIssue>) {
+    let mut seen: HashMap<&str, String> = HashMap::new();
+
+    for (index, option) in options.iter().enumerate() {
+        let option_path = format!("{}[{}]", path, index);
+
+        for (field, flag) in [("short", &option.short), ("long", &option.long)] {
+            let Some(flag) = flag.as_deref() else {
+                continue;
+            };
+
+            let flag_path = format!("{}.{}", option_path, field);
+            match seen.get(flag) {
+                Some(first) => issues.push(AnalysisIssue::new(
+                    flag_path,
+                    format!("duplicate flag '{}' (first defined at {})", flag, first),
+                )),
+                None => {
+                    seen.insert(flag, flag_path);
+                }
+            }
+        }
+
+        match &option.option_type {
+            OptionType::Enum { values } if values.is_empty() => {
+                issues.push(AnalysisIssue::new(
+                    format!("{}.option_type.Enum.values", option_path),
+                    "enum option has no allowed values",
+                ));
+            }
+            OptionType::Numeric {
+                min: Some(min),
+                max: Some(max),
+            } if min > max => {
+                issues.push(AnalysisIssue::new(
+                    format!("{}.option_type.Numeric", option_path),
+                    format!("numeric range is empty (min {} > max {})", min, max),
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Check that option relations only name options defined in the same scope
+fn validate_relations(
+    relations: &[OptionRelation],
+    options: &[CliOption],
+    path: &str,
+    issues: &mut Vec<AnalysisIssue>,
+) {
+    for (index, relation) in relations.iter().enumerate() {
+        for name in relation.options() {
+            let defined = options
+                .iter()
+                .any(|o| o.long.as_deref() == Some(name) || o.short.as_deref() == Some(name));
+            if !defined {
+                issues.push(AnalysisIssue::new(
+                    format!("{}[{}]", path, index),
+                    format!("relation refers to unknown option '{}'", name),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::analysis::OptionSource;
+    use std::path::PathBuf;
+
+    fn option(short: Option<&str>, long: Option<&str>, option_type: OptionType) -> CliOption {
+        CliOption {
+            short: short.map(String::from),
+            long: long.map(String::from),
+            description: None,
+            option_type,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        }
+    }
+
+    fn subcommand(name: &str, depth: u8, subcommands: Vec<Subcommand>) -> Subcommand {
+        Subcommand {
+            name: name.to_string(),
+            description: None,
+            options: vec![],
+            required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
+            subcommands,
+            depth,
+        }
+    }
+
+    fn create_valid_analysis() -> CliAnalysis {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/bin/tool"),
+            "tool".to_string(),
+            "Usage: tool".to_string(),
+        );
+        analysis.global_options = vec![
+            option(Some("-v"), Some("--verbose"), OptionType::Flag),
+            option(
+                None,
+                Some("--level"),
+                OptionType::Numeric {
+                    min: Some(0),
+                    max: Some(9),
+                },
+            ),
+        ];
+        analysis.subcommands = vec![subcommand("remote", 0, vec![subcommand("add", 1, vec![])])];
+        analysis.update_metadata(0);
+        analysis
+    }
+
+    #[test]
+    fn test_valid_analysis_has_no_issues() {
+        let analysis = create_valid_analysis();
+        assert!(validate_analysis(&analysis).is_empty());
+    }
+
+    #[test]
+    fn test_metadata_count_mismatch() {
+        let mut analysis = create_valid_analysis();
+        analysis.metadata.total_subcommands = 5;
+        analysis.metadata.total_options = 0;
+
+        let issues = validate_analysis(&analysis);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "$.metadata.total_subcommands");
+        assert_eq!(issues[1].path, "$.metadata.total_options");
+    }
+
+    #[test]
+    fn test_inconsistent_depth() {
+        let mut analysis = create_valid_analysis();
+        analysis.subcommands[0].subcommands[0].depth = 3;
+
+        let issues = validate_analysis(&analysis);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "$.subcommands[0].subcommands[0].depth");
+    }
+
+    #[test]
+    fn test_duplicate_flags() {
+        let mut analysis = create_valid_analysis();
+        analysis
+            .global_options
+            .push(option(Some("-v"), Some("--version"), OptionType::Flag));
+        analysis.update_metadata(0);
+
+        let issues = validate_analysis(&analysis);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "$.global_options[2].short");
+        assert!(issues[0].message.contains("$.global_options[0].short"));
+    }
+
+    #[test]
+    fn test_empty_enum_and_inverted_range() {
+        let mut analysis = create_valid_analysis();
+        analysis.subcommands[0].options = vec![
+            option(None, Some("--mode"), OptionType::Enum { values: vec![] }),
+            option(
+                None,
+                Some("--count"),
+                OptionType::Numeric {
+                    min: Some(10),
+                    max: Some(1),
+                },
+            ),
+        ];
+        analysis.update_metadata(0);
+
+        let issues = validate_analysis(&analysis);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0].path,
+            "$.subcommands[0].options[0].option_type.Enum.values"
+        );
+        assert_eq!(
+            issues[1].path,
+            "$.subcommands[0].options[1].option_type.Numeric"
+        );
+    }
+
+    #[test]
+    fn test_relation_with_unknown_option() {
+        let mut analysis = create_valid_analysis();
+        analysis.option_relations = vec![
+            OptionRelation::Requires {
+                option: "--level".to_string(),
+                requires: "--verbose".to_string(),
+            },
+            OptionRelation::Conflicts {
+                option: "-v".to_string(),
+                with: "--quiet".to_string(),
+            },
+        ];
+
+        let issues = validate_analysis(&analysis);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "$.option_relations[1]");
+        assert!(issues[0].message.contains("'--quiet'"));
+    }
+
+    #[test]
+    fn test_issue_display() {
+        let issue = AnalysisIssue::new("$.metadata.total_options", "mismatch");
+        assert_eq!(issue.to_string(), "$.metadata.total_options: mismatch");
+    }
+}
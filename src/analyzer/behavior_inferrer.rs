@@ -234,6 +234,8 @@ mod tests {
                 description: None,
                 options: vec![],
                 required_args: vec![],
+                positional_args: vec![],
+                option_relations: vec![],
                 subcommands: vec![],
                 depth: 0,
             })
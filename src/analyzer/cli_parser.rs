@@ -1,10 +1,16 @@
-use crate::analyzer::SubcommandDetector;
+use crate::analyzer::completion::{parse_completion_script, CompletionShell, Completions};
+use crate::analyzer::framework::{detect_framework, parser_for};
+use crate::analyzer::man_page::{find_man_page, parse_man_page, read_man_page, ManPage};
+use crate::analyzer::{OptionInferrer, SubcommandDetector};
 use crate::error::{CliTestError, Result};
-use crate::types::analysis::{CliAnalysis, CliOption, OptionType};
+use crate::types::analysis::{
+    CliAnalysis, CliOption, EnvironmentVariable, OptionRelation, OptionSource, OptionType,
+    PositionalArg, Subcommand,
+};
 use crate::utils::{execute_with_timeout, validate_binary_path, ResourceLimits};
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 lazy_static! {
@@ -22,11 +28,69 @@ lazy_static! {
 
     /// Regex pattern for option description (tries to capture text after option)
     static ref OPTION_DESCRIPTION: Regex = Regex::new(r"(?:--[a-z][a-z0-9-]+)(?:\s+<[^>]+>)?\s+(.+)").unwrap();
+
+    /// Regex pattern for environment hints: [env: NAME=value] (clap), (env: NAME)
+    static ref ENV_HINT: Regex = Regex::new(r"[\[(]env:\s*([A-Za-z_][A-Za-z0-9_]*)(?:=[^\])]*)?[\])]").unwrap();
+
+    /// Regex pattern for defaults: [default: VALUE] (clap), (default: VALUE) (argparse),
+    /// (default "VALUE") / (default VALUE) (cobra)
+    static ref DEFAULT_HINT: Regex = Regex::new(r#"[\[(]default:?\s+(?:"([^"]*)"|([^\s\])]+))[\])]"#).unwrap();
+
+    /// Regex pattern for clap choices: [possible values: a, b, c]
+    static ref POSSIBLE_VALUES: Regex = Regex::new(r"\[possible values:\s*([^\]]+)\]").unwrap();
+
+    /// Regex pattern for one entry of clap's multi-line "Possible values:" list
+    static ref POSSIBLE_VALUE_ITEM: Regex = Regex::new(r"^-\s+([^\s:]+):?(?:\s|$)").unwrap();
+
+    /// Regex pattern for argparse choices: --format {json,yaml}
+    static ref ARGPARSE_CHOICES: Regex = Regex::new(r"--?[a-zA-Z][a-zA-Z0-9-]*[ =]\{([^{}\s]+)\}").unwrap();
+
+    /// Regex pattern for argparse/GNU metavars: --output OUTPUT, --block-size=SIZE
+    static ref UPPERCASE_METAVAR: Regex = Regex::new(r"--[a-z][a-z0-9-]*[ =][A-Z][A-Z0-9_-]*(?:\s|,|$)").unwrap();
+
+    /// Regex pattern for cobra value types: --count int, --name string
+    static ref COBRA_VALUE_TYPE: Regex = Regex::new(r"--[a-z][a-z0-9-]*\s(string|strings|stringArray|stringSlice|duration|int|int32|int64|uint|uint32|uint64|float32|float64|ints)(?:\s|$)").unwrap();
+
+    /// Regex pattern for ENVIRONMENT section headers
+    static ref ENVIRONMENT_HEADER: Regex = Regex::new(r"(?i)^environment(?:\s+variables)?:?$").unwrap();
+
+    /// Regex pattern for an ENVIRONMENT section entry: NAME  description
+    static ref ENVIRONMENT_ENTRY: Regex = Regex::new(r"^\$?([A-Z][A-Z0-9_]+)(?:\s*[:=]\s*|\s+|$)(.*)$").unwrap();
+
+    /// Regex pattern for usage lines: "Usage: tool [OPTIONS] <FILE>", "usage: tool [-h] file"
+    static ref USAGE_LINE: Regex = Regex::new(r"(?i)^\s*usage:(.*)$").unwrap();
+
+    /// Regex pattern for usage placeholders standing for a subcommand
+    static ref SUBCOMMAND_SLOT: Regex = Regex::new(r"(?i)^(?:sub)?commands?$").unwrap();
+
+    /// Regex pattern for usage placeholders standing for options
+    static ref OPTIONS_SLOT: Regex = Regex::new(r"(?i)^(?:options?|flags|global[ _-]?options)$").unwrap();
+
+    /// Regex pattern for positional argument names
+    static ref POSITIONAL_NAME: Regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.:-]*$|^\{[^{}]+\}$").unwrap();
+
+    /// Regex pattern for conflicts in descriptions: "cannot be used with --yaml", "conflicts with -q"
+    static ref CONFLICT_PHRASE: Regex = Regex::new(r"(?i)\b(?:(?:cannot|can't|can not|may not|must not) be (?:used|combined|specified|given)(?: together)? with|conflicts? with|(?:mutually exclusive|incompatible|not compatible) with)\b").unwrap();
+
+    /// Regex pattern for requirements in descriptions: "requires --output", "only valid with --json"
+    static ref REQUIRES_PHRASE: Regex = Regex::new(r"(?i)\b(?:requires|must be used(?: together)? with|only (?:valid|used|works|allowed|meaningful|applies|effective) (?:with|together with|in combination with))\b").unwrap();
+
+    /// Regex pattern for options mentioned in prose: "--yaml", "(-q)", "`--json`"
+    static ref MENTIONED_OPTION: Regex = Regex::new(r#"(?:^|[\s(`'",/])(--?[A-Za-z][A-Za-z0-9-]*)"#).unwrap();
 }
 
+/// Subcommands that print shell completion scripts (`<binary> completion bash`)
+const COMPLETION_SUBCOMMANDS: &[&str] = &[
+    "completion",
+    "completions",
+    "generate-completion",
+    "generate-completions",
+];
+
 /// CLI Parser - Executes binaries and parses help output
 pub struct CliParser {
     resource_limits: ResourceLimits,
+    completions: bool,
 }
 
 impl CliParser {
@@ -34,12 +98,28 @@ impl CliParser {
     pub fn new() -> Self {
         Self {
             resource_limits: ResourceLimits::default(),
+            completions: false,
         }
     }
 
     /// Create a new CLI parser with custom resource limits
     pub fn with_limits(resource_limits: ResourceLimits) -> Self {
-        Self { resource_limits }
+        Self {
+            resource_limits,
+            completions: false,
+        }
+    }
+
+    /// Also analyze the binary's shell completion scripts
+    ///
+    /// When enabled, [`CliParser::analyze`] runs the completion generator the help
+    /// output advertises (a `completion` subcommand or a `--*completion*` option)
+    /// for bash, zsh and fish, and merges the options, subcommands and enum values
+    /// the scripts offer. Options missing from `--help` are recorded with
+    /// [`OptionSource::Completion`].
+    pub fn with_completions(mut self, enabled: bool) -> Self {
+        self.completions = enabled;
+        self
     }
 
     /// Analyze a CLI binary and extract its structure
@@ -48,9 +128,10 @@ impl CliParser {
     /// 1. Validate binary path
     /// 2. Execute with --help to get help output
     /// 3. Execute with --version to get version string
-    /// 4. Parse help output to extract options
+    /// 4. Detect the CLI framework and parse options with its strategy
     /// 5. Detect subcommands recursively
-    /// 6. Build CliAnalysis structure
+    /// 6. Build CliAnalysis structure, merging in the man page if one is installed
+    ///    and completion scripts if enabled ([`CliParser::with_completions`])
     ///
     /// # Examples
     ///
@@ -111,20 +192,143 @@ impl CliParser {
         // Step 3: Try to get version
         let version = self.try_get_version(&canonical_path);
 
-        // Step 4: Parse options from help output
-        let global_options = self.parse_options(&help_output);
+        // Steps 4-6: Parse help output, detect subcommands, build analysis
+        let man_page = find_man_page(&canonical_path).and_then(|path| load_man_page(&path));
+        let binary = canonical_path.clone();
+        Ok(self.build_analysis(
+            canonical_path,
+            binary_name,
+            help_output,
+            version,
+            man_page,
+            start_time,
+            |detector, help| detector.detect(&binary, help),
+            |analysis| {
+                if self.completions {
+                    self.capture_completions(&binary, analysis)
+                } else {
+                    Vec::new()
+                }
+            },
+        ))
+    }
+
+    /// Analyze a CLI from captured help text instead of executing it
+    ///
+    /// The directory holds the output of the commands that [`CliParser::analyze`]
+    /// would run:
+    ///
+    /// ```text
+    /// <dir>/help.txt              <binary> --help (required)
+    /// <dir>/version.txt           <binary> --version (optional)
+    /// <dir>/man.1[.gz]            man page source (optional)
+    /// <dir>/completion.<shell>    bash, zsh or fish completion script (optional)
+    /// <dir>/sub/<name>/help.txt   <binary> <name> --help (nested: sub/<a>/sub/<b>/help.txt)
+    /// ```
+    ///
+    /// The directory name is used as binary name and path; callers can override
+    /// both on the returned analysis.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_testing_specialist::analyzer::CliParser;
+    /// use std::path::Path;
+    ///
+    /// let analysis = CliParser::new().analyze_help_dir(Path::new("captured/kubectl"))?;
+    /// println!("Subcommands: {}", analysis.subcommands.len());
+    /// # Ok::<(), cli_testing_specialist::error::CliTestError>(())
+    /// ```
+    pub fn analyze_help_dir(&self, dir: &Path) -> Result<CliAnalysis> {
+        let start_time = Instant::now();
+        log::info!("Analyzing captured help in {}", dir.display());
+
+        let help_file = dir.join("help.txt");
+        if !help_file.is_file() {
+            return Err(CliTestError::Validation(format!(
+                "{} not found (expected captured `--help` output)",
+                help_file.display()
+            )));
+        }
+        let help_output = std::fs::read_to_string(&help_file)?;
+        if help_output.trim().is_empty() {
+            return Err(CliTestError::InvalidHelpOutput);
+        }
+
+        let version = std::fs::read_to_string(dir.join("version.txt"))
+            .ok()
+            .and_then(|output| self.extract_version(&output));
+
+        let binary_name = dir
+            .canonicalize()
+            .ok()
+            .as_deref()
+            .unwrap_or(dir)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("cli")
+            .to_string();
+
+        let man_page = ["man.1", "man.1.gz"]
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+            .and_then(|path| load_man_page(&path));
+
+        Ok(self.build_analysis(
+            dir.to_path_buf(),
+            binary_name,
+            help_output,
+            version,
+            man_page,
+            start_time,
+            |detector, help| detector.detect_from_dir(dir, help),
+            |_| read_completion_files(dir),
+        ))
+    }
+
+    /// Parse help output and detect subcommands into a [`CliAnalysis`]
+    #[allow(clippy::too_many_arguments)]
+    fn build_analysis(
+        &self,
+        binary_path: PathBuf,
+        binary_name: String,
+        help_output: String,
+        version: Option<String>,
+        man_page: Option<ManPage>,
+        start_time: Instant,
+        detect_subcommands: impl FnOnce(&SubcommandDetector, &str) -> Result<Vec<Subcommand>>,
+        load_completions: impl FnOnce(&CliAnalysis) -> Vec<Completions>,
+    ) -> CliAnalysis {
+        // Step 4: Parse options and environment variables from help output
+        let framework = detect_framework(&help_output);
+        log::debug!("Detected CLI framework: {}", framework);
+        let global_options = parser_for(framework).parse_options(&help_output);
+        let environment_variables = self.parse_environment_variables(&help_output, &global_options);
+        let mut positional_args = self.parse_positional_args(&help_output);
+        OptionInferrer::default().infer_positional_types(&mut positional_args);
+        let option_relations = self.parse_option_relations(&help_output, &global_options);
 
         // Step 5: Detect subcommands recursively
-        let subcommand_detector = SubcommandDetector::default();
-        let subcommands = subcommand_detector
-            .detect(&canonical_path, &help_output)
-            .unwrap_or_default();
+        let subcommand_detector = SubcommandDetector::default().with_framework(framework);
+        let subcommands =
+            detect_subcommands(&subcommand_detector, &help_output).unwrap_or_default();
 
         // Step 6: Build analysis result
-        let mut analysis = CliAnalysis::new(canonical_path, binary_name, help_output);
+        let mut analysis = CliAnalysis::new(binary_path, binary_name, help_output);
         analysis.version = version;
+        analysis.framework = framework;
         analysis.global_options = global_options;
+        analysis.positional_args = positional_args;
+        analysis.option_relations = option_relations;
+        analysis.environment_variables = environment_variables;
         analysis.subcommands = subcommands;
+        if let Some(man_page) = man_page {
+            man_page.merge_into(&mut analysis);
+        }
+        for completions in load_completions(&analysis) {
+            completions.merge_into(&mut analysis);
+        }
 
         // Update metadata
         let duration_ms = start_time.elapsed().as_millis() as u64;
@@ -137,7 +341,40 @@ impl CliParser {
             duration_ms
         );
 
-        Ok(analysis)
+        analysis
+    }
+
+    /// Run the binary's completion generator for each shell and parse the scripts
+    fn capture_completions(&self, binary: &Path, analysis: &CliAnalysis) -> Vec<Completions> {
+        let generators = completion_generators(analysis);
+        if generators.is_empty() {
+            log::debug!("No completion generator advertised in help output");
+            return Vec::new();
+        }
+
+        let mut scripts = Vec::new();
+        for shell in CompletionShell::ALL {
+            for generator in &generators {
+                let mut args: Vec<&str> = generator.iter().map(String::as_str).collect();
+                args.push(shell.as_str());
+
+                let Ok(output) =
+                    execute_with_timeout(binary, &args, self.resource_limits.timeout())
+                else {
+                    continue;
+                };
+                if !shell.recognizes(&output) {
+                    continue;
+                }
+                let completions = parse_completion_script(shell, &output);
+                if !completions.is_empty() {
+                    log::debug!("Parsed {} completions from `{}`", shell, args.join(" "));
+                    scripts.push(completions);
+                    break;
+                }
+            }
+        }
+        scripts
     }
 
     /// Execute binary with --help flag
@@ -162,8 +399,8 @@ impl CliParser {
         }
     }
 
-    /// Try to get version string from binary
-    fn try_get_version(&self, binary: &Path) -> Option<String> {
+    /// Try to get version string from binary (`--version`, `-v`, then `version`)
+    pub fn try_get_version(&self, binary: &Path) -> Option<String> {
         log::debug!("Attempting to get version for {}", binary.display());
 
         // Try --version
@@ -200,36 +437,96 @@ impl CliParser {
     }
 
     /// Parse CLI options from help output
+    ///
+    /// Any line mentioning an option is treated as defining it. Framework-aware
+    /// parsing (see [`crate::analyzer::framework`]) uses a stricter variant.
     pub fn parse_options(&self, help_output: &str) -> Vec<CliOption> {
-        let mut options = Vec::new();
+        self.parse_option_lines(help_output, false)
+    }
+
+    /// Parse CLI options, optionally only from lines that start with a dash
+    ///
+    /// In strict mode options are only read from the option syntax before the
+    /// description (see [`option_spec`]), so options mentioned inside
+    /// descriptions (e.g. "overrides --color", "with -l, scale sizes") are not
+    /// mistaken for definitions.
+    pub(crate) fn parse_option_lines(&self, help_output: &str, strict: bool) -> Vec<CliOption> {
+        let mut options: Vec<CliOption> = Vec::new();
         let mut seen_options = std::collections::HashSet::new();
 
+        // Option that indented continuation lines (clap's long help) belong to
+        let mut current: Option<usize> = None;
+        let mut in_possible_values = false;
+
         for line in help_output.lines() {
             let trimmed = line.trim();
 
-            // Skip empty lines and headers
-            if trimmed.is_empty() || !trimmed.contains('-') {
+            // Skip empty lines
+            if trimmed.is_empty() {
+                in_possible_values = false;
+                continue;
+            }
+
+            // clap's long help lists choices one per line: "- value: description"
+            if in_possible_values {
+                if let (Some(cap), Some(idx)) = (POSSIBLE_VALUE_ITEM.captures(trimmed), current) {
+                    add_possible_value(&mut options[idx], &cap[1]);
+                    continue;
+                }
+                in_possible_values = false;
+            }
+
+            // Usage lines list options without defining them
+            if trimmed
+                .get(..6)
+                .is_some_and(|p| p.eq_ignore_ascii_case("usage:"))
+            {
+                current = None;
                 continue;
             }
 
             // Extract short and long options from the line
+            let defines_option = !strict || trimmed.starts_with('-');
+            let spec = if strict {
+                option_spec(trimmed)
+            } else {
+                trimmed
+            };
             let short = SHORT_OPTION
-                .captures(trimmed)
+                .captures(spec)
+                .filter(|cap| defines_option && (!strict || cap.get(0).unwrap().start() == 0))
                 .and_then(|cap| cap.get(1))
                 .map(|m| format!("-{}", m.as_str()));
 
             let long = LONG_OPTION
-                .captures(trimmed)
+                .captures(spec)
+                .filter(|_| defines_option)
                 .and_then(|cap| cap.get(1))
                 .map(|m| format!("--{}", m.as_str()));
 
-            // Skip if no option found or already processed
+            // Headers end the current option; description lines may carry
+            // annotations such as `[env: NAME=]` or `[default: VALUE]`
             if short.is_none() && long.is_none() {
+                if !line.starts_with(char::is_whitespace) {
+                    current = None;
+                } else if let Some(idx) = current {
+                    if trimmed.eq_ignore_ascii_case("possible values:") {
+                        in_possible_values = true;
+                    } else {
+                        apply_annotations(&mut options[idx], trimmed);
+                    }
+                }
                 continue;
             }
 
+            // Skip if already processed, or if the long option was defined on
+            // an earlier line (`-p, --indicator-style=slash` after
+            // `--indicator-style=WORD`)
             let option_key = format!("{:?}:{:?}", short, long);
-            if seen_options.contains(&option_key) {
+            if seen_options.contains(&option_key)
+                || long.is_some() && options.iter().any(|o| o.long == long)
+            {
+                current = None;
                 continue;
             }
             seen_options.insert(option_key);
@@ -241,54 +538,630 @@ impl CliParser {
                 .map(|m| m.as_str().trim().to_string());
 
             // Determine option type (basic inference, will be enhanced by option_inferrer)
-            let option_type = if OPTION_WITH_VALUE.is_match(trimmed) {
+            let option_type = if let Some(cap) = ARGPARSE_CHOICES.captures(trimmed) {
+                OptionType::Enum {
+                    values: cap[1].split(',').map(str::to_string).collect(),
+                }
+            } else if let Some(cap) = COBRA_VALUE_TYPE.captures(trimmed) {
+                match &cap[1] {
+                    "int" | "int32" | "int64" | "uint" | "uint32" | "uint64" | "float32"
+                    | "float64" => OptionType::Numeric {
+                        min: None,
+                        max: None,
+                    },
+                    _ => OptionType::String,
+                }
+            } else if OPTION_WITH_VALUE.is_match(trimmed) || UPPERCASE_METAVAR.is_match(trimmed) {
                 OptionType::String
             } else {
                 OptionType::Flag
             };
 
-            options.push(CliOption {
+            let mut option = CliOption {
                 short,
                 long,
                 description,
                 option_type,
                 required: false, // Default to optional
                 default_value: None,
-            });
+                env_var: None,
+                source: OptionSource::Help,
+            };
+            apply_annotations(&mut option, trimmed);
+
+            options.push(option);
+            current = Some(options.len() - 1);
         }
 
         options
     }
 
+    /// Collect environment variables from options and ENVIRONMENT sections
+    ///
+    /// Variables bound to an option (`[env: NAME=]`) come first, in option order,
+    /// followed by variables that are only listed in an `ENVIRONMENT` or
+    /// `Environment variables:` section. Section descriptions fill in missing ones.
+    pub fn parse_environment_variables(
+        &self,
+        help_output: &str,
+        options: &[CliOption],
+    ) -> Vec<EnvironmentVariable> {
+        let mut variables: Vec<EnvironmentVariable> = Vec::new();
+
+        for option in options {
+            let Some(name) = &option.env_var else {
+                continue;
+            };
+            if variables.iter().any(|v| &v.name == name) {
+                continue;
+            }
+            variables.push(EnvironmentVariable {
+                name: name.clone(),
+                description: option.description.clone(),
+                option: option.long.clone().or_else(|| option.short.clone()),
+            });
+        }
+
+        for (name, description) in parse_environment_section(help_output) {
+            match variables.iter_mut().find(|v| v.name == name) {
+                Some(existing) => {
+                    if existing.description.is_none() {
+                        existing.description = description;
+                    }
+                }
+                None => variables.push(EnvironmentVariable {
+                    name,
+                    description,
+                    option: None,
+                }),
+            }
+        }
+
+        log::debug!("Detected {} environment variables", variables.len());
+        variables
+    }
+
     /// Parse required positional arguments from help output
     ///
-    /// Looks for Usage line and extracts `<ARG>` patterns:
+    /// Names of the required arguments from [`CliParser::parse_positional_args`]:
     /// - "Usage: cmd \[OPTIONS\] `<ID>`" → \["ID"\]
     /// - "Usage: cmd `<FILE>` `<OUTPUT>`" → \["FILE", "OUTPUT"\]
     pub fn parse_required_args(&self, help_output: &str) -> Vec<String> {
-        lazy_static! {
-            static ref USAGE_LINE: Regex = Regex::new(r"(?i)^\s*usage:\s+").unwrap();
-            static ref REQUIRED_ARG: Regex = Regex::new(r"<([^>]+)>").unwrap();
+        let required_args: Vec<String> = self
+            .parse_positional_args(help_output)
+            .into_iter()
+            .filter(|arg| arg.required)
+            .map(|arg| arg.name)
+            .collect();
+
+        log::debug!("Detected {} required arguments", required_args.len());
+        required_args
+    }
+
+    /// Parse positional arguments from the first usage line of help output
+    ///
+    /// Understands the usual notations:
+    /// - `<FILE>` / `FILE` / `file` (argparse) → required
+    /// - `[FILE]` → optional
+    /// - `<FILE>...`, `[FILE]...`, `file [file ...]` → variadic
+    /// - `{json,yaml}` (argparse choices) → Enum
+    ///
+    /// Option groups (`[OPTIONS]`, `[-h]`, `[--format FMT]`) are skipped, and a
+    /// subcommand slot (`<COMMAND>`, `[command]`, `{push,pull} ...`) ends the
+    /// list since the arguments after it belong to the subcommand. Types other
+    /// than Enum are left as String for [`OptionInferrer::infer_positional_types`].
+    ///
+    /// [`OptionInferrer::infer_positional_types`]: crate::analyzer::OptionInferrer::infer_positional_types
+    pub fn parse_positional_args(&self, help_output: &str) -> Vec<PositionalArg> {
+        let Some(usage) = usage_text(help_output) else {
+            return Vec::new();
+        };
+
+        let tokens = usage_tokens(&usage);
+        let mut args: Vec<PositionalArg> = Vec::new();
+        let mut in_program_name = true;
+
+        for (index, token) in tokens.iter().enumerate() {
+            let (token, mut variadic) = strip_ellipsis(token);
+            if token.is_empty() {
+                // Bare `...` repeats the previous argument
+                if let Some(last) = args.last_mut() {
+                    last.variadic = true;
+                }
+                continue;
+            }
+
+            let (inner, required) = match token.strip_prefix('[').and_then(|t| t.strip_suffix(']'))
+            {
+                Some(inner) => (inner, false),
+                None => (token, true),
+            };
+
+            // Leading plain words are the program and subcommand names
+            if in_program_name {
+                if required && !token.starts_with(['<', '{', '(', '-']) && !is_metavar(token) {
+                    continue;
+                }
+                in_program_name = false;
+            }
+
+            let (inner, inner_variadic) = strip_ellipsis(inner.trim());
+            variadic |= inner_variadic;
+            let Some(name) = inner.split_whitespace().next() else {
+                continue;
+            };
+            if name.starts_with(['-', '(']) {
+                continue;
+            }
+
+            let next_is_ellipsis = tokens.get(index + 1).is_some_and(|t| t == "...");
+            let (name, arg_type) = match name.strip_prefix('{').and_then(|n| n.strip_suffix('}')) {
+                // argparse subparsers: `{push,rollback} ...`
+                Some(_) if next_is_ellipsis => break,
+                Some(choices) => (
+                    name.to_string(),
+                    OptionType::Enum {
+                        values: choices.split(',').map(str::to_string).collect(),
+                    },
+                ),
+                None => {
+                    let name = name.trim_start_matches('<').trim_end_matches('>');
+                    (name.to_string(), OptionType::String)
+                }
+            };
+
+            if SUBCOMMAND_SLOT.is_match(&name) {
+                break;
+            }
+            if OPTIONS_SLOT.is_match(&name) || !POSITIONAL_NAME.is_match(&name) {
+                continue;
+            }
+
+            // argparse spells repetition as `file [file ...]`
+            if let Some(last) = args.last_mut() {
+                if !required && variadic && last.name == name {
+                    last.variadic = true;
+                    continue;
+                }
+            }
+
+            args.push(PositionalArg {
+                name,
+                index: args.len(),
+                required,
+                variadic,
+                arg_type,
+            });
         }
 
-        let mut required_args = Vec::new();
+        log::debug!("Detected {} positional arguments", args.len());
+        args
+    }
 
-        for line in help_output.lines() {
-            if USAGE_LINE.is_match(line) {
-                // Extract all <ARG> patterns from the usage line
-                for cap in REQUIRED_ARG.captures_iter(line) {
-                    if let Some(arg_match) = cap.get(1) {
-                        let arg_name = arg_match.as_str().to_string();
-                        required_args.push(arg_name);
+    /// Infer conflicts, requirements and exclusive groups among `options`
+    ///
+    /// Sources:
+    /// - Usage alternatives: `[--json | --yaml]`, `(-a | -b)`, `<--x|--y>` → exclusive group
+    /// - Descriptions: "cannot be used with --x", "conflicts with", "mutually exclusive
+    ///   with", "incompatible with" → conflict
+    /// - Descriptions: "requires --y", "must be used with", "only valid with" → requirement
+    ///
+    /// Mentions are resolved against `options` and unknown flags are dropped.
+    /// Requirements naming alternatives ("requires --to or --last") are ambiguous
+    /// and skipped. Conflicts already implied by an exclusive group are not repeated.
+    pub fn parse_option_relations(
+        &self,
+        help_output: &str,
+        options: &[CliOption],
+    ) -> Vec<OptionRelation> {
+        let mut relations: Vec<OptionRelation> = Vec::new();
+
+        if let Some(usage) = usage_text(help_output) {
+            for group in usage_alternatives(&usage) {
+                let mut members: Vec<String> = Vec::new();
+                for flag in group {
+                    if let Some(name) = resolve_option(options, &flag) {
+                        if !members.contains(&name) {
+                            members.push(name);
+                        }
+                    }
+                }
+                if members.len() >= 2 {
+                    push_relation(
+                        &mut relations,
+                        OptionRelation::ExclusiveGroup { options: members },
+                    );
+                }
+            }
+        }
+
+        for option in options {
+            let (Some(name), Some(description)) = (
+                option.long.as_ref().or(option.short.as_ref()),
+                option.description.as_deref(),
+            ) else {
+                continue;
+            };
+
+            for phrase in CONFLICT_PHRASE.find_iter(description) {
+                for with in mentioned_options(options, &description[phrase.end()..]) {
+                    if &with != name {
+                        push_relation(
+                            &mut relations,
+                            OptionRelation::Conflicts {
+                                option: name.clone(),
+                                with,
+                            },
+                        );
+                    }
+                }
+            }
+
+            for phrase in REQUIRES_PHRASE.find_iter(description) {
+                let clause = clause_after(&description[phrase.end()..]);
+                if clause.split_whitespace().any(|word| word == "or") {
+                    continue;
+                }
+                for requires in mentioned_options(options, clause) {
+                    if &requires != name {
+                        push_relation(
+                            &mut relations,
+                            OptionRelation::Requires {
+                                option: name.clone(),
+                                requires,
+                            },
+                        );
                     }
                 }
-                break; // Only process the first Usage line
             }
         }
 
-        log::debug!("Detected {} required arguments", required_args.len());
-        required_args
+        log::debug!("Detected {} option relations", relations.len());
+        relations
+    }
+}
+
+/// Usage text after `Usage:`, joined with its continuation lines
+///
+/// Continuation lines (argparse wraps long usages) are indented at least as far
+/// as the usage text itself; less indented lines such as curl's option summary
+/// end the usage. `USAGE:` headers with the usage on the next line (cobra,
+/// clap 2) yield the first form listed; `or:` alternatives are ignored.
+fn usage_text(help_output: &str) -> Option<String> {
+    let mut lines = help_output.lines();
+    let (rest, column) = loop {
+        let line = lines.next()?;
+        if let Some(rest) = USAGE_LINE.captures(line).and_then(|c| c.get(1)) {
+            let trimmed = rest.as_str().trim_start();
+            break (trimmed.trim_end(), line.len() - trimmed.len());
+        }
+    };
+
+    if rest.is_empty() {
+        return lines
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string);
+    }
+
+    let mut usage = rest.to_string();
+    for line in lines {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || line.len() - trimmed.len() < column || trimmed.starts_with("or:") {
+            break;
+        }
+        usage.push(' ');
+        usage.push_str(trimmed.trim_end());
+    }
+    Some(usage)
+}
+
+/// Flags of each usage group with `|`-separated option alternatives
+///
+/// Only groups whose alternatives all start with an option qualify, so
+/// `{json|yaml}` value lists and `[FILE | -]` are ignored. The flag is the first
+/// word of each alternative, without a `=VALUE` suffix.
+fn usage_alternatives(usage: &str) -> Vec<Vec<String>> {
+    let mut groups = Vec::new();
+    let mut starts: Vec<usize> = Vec::new();
+
+    for (index, c) in usage.char_indices() {
+        match c {
+            '[' | '(' | '<' | '{' => starts.push(index),
+            ']' | ')' | '>' | '}' => {
+                let Some(start) = starts.pop() else {
+                    continue;
+                };
+                let content = &usage[start + 1..index];
+                let alternatives = split_top_level(content, '|');
+                if alternatives.len() < 2 {
+                    continue;
+                }
+                let flags: Vec<String> = alternatives
+                    .iter()
+                    .filter_map(|alternative| {
+                        let word = alternative.split_whitespace().next()?;
+                        let flag = word.split('=').next().unwrap_or(word);
+                        flag.starts_with('-').then(|| flag.to_string())
+                    })
+                    .collect();
+                if flags.len() == alternatives.len() {
+                    groups.push(flags);
+                }
+            }
+            _ => {}
+        }
+    }
+    groups
+}
+
+/// Split `text` on `separator` outside nested brackets
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, c) in text.char_indices() {
+        match c {
+            '[' | '(' | '<' | '{' => depth += 1,
+            ']' | ')' | '>' | '}' => depth = depth.saturating_sub(1),
+            _ if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Option syntax of a help line: the text before the gap (two spaces or a tab)
+/// that separates it from the description
+pub(crate) fn option_spec(line: &str) -> &str {
+    let line = line.trim_start();
+    let end = [line.find("  "), line.find('\t')]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(line.len());
+    &line[..end]
+}
+
+/// Text up to the end of the clause (`.`, `;` or `)`)
+fn clause_after(text: &str) -> &str {
+    let end = text
+        .char_indices()
+        .find(|&(index, c)| {
+            matches!(c, ';' | ')')
+                || (c == '.'
+                    && text[index + 1..]
+                        .chars()
+                        .next()
+                        .map_or(true, char::is_whitespace))
+        })
+        .map_or(text.len(), |(index, _)| index);
+    &text[..end]
+}
+
+/// Known options mentioned in the clause starting `text`
+fn mentioned_options(options: &[CliOption], text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for capture in MENTIONED_OPTION.captures_iter(clause_after(text)) {
+        if let Some(name) = resolve_option(options, &capture[1]) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Canonical name (long flag, else short flag) of the option spelled `flag`
+fn resolve_option(options: &[CliOption], flag: &str) -> Option<String> {
+    options
+        .iter()
+        .find(|o| o.long.as_deref() == Some(flag) || o.short.as_deref() == Some(flag))
+        .and_then(|o| o.long.clone().or_else(|| o.short.clone()))
+}
+
+/// Add a relation unless it (or a relation implying it) is already known
+fn push_relation(relations: &mut Vec<OptionRelation>, relation: OptionRelation) {
+    let known = match &relation {
+        OptionRelation::Conflicts { option, with } => {
+            relations.iter().any(|r| r.excludes(option, with))
+        }
+        _ => relations.contains(&relation),
+    };
+    if !known {
+        relations.push(relation);
+    }
+}
+
+/// Split a usage line on whitespace outside brackets: `[--format FMT]` is one token
+fn usage_tokens(usage: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut depth = 0usize;
+
+    for c in usage.chars() {
+        match c {
+            '[' | '<' | '{' | '(' => depth += 1,
+            ']' | '>' | '}' | ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if c.is_whitespace() && depth == 0 {
+            if !token.is_empty() {
+                tokens.push(std::mem::take(&mut token));
+            }
+        } else {
+            token.push(c);
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Strip a trailing `...` (or `…`), returning whether it was present
+fn strip_ellipsis(token: &str) -> (&str, bool) {
+    match token
+        .strip_suffix("...")
+        .or_else(|| token.strip_suffix('…'))
+    {
+        Some(stripped) => (stripped.trim_end(), true),
+        None => (token, false),
+    }
+}
+
+/// Whether a bare usage word is an uppercase metavar (`FILE`, `SRC_DIR`)
+fn is_metavar(word: &str) -> bool {
+    word.chars().any(|c| c.is_ascii_uppercase())
+        && word
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_' || c == '-')
+}
+
+/// Arguments preceding the shell name in completion generator invocations
+///
+/// Only generators the help output lists are used: `completion`-like
+/// subcommands and options such as `--generate-completion <SHELL>`.
+fn completion_generators(analysis: &CliAnalysis) -> Vec<Vec<String>> {
+    let subcommands = analysis
+        .subcommands
+        .iter()
+        .filter(|s| COMPLETION_SUBCOMMANDS.contains(&s.name.as_str()))
+        .map(|s| vec![s.name.clone()]);
+    let options = analysis
+        .global_options
+        .iter()
+        .filter(|o| o.option_type != OptionType::Flag)
+        .filter_map(|o| o.long.clone())
+        .filter(|long| long.contains("completion"))
+        .map(|long| vec![long]);
+    subcommands.chain(options).collect()
+}
+
+/// Parse `completion.<shell>` scripts captured next to `help.txt`
+fn read_completion_files(dir: &Path) -> Vec<Completions> {
+    CompletionShell::ALL
+        .iter()
+        .filter_map(|shell| {
+            let script = std::fs::read_to_string(dir.join(format!("completion.{}", shell))).ok()?;
+            Some(parse_completion_script(*shell, &script))
+        })
+        .collect()
+}
+
+/// Read and parse a man page, logging (not failing on) unreadable pages
+fn load_man_page(path: &Path) -> Option<ManPage> {
+    match read_man_page(path) {
+        Ok(roff) => Some(parse_man_page(&roff)),
+        Err(e) => {
+            log::warn!("Failed to read man page {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Apply `[env: ...]`, `[default: ...]` and `[possible values: ...]` annotations
+///
+/// The first annotation of each kind wins, so hints on the option line take
+/// precedence over later description lines.
+fn apply_annotations(option: &mut CliOption, text: &str) {
+    if option.env_var.is_none() {
+        option.env_var = ENV_HINT.captures(text).map(|cap| cap[1].to_string());
+    }
+
+    if option.default_value.is_none() {
+        option.default_value = DEFAULT_HINT
+            .captures(text)
+            .and_then(|cap| cap.get(1).or(cap.get(2)))
+            .map(|m| m.as_str().to_string())
+            // cobra prints slice defaults as "[a,b]"
+            .filter(|value| !value.is_empty() && !value.starts_with('['));
+    }
+
+    if let Some(cap) = POSSIBLE_VALUES.captures(text) {
+        if !matches!(&option.option_type, OptionType::Enum { values } if !values.is_empty()) {
+            option.option_type = OptionType::Enum {
+                values: cap[1]
+                    .split(',')
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+                    .collect(),
+            };
+        }
+    }
+}
+
+/// Append one entry of a multi-line possible values list
+fn add_possible_value(option: &mut CliOption, value: &str) {
+    match &mut option.option_type {
+        OptionType::Enum { values } => {
+            if !values.iter().any(|v| v == value) {
+                values.push(value.to_string());
+            }
+        }
+        _ => {
+            option.option_type = OptionType::Enum {
+                values: vec![value.to_string()],
+            }
+        }
+    }
+}
+
+/// Entries of `ENVIRONMENT` sections as (name, description) pairs
+///
+/// A section ends at the next line indented no deeper than its header. Entries
+/// share the indentation of the first line in the section; deeper lines continue
+/// the previous entry's description (man pages put it on the following line).
+fn parse_environment_section(help_output: &str) -> Vec<(String, Option<String>)> {
+    let mut entries: Vec<(String, Option<String>)> = Vec::new();
+    let mut header_indent: Option<usize> = None;
+    let mut entry_indent: Option<usize> = None;
+
+    for line in help_output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        let Some(section_indent) = header_indent else {
+            if ENVIRONMENT_HEADER.is_match(trimmed) {
+                header_indent = Some(indent);
+                entry_indent = None;
+            }
+            continue;
+        };
+
+        if indent <= section_indent {
+            // Next section (possibly another ENVIRONMENT section)
+            header_indent = ENVIRONMENT_HEADER.is_match(trimmed).then_some(indent);
+            entry_indent = None;
+            continue;
+        }
+
+        let entry_indent = *entry_indent.get_or_insert(indent);
+        if indent == entry_indent {
+            if let Some(cap) = ENVIRONMENT_ENTRY.captures(trimmed) {
+                let description = cap[2].trim();
+                entries.push((
+                    cap[1].to_string(),
+                    (!description.is_empty()).then(|| description.to_string()),
+                ));
+            }
+        } else if let Some((_, description)) = entries.last_mut() {
+            *description = Some(match description.take() {
+                Some(text) => format!("{} {}", text, trimmed),
+                None => trimmed.to_string(),
+            });
+        }
     }
+
+    entries
 }
 
 impl Default for CliParser {
@@ -385,6 +1258,330 @@ Options:
 
         // Should only have one option despite duplicate
         assert_eq!(options.len(), 1);
+
+        // GNU ls lists a preset of --indicator-style under its own short flag
+        let help_output = r#"
+      --indicator-style=WORD  append indicator with style WORD
+  -p, --indicator-style=slash
+"#;
+
+        let options = parser.parse_option_lines(help_output, true);
+
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].short, None);
+        assert_eq!(options[0].option_type, OptionType::String);
+    }
+
+    #[test]
+    fn test_parse_options_env_hints() {
+        let parser = CliParser::new();
+        let help_output = r#"
+Options:
+  -c, --config <FILE>  Config file [env: MYTOOL_CONFIG=]
+      --color <WHEN>
+          When to use colors
+
+          [env: MYTOOL_COLOR=auto]
+          [possible values: auto, always, never]
+
+  -q, --quiet  Less output (env: MYTOOL_QUIET)
+  -h, --help   Print help
+"#;
+
+        let options = parser.parse_options(help_output);
+        let env_of = |long: &str| {
+            options
+                .iter()
+                .find(|o| o.long.as_deref() == Some(long))
+                .and_then(|o| o.env_var.clone())
+        };
+
+        assert_eq!(env_of("--config"), Some("MYTOOL_CONFIG".to_string()));
+        assert_eq!(env_of("--color"), Some("MYTOOL_COLOR".to_string()));
+        assert_eq!(env_of("--quiet"), Some("MYTOOL_QUIET".to_string()));
+        assert_eq!(env_of("--help"), None);
+    }
+
+    fn find<'a>(options: &'a [CliOption], long: &str) -> &'a CliOption {
+        options
+            .iter()
+            .find(|o| o.long.as_deref() == Some(long))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_options_clap_annotations() {
+        let parser = CliParser::new();
+        let help_output = r#"
+Options:
+  -f, --format <FORMAT>  Output format [default: text] [possible values: text, json, yaml]
+  -j, --jobs <N>         Worker count [default: 4]
+      --color <WHEN>
+          When to use colors
+
+          Possible values:
+          - auto:   Detect the terminal
+          - always: Always use colors
+          - never
+
+          [default: auto]
+
+  -h, --help  Print help
+"#;
+
+        let options = parser.parse_options(help_output);
+
+        let format = find(&options, "--format");
+        assert_eq!(format.default_value.as_deref(), Some("text"));
+        assert_eq!(
+            format.option_type,
+            OptionType::Enum {
+                values: vec!["text".to_string(), "json".to_string(), "yaml".to_string()]
+            }
+        );
+
+        let jobs = find(&options, "--jobs");
+        assert_eq!(jobs.default_value.as_deref(), Some("4"));
+        assert_eq!(jobs.option_type, OptionType::String);
+
+        let color = find(&options, "--color");
+        assert_eq!(color.default_value.as_deref(), Some("auto"));
+        assert_eq!(
+            color.option_type,
+            OptionType::Enum {
+                values: vec![
+                    "auto".to_string(),
+                    "always".to_string(),
+                    "never".to_string()
+                ]
+            }
+        );
+
+        assert_eq!(find(&options, "--help").default_value, None);
+    }
+
+    #[test]
+    fn test_parse_options_argparse_annotations() {
+        let parser = CliParser::new();
+        let help_output = r#"
+usage: tool [-h] [-f {json,yaml}] [--retries RETRIES]
+
+options:
+  -h, --help            show this help message and exit
+  -f {json,yaml}, --format {json,yaml}
+                        output format (default: json)
+  --retries RETRIES     retry count (default: 3)
+"#;
+
+        let options = parser.parse_options(help_output);
+
+        let format = find(&options, "--format");
+        assert_eq!(format.default_value.as_deref(), Some("json"));
+        assert_eq!(
+            format.option_type,
+            OptionType::Enum {
+                values: vec!["json".to_string(), "yaml".to_string()]
+            }
+        );
+
+        let retries = find(&options, "--retries");
+        assert_eq!(retries.option_type, OptionType::String);
+        assert_eq!(retries.default_value.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_parse_options_cobra_annotations() {
+        let parser = CliParser::new();
+        let help_output = r#"
+Flags:
+  -o, --output string   Output format (default "table")
+      --limit int       Maximum results (default 50)
+      --labels strings  Labels to apply (default [a,b])
+      --dry-run         Only print the actions
+"#;
+
+        let options = parser.parse_options(help_output);
+
+        let output = find(&options, "--output");
+        assert_eq!(output.option_type, OptionType::String);
+        assert_eq!(output.default_value.as_deref(), Some("table"));
+
+        let limit = find(&options, "--limit");
+        assert_eq!(
+            limit.option_type,
+            OptionType::Numeric {
+                min: None,
+                max: None
+            }
+        );
+        assert_eq!(limit.default_value.as_deref(), Some("50"));
+
+        assert_eq!(find(&options, "--labels").default_value, None);
+        assert_eq!(find(&options, "--dry-run").option_type, OptionType::Flag);
+    }
+
+    #[test]
+    fn test_parse_environment_variables() {
+        let parser = CliParser::new();
+        let help_output = r#"
+Usage: mytool [OPTIONS]
+
+Options:
+  -c, --config <FILE>  Config file [env: MYTOOL_CONFIG=]
+  -h, --help           Print help
+
+ENVIRONMENT
+       MYTOOL_CONFIG
+              Path to the configuration file
+       NO_COLOR   Disable colored output
+              when set to any value
+
+EXIT STATUS
+       0      Success
+"#;
+
+        let options = parser.parse_options(help_output);
+        let variables = parser.parse_environment_variables(help_output, &options);
+
+        assert_eq!(
+            variables,
+            vec![
+                EnvironmentVariable {
+                    name: "MYTOOL_CONFIG".to_string(),
+                    description: Some("Config file [env: MYTOOL_CONFIG=]".to_string()),
+                    option: Some("--config".to_string()),
+                },
+                EnvironmentVariable {
+                    name: "NO_COLOR".to_string(),
+                    description: Some("Disable colored output when set to any value".to_string()),
+                    option: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_environment_section_descriptions() {
+        let entries = parse_environment_section(
+            "Environment variables:\n  RUST_LOG: Log filter\n  HOME\n    Home directory\nOptions:\n  -h  Help\n",
+        );
+
+        assert_eq!(
+            entries,
+            vec![
+                ("RUST_LOG".to_string(), Some("Log filter".to_string())),
+                ("HOME".to_string(), Some("Home directory".to_string())),
+            ]
+        );
+    }
+
+    fn positionals(help: &str) -> Vec<(String, bool, bool)> {
+        CliParser::new()
+            .parse_positional_args(help)
+            .into_iter()
+            .map(|arg| (arg.name, arg.required, arg.variadic))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_positional_args_notations() {
+        assert_eq!(
+            positionals("Usage: tool build [OPTIONS] <SRC>... [OUT]\n"),
+            vec![("SRC".into(), true, true), ("OUT".into(), false, false)]
+        );
+        assert_eq!(
+            positionals("Usage: ls [OPTION]... [FILE]...\nList information\n"),
+            vec![("FILE".into(), false, true)]
+        );
+        assert_eq!(
+            positionals("Usage: cp [OPTION]... [-T] SOURCE DEST\n  or:  cp [OPTION]... SOURCE... DIRECTORY\n"),
+            vec![("SOURCE".into(), true, false), ("DEST".into(), true, false)]
+        );
+        // Arguments after a subcommand slot belong to the subcommand
+        assert!(positionals("Usage: tool [OPTIONS] <COMMAND>\n").is_empty());
+        assert!(positionals("Usage: tool [OPTIONS] COMMAND [ARGS]...\n").is_empty());
+        assert!(
+            positionals("usage: deploy [-h] [--region {us,eu}] {push,rollback} ...\n").is_empty()
+        );
+        assert!(positionals("Usage:\n  kubectl [flags]\n  kubectl [command]\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_positional_args_argparse_wrapped() {
+        let help = "usage: tool [-h] [--a-very-long-option-name VALUE]\n            [--another-very-long-option {x,y}]\n            src [src ...] [dst] {fast,slow} count\n\npositional arguments:\n  src\n";
+        let args = CliParser::new().parse_positional_args(help);
+
+        let summary: Vec<_> = args
+            .iter()
+            .map(|arg| (arg.index, arg.name.as_str(), arg.required, arg.variadic))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "src", true, true),
+                (1, "dst", false, false),
+                (2, "{fast,slow}", true, false),
+                (3, "count", true, false),
+            ]
+        );
+        assert_eq!(
+            args[2].arg_type,
+            OptionType::Enum {
+                values: vec!["fast".to_string(), "slow".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_positional_args_ignores_option_summary() {
+        // curl lists options right below its usage line
+        let help = "Usage: curl [options...] <url>\n -d, --data <data>   HTTP POST data\n -o, --output <file> Write to file\n";
+        assert_eq!(positionals(help), vec![("url".into(), true, false)]);
+        assert_eq!(CliParser::new().parse_required_args(help), vec!["url"]);
+    }
+
+    #[test]
+    fn test_parse_option_relations_from_usage_groups() {
+        let help = "usage: tool [-h] [--json | --yaml | --format=FMT] (-q | --verbose) [--color {auto|never}]\n\noptions:\n  -h, --help     show help\n  --json         JSON output\n  --yaml         YAML output\n  --format FMT   output format\n  -q, --quiet    quiet\n  --verbose      verbose\n  --color WHEN   colorize\n";
+        let parser = CliParser::new();
+        let options = parser.parse_options(help);
+
+        assert_eq!(
+            parser.parse_option_relations(help, &options),
+            vec![
+                OptionRelation::ExclusiveGroup {
+                    options: vec!["--json".into(), "--yaml".into(), "--format".into()]
+                },
+                OptionRelation::ExclusiveGroup {
+                    options: vec!["--quiet".into(), "--verbose".into()]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_option_relations_from_descriptions() {
+        let help = "Usage: tool [OPTIONS]\n\nOptions:\n      --json           Print JSON. Cannot be used with --yaml or --template\n      --yaml           Print YAML (conflicts with --json)\n  -t, --template <T>   Output template\n      --force          Skip checks (requires --to)\n      --to <VERSION>   Target version\n      --last           Previous release, requires --to or --tag\n      --tag <TAG>      Release tag; see --json\n";
+        let parser = CliParser::new();
+        let options = parser.parse_options(help);
+
+        assert_eq!(
+            parser.parse_option_relations(help, &options),
+            vec![
+                OptionRelation::Conflicts {
+                    option: "--json".into(),
+                    with: "--yaml".into()
+                },
+                OptionRelation::Conflicts {
+                    option: "--json".into(),
+                    with: "--template".into()
+                },
+                OptionRelation::Requires {
+                    option: "--force".into(),
+                    requires: "--to".into()
+                },
+            ]
+        );
     }
 
     #[cfg(unix)]
@@ -0,0 +1,1276 @@
+use crate::analyzer::man_page::same_option;
+use crate::types::analysis::{CliAnalysis, CliOption, OptionSource, OptionType, Subcommand};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+lazy_static! {
+    /// Option names listed in completion scripts: -v, --output, --output= (takes a value)
+    static ref OPTION_NAME: Regex = Regex::new(r"^(--?[A-Za-z0-9][A-Za-z0-9_.-]*?)(=)?$").unwrap();
+
+    /// zsh `_arguments` option names with their argument suffix: -o+, --output=, --level=-
+    static ref ZSH_OPTION_NAME: Regex = Regex::new(r"^(--?[A-Za-z0-9][A-Za-z0-9_.-]*?)(\+|=-?|-)?$").unwrap();
+
+    /// Values of a zsh `((value\:description ...))` action
+    static ref ZSH_DESCRIBED_VALUE: Regex = Regex::new(r#"(?:^|\s)([^\s\\:"()]+)\\:"#).unwrap();
+
+    /// fish conditions selecting a subcommand: __fish_seen_subcommand_from analyze,
+    /// __fish_<cmd>_using_subcommand analyze
+    static ref FISH_SUBCOMMAND_CONDITION: Regex =
+        Regex::new(r"(not\s+)?__fish_(?:seen_subcommand_from|\w+_using_subcommand)\s+([^;|&]*)").unwrap();
+
+    /// fish conditions that hold while no subcommand has been given yet
+    static ref FISH_ROOT_CONDITION: Regex =
+        Regex::new(r"__fish_use_subcommand|_needs_command|not\s+__fish_seen_subcommand_from").unwrap();
+}
+
+/// Shells whose completion scripts can be analyzed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl CompletionShell {
+    /// All supported shells, in the order completions are requested
+    pub const ALL: [CompletionShell; 3] = [Self::Bash, Self::Zsh, Self::Fish];
+
+    /// Shell name as passed to completion generators (`tool completion bash`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Zsh => "zsh",
+            Self::Fish => "fish",
+        }
+    }
+
+    /// Whether `output` looks like a completion script for this shell
+    ///
+    /// Completion commands are invoked by guessing; error messages and usage
+    /// text printed instead of a script are rejected here.
+    pub fn recognizes(&self, output: &str) -> bool {
+        match self {
+            Self::Bash => output.contains("complete -") && output.contains("COMPREPLY"),
+            Self::Zsh => output.contains("#compdef") || output.contains("_arguments"),
+            Self::Fish => output
+                .lines()
+                .any(|l| l.trim_start().starts_with("complete ")),
+        }
+    }
+}
+
+impl fmt::Display for CompletionShell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options and subcommands offered by a shell completion script
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Completions {
+    /// Completions per command, in script order
+    pub commands: Vec<CompletionCommand>,
+}
+
+/// Completions offered for one command of the CLI
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompletionCommand {
+    /// Subcommand path (empty for the binary itself)
+    pub path: Vec<String>,
+
+    /// Options completed for this command
+    pub options: Vec<CliOption>,
+
+    /// Subcommand names completed for this command
+    pub subcommands: Vec<String>,
+}
+
+impl Completions {
+    /// Whether the script offered no options or subcommands at all
+    pub fn is_empty(&self) -> bool {
+        self.commands
+            .iter()
+            .all(|c| c.options.is_empty() && c.subcommands.is_empty())
+    }
+
+    /// Merge into an analysis built from `--help` output
+    ///
+    /// Options already known keep their help text and only gain missing details;
+    /// a String option gains the completion's enum candidates. Options and
+    /// subcommands that only the completion script offers are added and logged as
+    /// undocumented, with options marked [`OptionSource::Completion`]. Completions
+    /// below a `help` subcommand mirror the real commands and are ignored.
+    pub fn merge_into(self, analysis: &mut CliAnalysis) {
+        for command in self.commands {
+            if command.path.iter().any(|name| name == "help") {
+                continue;
+            }
+
+            let (options, subcommands) = command_mut(analysis, &command.path);
+            let depth = command.path.len() as u8;
+            for name in command.subcommands {
+                if name != "help" {
+                    subcommand_mut(subcommands, &name, depth, &command.path);
+                }
+            }
+
+            for option in command.options {
+                match options
+                    .iter_mut()
+                    .find(|existing| same_option(existing, &option))
+                {
+                    Some(existing) => {
+                        existing.short = existing.short.take().or(option.short);
+                        existing.description = existing.description.take().or(option.description);
+                        if existing.option_type == OptionType::String {
+                            if let OptionType::Enum { .. } = option.option_type {
+                                existing.option_type = option.option_type;
+                            }
+                        }
+                    }
+                    None => {
+                        log::info!(
+                            "Undocumented option {} found in completions{}",
+                            display_name(&option),
+                            command_suffix(&command.path)
+                        );
+                        options.push(option);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Options and subcommands of the command at `path`, creating missing subcommands
+fn command_mut<'a>(
+    analysis: &'a mut CliAnalysis,
+    path: &[String],
+) -> (&'a mut Vec<CliOption>, &'a mut Vec<Subcommand>) {
+    let mut options = &mut analysis.global_options;
+    let mut subcommands = &mut analysis.subcommands;
+    for (depth, name) in path.iter().enumerate() {
+        let subcommand = subcommand_mut(subcommands, name, depth as u8, &path[..depth]);
+        options = &mut subcommand.options;
+        subcommands = &mut subcommand.subcommands;
+    }
+    (options, subcommands)
+}
+
+/// Subcommand `name` in `subcommands`, added if only the completion script knows it
+fn subcommand_mut<'a>(
+    subcommands: &'a mut Vec<Subcommand>,
+    name: &str,
+    depth: u8,
+    parents: &[String],
+) -> &'a mut Subcommand {
+    match subcommands.iter().position(|s| s.name == name) {
+        Some(index) => &mut subcommands[index],
+        None => {
+            log::info!(
+                "Undocumented subcommand {} found in completions{}",
+                name,
+                command_suffix(parents)
+            );
+            subcommands.push(Subcommand {
+                name: name.to_string(),
+                description: None,
+                options: Vec::new(),
+                required_args: Vec::new(),
+                positional_args: Vec::new(),
+                option_relations: Vec::new(),
+                subcommands: Vec::new(),
+                depth,
+            });
+            subcommands.last_mut().unwrap()
+        }
+    }
+}
+
+/// " of `a b`" for log messages about subcommand `a b`
+fn command_suffix(path: &[String]) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(" of `{}`", path.join(" "))
+    }
+}
+
+/// Preferred display name of an option (long form, else short)
+fn display_name(option: &CliOption) -> &str {
+    option
+        .long
+        .as_deref()
+        .or(option.short.as_deref())
+        .unwrap_or("?")
+}
+
+/// Parse a completion script generated for `shell`
+pub fn parse_completion_script(shell: CompletionShell, script: &str) -> Completions {
+    let mut builder = Builder::default();
+    match shell {
+        CompletionShell::Bash => parse_bash(script, &mut builder),
+        CompletionShell::Zsh => parse_zsh(script, &mut builder),
+        CompletionShell::Fish => parse_fish(script, &mut builder),
+    }
+    builder.finish()
+}
+
+/// Collects options and subcommands per command path
+#[derive(Default)]
+struct Builder {
+    commands: Vec<CompletionCommand>,
+}
+
+impl Builder {
+    fn command(&mut self, path: &[String]) -> &mut CompletionCommand {
+        match self.commands.iter().position(|c| c.path == path) {
+            Some(index) => &mut self.commands[index],
+            None => {
+                self.commands.push(CompletionCommand {
+                    path: path.to_vec(),
+                    ..Default::default()
+                });
+                self.commands.last_mut().unwrap()
+            }
+        }
+    }
+
+    /// Add an option, combining it with an earlier entry for the same option
+    fn option(&mut self, path: &[String], option: CliOption) {
+        let options = &mut self.command(path).options;
+        match options
+            .iter_mut()
+            .find(|existing| same_option(existing, &option))
+        {
+            Some(existing) => {
+                existing.short = existing.short.take().or(option.short);
+                existing.description = existing.description.take().or(option.description);
+                if existing.option_type == OptionType::Flag
+                    || (existing.option_type == OptionType::String
+                        && option.option_type != OptionType::Flag)
+                {
+                    existing.option_type = option.option_type;
+                }
+            }
+            None => options.push(option),
+        }
+    }
+
+    fn subcommand(&mut self, path: &[String], name: &str) {
+        let subcommands = &mut self.command(path).subcommands;
+        if !subcommands.iter().any(|s| s == name) {
+            subcommands.push(name.to_string());
+        }
+    }
+
+    fn finish(self) -> Completions {
+        Completions {
+            commands: self.commands,
+        }
+    }
+}
+
+/// Build an option from a completion entry
+fn completion_option(
+    name: &str,
+    description: Option<String>,
+    option_type: OptionType,
+) -> Option<CliOption> {
+    let (short, long) = if name.starts_with("--") {
+        (None, Some(name.to_string()))
+    } else if name.len() == 2 {
+        (Some(name.to_string()), None)
+    } else {
+        // Single-dash long options (-name) have no CliOption representation
+        return None;
+    };
+
+    Some(CliOption {
+        short,
+        long,
+        description: description.filter(|d| !d.is_empty()),
+        option_type,
+        required: false,
+        default_value: None,
+        env_var: None,
+        source: OptionSource::Completion,
+    })
+}
+
+/// Enum type from candidate values (String if there are none)
+fn enum_or_string(values: Vec<String>) -> OptionType {
+    if values.is_empty() {
+        OptionType::String
+    } else {
+        OptionType::Enum { values }
+    }
+}
+
+/// Word of a shell command
+#[derive(Debug, Clone, PartialEq)]
+struct Word {
+    text: String,
+    quoted: bool,
+}
+
+/// Accumulates words and commands while splitting a script
+#[derive(Default)]
+struct CommandSplitter {
+    commands: Vec<Vec<Word>>,
+    command: Vec<Word>,
+    word: String,
+    in_word: bool,
+    quoted: bool,
+}
+
+impl CommandSplitter {
+    fn end_word(&mut self) {
+        if self.in_word {
+            self.command.push(Word {
+                text: std::mem::take(&mut self.word),
+                quoted: self.quoted,
+            });
+            self.in_word = false;
+            self.quoted = false;
+        }
+    }
+
+    fn end_command(&mut self) {
+        self.end_word();
+        if !self.command.is_empty() {
+            self.commands.push(std::mem::take(&mut self.command));
+        }
+    }
+}
+
+/// Split a script into commands of words, honouring quotes, escapes, line
+/// continuations and comments
+///
+/// Commands end at unquoted newlines, `;` and `&&`; the `;;` ending a `case`
+/// arm is kept as a command of its own. Expansions are kept verbatim; this is
+/// enough to read the literal lists completion scripts use.
+fn shell_commands(script: &str) -> Vec<Vec<Word>> {
+    let mut splitter = CommandSplitter::default();
+    let mut chars = script.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' | '\r' => splitter.end_word(),
+            ';' if chars.peek() == Some(&';') => {
+                chars.next();
+                splitter.end_command();
+                splitter.commands.push(vec![Word {
+                    text: ";;".to_string(),
+                    quoted: false,
+                }]);
+            }
+            '\n' | ';' => splitter.end_command(),
+            '&' if chars.peek() == Some(&'&') => {
+                chars.next();
+                splitter.end_command();
+            }
+            '#' if !splitter.in_word => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') | None => {}
+                Some(escaped) => {
+                    splitter.word.push(escaped);
+                    splitter.in_word = true;
+                }
+            },
+            '\'' => {
+                splitter.in_word = true;
+                splitter.quoted = true;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    splitter.word.push(c);
+                }
+            }
+            '"' => {
+                splitter.in_word = true;
+                splitter.quoted = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.peek() {
+                            Some(&next @ ('"' | '\\' | '$' | '`')) => {
+                                splitter.word.push(next);
+                                chars.next();
+                            }
+                            Some('\n') => {
+                                chars.next();
+                            }
+                            _ => splitter.word.push('\\'),
+                        },
+                        _ => splitter.word.push(c),
+                    }
+                }
+            }
+            _ => {
+                splitter.word.push(c);
+                splitter.in_word = true;
+            }
+        }
+    }
+    splitter.end_command();
+
+    splitter.commands
+}
+
+/// Pattern of a `case` arm label (`--format)`, `(analyze)`, `-o|--output)`)
+fn arm_label(word: &Word) -> Option<&str> {
+    let label = word.text.strip_suffix(')')?;
+    let label = label.strip_prefix('(').unwrap_or(label);
+    if label.is_empty() || (label.contains(['=', '(', ')', ' ', '$']) && !label.starts_with(",$")) {
+        return None;
+    }
+    Some(label)
+}
+
+/// Option names of a `case` arm label, if all alternatives are options
+fn option_label(label: &str) -> Option<Vec<&str>> {
+    let names: Vec<&str> = label.split('|').collect();
+    names
+        .iter()
+        .all(|name| OPTION_NAME.is_match(name))
+        .then_some(names)
+}
+
+/// Parse a bash completion script
+///
+/// clap-style scripts resolve the command being completed in a first `case`
+/// (`cli,analyze) cmd="cli__analyze"`) and list each command's words in a second
+/// one (`cli__analyze) opts="-o --output ..."`); options are attributed to that
+/// command. Other scripts are read as completing the binary itself. Option
+/// values come from `--option) ... compgen -W "a b c"` arms.
+fn parse_bash(script: &str, builder: &mut Builder) {
+    let commands = shell_commands(script);
+
+    // `parent,word) cmd="child"` arms map each command variable to its path
+    let mut children: Vec<(&str, &str, &str)> = Vec::new();
+    for (index, command) in commands.iter().enumerate() {
+        let Some((parent, word)) = command
+            .first()
+            .and_then(arm_label)
+            .and_then(|label| label.split_once(','))
+        else {
+            continue;
+        };
+        if let Some(child) = arm_body(&commands, index).and_then(|w| w.text.strip_prefix("cmd=")) {
+            children.push((child, parent, word));
+        }
+    }
+    let parents: HashMap<&str, (&str, &str)> = children
+        .iter()
+        .map(|&(child, parent, word)| (child, (parent, word)))
+        .collect();
+    let resolve = |cmd: &str| -> Option<Vec<String>> {
+        let mut path = Vec::new();
+        let mut current = cmd;
+        while let Some(&(parent, word)) = parents.get(current) {
+            if parent.is_empty() {
+                path.reverse();
+                return Some(path);
+            }
+            path.push(word.to_string());
+            current = parent;
+        }
+        None
+    };
+    for &(_, parent, word) in &children {
+        if let Some(parent_path) = resolve(parent).filter(|_| !parent.is_empty()) {
+            builder.subcommand(&parent_path, word);
+        }
+    }
+
+    // `None` inside the arm of a command whose path could not be resolved
+    let mut path: Option<Vec<String>> = Some(Vec::new());
+    let mut arm_options: Vec<&str> = Vec::new();
+    for (index, command) in commands.iter().enumerate() {
+        let mut words = command.as_slice();
+        if matches!(words.first().map(|w| w.text.as_str()), Some(";;" | "esac")) {
+            arm_options.clear();
+            continue;
+        }
+        if let Some(label) = words.first().and_then(arm_label) {
+            words = &words[1..];
+            arm_options.clear();
+            if let Some(command_path) = resolve(label) {
+                path = Some(command_path);
+            } else if let Some(names) = option_label(label) {
+                arm_options = names;
+            } else if arm_body(&commands, index).is_some_and(|w| w.text.starts_with("opts=")) {
+                path = None;
+            }
+        }
+        let Some(path) = &path else {
+            continue;
+        };
+
+        for (index, word) in words.iter().enumerate() {
+            let text = word.text.as_str();
+            if let Some(list) = text.strip_prefix("opts=") {
+                for name in list.split_whitespace() {
+                    add_bash_option(builder, path, name, OptionType::Flag);
+                }
+            } else if text.ends_with("compgen") {
+                let (flag, list) = match words.get(index + 1..index + 3) {
+                    Some([flag, list]) => (flag.text.as_str(), list.text.as_str()),
+                    _ => (words.get(index + 1).map_or("", |w| w.text.as_str()), ""),
+                };
+                if flag == "-W" && !list.starts_with('$') {
+                    if arm_options.is_empty() {
+                        for name in list.split_whitespace() {
+                            add_bash_option(builder, path, name, OptionType::Flag);
+                        }
+                    } else {
+                        let values = list.split_whitespace().map(str::to_string).collect();
+                        let option_type = enum_or_string(values);
+                        for name in &arm_options {
+                            add_bash_option(builder, path, name, option_type.clone());
+                        }
+                    }
+                } else if !arm_options.is_empty() {
+                    // `compgen -f` is emitted for any value; only `-d` is a real hint
+                    let option_type = if flag == "-d" {
+                        OptionType::Path
+                    } else {
+                        OptionType::String
+                    };
+                    for name in &arm_options {
+                        add_bash_option(builder, path, name, option_type.clone());
+                    }
+                }
+            } else if text.starts_with("_filedir") {
+                for name in &arm_options {
+                    add_bash_option(builder, path, name, OptionType::Path);
+                }
+            }
+        }
+    }
+}
+
+/// First word after the `case` arm label of `commands[index]` (same line or next)
+fn arm_body(commands: &[Vec<Word>], index: usize) -> Option<&Word> {
+    commands[index]
+        .get(1)
+        .or_else(|| commands.get(index + 1).and_then(|c| c.first()))
+}
+
+/// Add a bash option word (`--output=` takes a value)
+fn add_bash_option(builder: &mut Builder, path: &[String], word: &str, option_type: OptionType) {
+    let Some(captures) = OPTION_NAME.captures(word) else {
+        return;
+    };
+    let option_type = match (&option_type, captures.get(2)) {
+        (OptionType::Flag, Some(_)) => OptionType::String,
+        _ => option_type,
+    };
+    if let Some(option) = completion_option(&captures[1], None, option_type) {
+        builder.option(path, option);
+    }
+}
+
+/// `case` statement being read in a zsh script
+struct ZshCase {
+    /// Whether arms are subcommand names (`case $line[1] in`)
+    subcommands: bool,
+
+    /// Command path outside the `case`
+    parent: Vec<String>,
+}
+
+/// Parse a zsh completion script
+///
+/// Options come from `_arguments` specs. Specs inside an arm of
+/// `case $line[1] in` / `case $words[1] in` belong to the subcommand named by
+/// the arm (clap, cobra and hand-written `_arguments -C` scripts all use this).
+fn parse_zsh(script: &str, builder: &mut Builder) {
+    let mut path: Vec<String> = Vec::new();
+    let mut cases: Vec<ZshCase> = Vec::new();
+
+    for command in shell_commands(script) {
+        let mut words = command.as_slice();
+        if let [first, subject, keyword, ..] = words {
+            if first.text == "case" && keyword.text == "in" {
+                cases.push(ZshCase {
+                    subcommands: subject.text.contains("line[1]")
+                        || subject.text.contains("words[1]"),
+                    parent: path.clone(),
+                });
+                words = &words[3..];
+            }
+        }
+
+        for (index, word) in words.iter().enumerate() {
+            if word.text == "esac" && !word.quoted {
+                if let Some(case) = cases.pop() {
+                    path = case.parent;
+                }
+                continue;
+            }
+
+            if index == 0 && !word.quoted {
+                if let Some(case) = cases.last().filter(|case| case.subcommands) {
+                    if let Some(label) = arm_label(word) {
+                        let name = label.split('|').next().unwrap_or(label);
+                        if name != "*" && !name.starts_with('-') {
+                            let parent = case.parent.clone();
+                            builder.subcommand(&parent, name);
+                            path = parent;
+                            path.push(name.to_string());
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            if word.text == "_arguments" {
+                let mut previous: Option<CliOption> = None;
+                for spec in &words[index + 1..] {
+                    for option in parse_zsh_spec(&spec.text) {
+                        // clap lists -o and --output as separate specs with one description
+                        let option = match previous.take() {
+                            Some(short)
+                                if short.long.is_none()
+                                    && option.short.is_none()
+                                    && short.description == option.description =>
+                            {
+                                CliOption {
+                                    short: short.short,
+                                    ..option
+                                }
+                            }
+                            Some(other) => {
+                                builder.option(&path, other);
+                                option
+                            }
+                            None => option,
+                        };
+                        previous = Some(option);
+                    }
+                }
+                if let Some(option) = previous {
+                    builder.option(&path, option);
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// Parse one `_arguments` option spec
+///
+/// `'(-v --verbose)'{-v,--verbose}'[Enable verbose output]'`,
+/// `'--format=[Output format]:FORMAT:(json yaml)'`, `'*-I+[Include]:dir:_files -/'`
+fn parse_zsh_spec(spec: &str) -> Vec<CliOption> {
+    let mut rest = spec;
+    if rest.starts_with('(') {
+        match rest.find(')') {
+            Some(end) => rest = &rest[end + 1..],
+            None => return Vec::new(),
+        }
+    }
+    rest = rest.trim_start_matches('*');
+
+    let (names, mut rest): (Vec<&str>, &str) = if let Some(braced) = rest.strip_prefix('{') {
+        match braced.split_once('}') {
+            Some((names, rest)) => (names.split(',').collect(), rest),
+            None => return Vec::new(),
+        }
+    } else {
+        let end = rest.find(['[', ':']).unwrap_or(rest.len());
+        (vec![&rest[..end]], &rest[end..])
+    };
+
+    let mut description = None;
+    if let Some(bracketed) = rest.strip_prefix('[') {
+        let end = unescaped_position(bracketed, ']').unwrap_or(bracketed.len());
+        description = Some(unescape_zsh(&bracketed[..end]));
+        rest = bracketed.get(end + 1..).unwrap_or("");
+    }
+
+    let value_type = rest.strip_prefix(':').map(|argument| {
+        let action = unescaped_position(argument, ':')
+            .map(|end| argument[end + 1..].trim())
+            .unwrap_or("");
+        zsh_action_type(action)
+    });
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let captures = ZSH_OPTION_NAME.captures(name)?;
+            let option_type = match (&value_type, captures.get(2)) {
+                (Some(option_type), _) => option_type.clone(),
+                (None, Some(_)) => OptionType::String,
+                (None, None) => OptionType::Flag,
+            };
+            completion_option(&captures[1], description.clone(), option_type)
+        })
+        .collect()
+}
+
+/// Option type implied by a zsh completion action
+fn zsh_action_type(action: &str) -> OptionType {
+    if let Some(described) = action.strip_prefix("((") {
+        let values = ZSH_DESCRIBED_VALUE
+            .captures_iter(described.trim_end_matches(')'))
+            .map(|c| c[1].to_string())
+            .collect();
+        enum_or_string(values)
+    } else if let Some(list) = action.strip_prefix('(') {
+        let values = list
+            .trim_end_matches(')')
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        enum_or_string(values)
+    } else if ["_files", "_directories", "_path_files"]
+        .iter()
+        .any(|helper| action.starts_with(helper))
+    {
+        OptionType::Path
+    } else {
+        OptionType::String
+    }
+}
+
+/// Byte position of the first `target` not escaped by a backslash
+fn unescaped_position(text: &str, target: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            c if c == target && !escaped => return Some(index),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+/// Remove `_arguments` escapes (`\]`, `\:`) from a description
+fn unescape_zsh(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            _ => result.push(c),
+        }
+    }
+    result.trim().to_string()
+}
+
+/// Parse a fish completion script
+///
+/// Each `complete` command describes one option (`-s`/`-l`) or offers
+/// arguments (`-a`). Its `-n` condition tells which subcommand it applies to:
+/// `__fish_seen_subcommand_from NAME` or clap's `__fish_<cmd>_using_subcommand
+/// NAME`. Arguments offered while no subcommand was given yet are subcommands.
+fn parse_fish(script: &str, builder: &mut Builder) {
+    for command in shell_commands(script) {
+        if command.first().map(|w| w.text.as_str()) != Some("complete") {
+            continue;
+        }
+
+        let mut condition = "";
+        let mut short = None;
+        let mut long = None;
+        let mut arguments = None;
+        let mut description = None;
+        let mut requires_value = false;
+        let mut files = false;
+
+        let mut words = command[1..].iter().map(|w| w.text.as_str());
+        while let Some(word) = words.next() {
+            let (flag, inline) = match word.strip_prefix("--") {
+                Some(long_flag) => match long_flag.split_once('=') {
+                    Some((name, value)) => (fish_long_flag(name), Some(value)),
+                    None => (fish_long_flag(long_flag), None),
+                },
+                None => match word.strip_prefix('-') {
+                    Some(cluster) if !cluster.is_empty() => {
+                        // Short flags may be clustered: -rfa "values"
+                        let mut flag = ' ';
+                        let mut inline = None;
+                        for (index, c) in cluster.char_indices() {
+                            match c {
+                                'r' => requires_value = true,
+                                'x' => requires_value = true,
+                                'F' => files = true,
+                                c => {
+                                    flag = c;
+                                    let value = &cluster[index + c.len_utf8()..];
+                                    inline = (!value.is_empty()).then_some(value);
+                                    break;
+                                }
+                            }
+                        }
+                        (flag, inline)
+                    }
+                    _ => continue,
+                },
+            };
+            let takes_value = matches!(flag, 'c' | 'n' | 's' | 'l' | 'o' | 'a' | 'd' | 'w');
+            let value = if takes_value {
+                inline.or_else(|| words.next())
+            } else {
+                None
+            };
+            match (flag, value) {
+                ('n', Some(value)) => condition = value,
+                ('s', Some(value)) => short = Some(format!("-{}", value)),
+                ('l', Some(value)) => long = Some(format!("--{}", value)),
+                ('a', Some(value)) => arguments = Some(value),
+                ('d', Some(value)) => description = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        let Some(path) = fish_condition_path(condition) else {
+            continue;
+        };
+        let values = arguments.map(fish_arguments).unwrap_or_default();
+
+        if short.is_some() || long.is_some() {
+            let option_type = if !values.is_empty() {
+                OptionType::Enum { values }
+            } else if requires_value && files {
+                OptionType::Path
+            } else if requires_value || arguments.is_some() {
+                OptionType::String
+            } else {
+                OptionType::Flag
+            };
+            let name = long.as_deref().or(short.as_deref()).unwrap_or_default();
+            if let Some(mut option) = completion_option(name, description, option_type) {
+                option.short = short;
+                builder.option(&path, option);
+            }
+        } else if FISH_ROOT_CONDITION.is_match(condition) || condition.is_empty() {
+            for name in values {
+                builder.subcommand(&path, &name);
+            }
+        }
+    }
+}
+
+/// Short flag equivalent of a `complete` long flag
+fn fish_long_flag(name: &str) -> char {
+    match name {
+        "command" => 'c',
+        "condition" => 'n',
+        "short-option" => 's',
+        "long-option" => 'l',
+        "old-option" => 'o',
+        "arguments" => 'a',
+        "description" => 'd',
+        "wraps" => 'w',
+        "require-parameter" | "exclusive" => 'r',
+        "force-files" => 'F',
+        _ => ' ',
+    }
+}
+
+/// Subcommand path selected by a `complete -n` condition
+///
+/// Returns `None` for conditions that cannot be attributed to a command.
+fn fish_condition_path(condition: &str) -> Option<Vec<String>> {
+    let mut path = Vec::new();
+    for captures in FISH_SUBCOMMAND_CONDITION.captures_iter(condition) {
+        if captures.get(1).is_none() {
+            path.push(captures[2].split_whitespace().next()?.to_string());
+        }
+    }
+
+    let attributable = condition
+        .split([';', '|', '&'])
+        .map(|clause| {
+            let clause = clause.trim();
+            let clause = clause.strip_prefix("and ").unwrap_or(clause).trim_start();
+            clause.strip_prefix("not ").unwrap_or(clause).trim_start()
+        })
+        .filter(|clause| !clause.is_empty())
+        .all(|clause| {
+            FISH_SUBCOMMAND_CONDITION.is_match(clause) || FISH_ROOT_CONDITION.is_match(clause)
+        });
+    attributable.then_some(path)
+}
+
+/// Candidate values of `complete -a`, without their `\t` descriptions
+///
+/// Command substitutions (`(__fish_complete_path)`) yield no candidates.
+fn fish_arguments(arguments: &str) -> Vec<String> {
+    if arguments.trim_start().starts_with('(') {
+        return Vec::new();
+    }
+    arguments
+        .lines()
+        .flat_map(|line| {
+            let value = line
+                .split_once("\\t")
+                .or_else(|| line.split_once('\t'))
+                .map_or(line, |(value, _)| value);
+            value.split_whitespace()
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn command<'a>(completions: &'a Completions, path: &[&str]) -> &'a CompletionCommand {
+        completions
+            .commands
+            .iter()
+            .find(|c| c.path == path)
+            .unwrap_or_else(|| panic!("no completions for {:?}", path))
+    }
+
+    fn option<'a>(command: &'a CompletionCommand, long: &str) -> &'a CliOption {
+        command
+            .options
+            .iter()
+            .find(|o| o.long.as_deref() == Some(long))
+            .unwrap_or_else(|| panic!("option {} not found", long))
+    }
+
+    #[test]
+    fn test_shell_commands() {
+        let commands = shell_commands(
+            "complete -c tool -d 'It'\\''s' # comment\nopts=\"-a \\\"b\\\"\" && echo \\\n x; y;; z",
+        );
+        let texts: Vec<Vec<&str>> = commands
+            .iter()
+            .map(|c| c.iter().map(|w| w.text.as_str()).collect())
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                vec!["complete", "-c", "tool", "-d", "It's"],
+                vec!["opts=-a \"b\""],
+                vec!["echo", "x"],
+                vec!["y"],
+                vec![";;"],
+                vec!["z"],
+            ]
+        );
+    }
+
+    const BASH: &str = r#"
+_tool() {
+    local i cur prev opts cmd
+    for i in "${COMP_WORDS[@]:0:COMP_CWORD}"
+    do
+        case "${cmd},${i}" in
+            ",$1")
+                cmd="tool"
+                ;;
+            tool,build)
+                cmd="tool__build"
+                ;;
+            *)
+                ;;
+        esac
+    done
+
+    case "${cmd}" in
+        tool)
+            opts="-v -h --verbose --help --color build"
+            case "${prev}" in
+                --color)
+                    COMPREPLY=($(compgen -W "auto always never" -- "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tool__build)
+            opts="-o -h --out-dir --release --help"
+            case "${prev}" in
+                --out-dir|-o)
+                    COMPREPLY=($(compgen -d "${cur}"))
+                    return 0
+                    ;;
+            esac
+            ;;
+    esac
+}
+complete -F _tool -o bashdefault -o default tool
+"#;
+
+    #[test]
+    fn test_parse_bash_clap_style() {
+        assert!(CompletionShell::Bash.recognizes(BASH));
+        let completions = parse_completion_script(CompletionShell::Bash, BASH);
+
+        let root = command(&completions, &[]);
+        assert_eq!(root.subcommands, vec!["build"]);
+        assert_eq!(option(root, "--verbose").option_type, OptionType::Flag);
+        assert_eq!(
+            option(root, "--color").option_type,
+            OptionType::Enum {
+                values: vec!["auto".into(), "always".into(), "never".into()]
+            }
+        );
+        assert!(root
+            .options
+            .iter()
+            .any(|o| o.short.as_deref() == Some("-v")));
+
+        let build = command(&completions, &["build"]);
+        assert_eq!(option(build, "--out-dir").option_type, OptionType::Path);
+        assert_eq!(option(build, "--release").option_type, OptionType::Flag);
+        assert!(root
+            .options
+            .iter()
+            .all(|o| o.long.as_deref() != Some("--release")));
+    }
+
+    #[test]
+    fn test_parse_bash_hand_written() {
+        let script = r#"
+_mytool() {
+    local cur prev
+    _init_completion || return
+    case $prev in
+        --config)
+            _filedir
+            return
+            ;;
+        --level)
+            COMPREPLY=( $(compgen -W 'debug info warn' -- "$cur") )
+            return
+            ;;
+    esac
+    COMPREPLY=( $(compgen -W '--config --level --dry-run --help' -- "$cur") )
+}
+complete -F _mytool mytool
+"#;
+        let completions = parse_completion_script(CompletionShell::Bash, script);
+        let root = command(&completions, &[]);
+        assert_eq!(option(root, "--config").option_type, OptionType::Path);
+        assert_eq!(
+            option(root, "--level").option_type,
+            OptionType::Enum {
+                values: vec!["debug".into(), "info".into(), "warn".into()]
+            }
+        );
+        assert_eq!(option(root, "--dry-run").option_type, OptionType::Flag);
+    }
+
+    const ZSH: &str = r#"#compdef tool
+
+_tool() {
+    local context curcontext="$curcontext" state line
+    _arguments "${_arguments_options[@]}" : \
+'--color=[When to use colors]:WHEN:(auto always never)' \
+'-v[Enable verbose output]' \
+'--verbose[Enable verbose output]' \
+'-h[Print help (see more with '\''--help'\'')]' \
+'--help[Print help (see more with '\''--help'\'')]' \
+":: :_tool_commands" \
+"*::: :->tool" \
+&& ret=0
+    case $state in
+    (tool)
+        words=($line[1] "${words[@]}")
+        case $line[1] in
+            (build)
+_arguments "${_arguments_options[@]}" : \
+'-o+[Output directory]:DIR:_files' \
+'--out-dir=[Output directory]:DIR:_files' \
+'--profile=[Build profile]:PROFILE:((dev\:"Fast builds"
+release\:"Optimized"))' \
+'(-q --quiet)'{-q,--quiet}'[Suppress output]' \
+'*--feature=[Feature to enable]:FEATURE:_default' \
+&& ret=0
+;;
+            (help)
+_arguments "${_arguments_options[@]}" : \
+":: :_tool__help_commands" \
+"*::: :->help" \
+&& ret=0
+    case $state in
+    (help)
+        case $line[1] in
+            (build)
+_arguments "${_arguments_options[@]}" : \
+&& ret=0
+;;
+        esac
+    ;;
+esac
+;;
+        esac
+    ;;
+esac
+}
+"#;
+
+    #[test]
+    fn test_parse_zsh_clap_style() {
+        assert!(CompletionShell::Zsh.recognizes(ZSH));
+        let completions = parse_completion_script(CompletionShell::Zsh, ZSH);
+
+        let root = command(&completions, &[]);
+        assert_eq!(root.subcommands, vec!["build", "help"]);
+        let verbose = option(root, "--verbose");
+        assert_eq!(verbose.short.as_deref(), Some("-v"));
+        assert_eq!(
+            verbose.description.as_deref(),
+            Some("Enable verbose output")
+        );
+        assert_eq!(
+            option(root, "--help").description.as_deref(),
+            Some("Print help (see more with '--help')")
+        );
+        assert_eq!(
+            option(root, "--color").option_type,
+            OptionType::Enum {
+                values: vec!["auto".into(), "always".into(), "never".into()]
+            }
+        );
+
+        let build = command(&completions, &["build"]);
+        let out_dir = option(build, "--out-dir");
+        assert_eq!(out_dir.short.as_deref(), Some("-o"));
+        assert_eq!(out_dir.option_type, OptionType::Path);
+        assert_eq!(
+            option(build, "--profile").option_type,
+            OptionType::Enum {
+                values: vec!["dev".into(), "release".into()]
+            }
+        );
+        assert_eq!(option(build, "--quiet").short.as_deref(), Some("-q"));
+        assert_eq!(option(build, "--feature").option_type, OptionType::String);
+
+        assert_eq!(command(&completions, &["help"]).subcommands, vec!["build"]);
+    }
+
+    const FISH: &str = r#"
+complete -c tool -n "__fish_tool_needs_command" -s v -l verbose -d 'Enable verbose output'
+complete -c tool -n "__fish_tool_needs_command" -l color -d 'When to use colors' -r -f -a "auto\t''
+always\t''
+never\t''"
+complete -c tool -n "__fish_tool_needs_command" -l debug-internals
+complete -c tool -n "__fish_tool_needs_command" -f -a "build" -d 'Build the project'
+complete -c tool -n "__fish_tool_using_subcommand build" -s o -l out-dir -d 'Output directory' -r -F
+complete -c tool -n "__fish_tool_using_subcommand build" -l jobs -d 'Parallel jobs' -r
+complete -c tool -n "__fish_seen_subcommand_from build" -f -a "(__fish_complete_path)"
+complete -c tool -n "__fish_tool_using_subcommand help; and not __fish_seen_subcommand_from build help" -f -a "build"
+complete -c tool -n "__fish_contains_opt color" -l theme -r
+"#;
+
+    #[test]
+    fn test_parse_fish_clap_style() {
+        assert!(CompletionShell::Fish.recognizes(FISH));
+        let completions = parse_completion_script(CompletionShell::Fish, FISH);
+
+        let root = command(&completions, &[]);
+        assert_eq!(root.subcommands, vec!["build"]);
+        assert_eq!(option(root, "--verbose").short.as_deref(), Some("-v"));
+        assert_eq!(
+            option(root, "--color").option_type,
+            OptionType::Enum {
+                values: vec!["auto".into(), "always".into(), "never".into()]
+            }
+        );
+        assert_eq!(option(root, "--debug-internals").description, None);
+        // Conditions on other state cannot be attributed to a command
+        assert!(root
+            .options
+            .iter()
+            .all(|o| o.long.as_deref() != Some("--theme")));
+
+        let build = command(&completions, &["build"]);
+        assert_eq!(option(build, "--out-dir").option_type, OptionType::Path);
+        assert_eq!(option(build, "--jobs").option_type, OptionType::String);
+        assert!(build.subcommands.is_empty());
+
+        assert_eq!(command(&completions, &["help"]).subcommands, vec!["build"]);
+    }
+
+    #[test]
+    fn test_recognizes_rejects_error_output() {
+        let error = "error: unrecognized subcommand 'completion'\n\nUsage: tool [OPTIONS]\n";
+        for shell in CompletionShell::ALL {
+            assert!(!shell.recognizes(error), "{} accepted an error", shell);
+        }
+    }
+
+    #[test]
+    fn test_merge_into_flags_undocumented() {
+        let mut analysis =
+            CliAnalysis::new(PathBuf::from("/bin/tool"), "tool".into(), String::new());
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--color".to_string()),
+            description: Some("Colorize output".to_string()),
+            option_type: OptionType::String,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        });
+        analysis.subcommands.push(Subcommand {
+            name: "build".to_string(),
+            description: Some("Build the project".to_string()),
+            options: Vec::new(),
+            required_args: Vec::new(),
+            positional_args: Vec::new(),
+            option_relations: Vec::new(),
+            subcommands: Vec::new(),
+            depth: 0,
+        });
+
+        parse_completion_script(CompletionShell::Fish, FISH).merge_into(&mut analysis);
+
+        let color = &analysis.global_options[0];
+        assert_eq!(color.source, OptionSource::Help);
+        assert_eq!(color.description.as_deref(), Some("Colorize output"));
+        assert!(matches!(color.option_type, OptionType::Enum { .. }));
+
+        let debug = analysis
+            .global_options
+            .iter()
+            .find(|o| o.long.as_deref() == Some("--debug-internals"))
+            .unwrap();
+        assert_eq!(debug.source, OptionSource::Completion);
+
+        // Completions for `help build` mirror `build` and add nothing
+        assert_eq!(analysis.subcommands.len(), 1);
+        let build = &analysis.subcommands[0];
+        assert_eq!(build.description.as_deref(), Some("Build the project"));
+        assert_eq!(build.options.len(), 2);
+        assert!(build.subcommands.is_empty());
+    }
+
+    #[test]
+    fn test_merge_into_adds_subcommands() {
+        let mut analysis =
+            CliAnalysis::new(PathBuf::from("/bin/tool"), "tool".into(), String::new());
+        parse_completion_script(CompletionShell::Zsh, ZSH).merge_into(&mut analysis);
+
+        assert_eq!(analysis.subcommands.len(), 1);
+        assert_eq!(analysis.subcommands[0].name, "build");
+        assert_eq!(analysis.subcommands[0].depth, 0);
+        assert_eq!(analysis.subcommands[0].options.len(), 4);
+    }
+}
@@ -0,0 +1,711 @@
+use crate::analyzer::cli_parser::{option_spec, CliParser};
+use crate::types::analysis::{CliFramework, CliOption, OptionType};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Help-text fingerprints, checked in order (the first match wins)
+    ///
+    /// Most frameworks word the built-in `--help` description in their own way,
+    /// which makes it the most reliable signal.
+    static ref FINGERPRINTS: Vec<(CliFramework, Regex)> = vec![
+        (CliFramework::Argparse, Regex::new(r"show this help message and exit").unwrap()),
+        (CliFramework::Click, Regex::new(r"Show this message and exit\.").unwrap()),
+        (CliFramework::Commander, Regex::new(r"display help for command|output usage information").unwrap()),
+        (
+            CliFramework::Cobra,
+            Regex::new(r#"(?m)^Available Commands:$|^Global Flags:$|-h, --help\s+help for \S|Use "[^"]+ \[command\] --help" for more information"#).unwrap(),
+        ),
+        (CliFramework::Clap, Regex::new(r"(?m)--help\s+Prints? help|^USAGE:$").unwrap()),
+        (CliFramework::GnuGetopt, Regex::new(r"display this help and exit|\[OPTION\]\.\.\.").unwrap()),
+    ];
+
+    /// Regex pattern for subcommand lines in help output
+    /// Matches lines like:
+    /// - "  help      Show help information" (standard format)
+    /// - "  config    Manage configuration" (standard format)
+    /// - "  publish [options] [project-path]  Publish package to registry" (Commander.js format)
+    ///
+    /// Pattern breakdown:
+    /// - `^\s{2,}` - Line starts with 2+ spaces (indentation)
+    /// - `([a-z][a-z0-9-]+)` - Subcommand name (lowercase, alphanumeric, hyphens)
+    /// - `(?:\s+\[[^\]]+\])*` - Optional argument specifications like [options], [path] (Commander.js)
+    /// - `\s{2,}` - 2+ spaces separating command from description
+    /// - `(.+)$` - Description text
+    static ref SUBCOMMAND_PATTERN: Regex = Regex::new(r"^\s{2,}([a-z][a-z0-9-]+)(?:\s+\[[^\]]+\])*\s{2,}(.+)$").unwrap();
+
+    /// Common section headers that indicate subcommands section
+    static ref SUBCOMMAND_HEADERS: Vec<&'static str> = vec![
+        "Commands:",
+        "Available Commands:",
+        "Subcommands:",
+        "Available subcommands:",
+        "COMMANDS:",
+        "SUBCOMMANDS:",
+        "positional arguments:",  // Python argparse
+    ];
+
+    /// commander.js command lines: "  install|i <pkg> [dir]  Install a package"
+    static ref COMMANDER_SUBCOMMAND: Regex = Regex::new(r"^\s{2,}([a-z][a-z0-9-]*)(?:\|[a-z0-9-]+)*(?:\s+(?:\[[^\]]+\]|<[^>]+>))*\s{2,}(.+)$").unwrap();
+
+    /// cobra command group headers: "Available Commands:", "Basic Commands (Beginner):"
+    static ref COBRA_COMMAND_HEADER: Regex = Regex::new(r"^[A-Za-z ]*Commands(?: \([^)]*\))?:$").unwrap();
+
+    /// argparse subparser choices: {init,build,test}
+    static ref ARGPARSE_SUBPARSERS: Regex = Regex::new(r"^\{([a-z0-9,_-]+)\}").unwrap();
+
+    /// Click value types: --count INTEGER, --format [json|yaml], --level INTEGER RANGE
+    static ref CLICK_VALUE_TYPE: Regex = Regex::new(r"--[a-z][a-z0-9-]*\s+(\[[^\]\s]+\]|(?:INTEGER|FLOAT)(?: RANGE)?|TEXT|PATH|FILE|FILENAME|DIRECTORY)(?:\s|$)").unwrap();
+
+    /// Click range bounds: [1<=x<=10], [x>=0], [x<=100]
+    static ref CLICK_RANGE: Regex = Regex::new(r"\[(?:(-?\d+)<=)?x(?:<=(-?\d+)|>=(-?\d+))?\]").unwrap();
+
+    /// First long option on a line
+    static ref LONG_OPTION: Regex = Regex::new(r"--[a-z][a-z0-9-]+").unwrap();
+}
+
+/// Detect the CLI framework from help output fingerprints
+///
+/// Returns [`CliFramework::Unknown`] when no fingerprint matches.
+///
+/// # Examples
+///
+/// ```
+/// use cli_testing_specialist::analyzer::detect_framework;
+/// use cli_testing_specialist::types::CliFramework;
+///
+/// let help = "usage: tool.py [-h]\n\noptions:\n  -h, --help  show this help message and exit\n";
+/// assert_eq!(detect_framework(help), CliFramework::Argparse);
+/// ```
+pub fn detect_framework(help_output: &str) -> CliFramework {
+    FINGERPRINTS
+        .iter()
+        .find(|(_, fingerprint)| fingerprint.is_match(help_output))
+        .map(|(framework, _)| *framework)
+        .unwrap_or_default()
+}
+
+/// Get the help parser for a framework
+pub fn parser_for(framework: CliFramework) -> Box<dyn FrameworkParser> {
+    match framework {
+        CliFramework::Clap => Box::new(ClapParser),
+        CliFramework::Argparse => Box::new(ArgparseParser),
+        CliFramework::Click => Box::new(ClickParser),
+        CliFramework::Cobra => Box::new(CobraParser),
+        CliFramework::Commander => Box::new(CommanderParser),
+        CliFramework::GnuGetopt => Box::new(GnuGetoptParser),
+        CliFramework::Unknown => Box::new(GenericParser),
+    }
+}
+
+/// Common interface for framework-specific help parsers
+///
+/// Help output is split into sections at non-indented lines (`Options:`,
+/// `Global Flags:`, `positional arguments:`, ...). Parsers decide which sections
+/// list subcommands and which ones must not contribute options; the default
+/// methods only accept option definitions from lines that start with a dash.
+pub trait FrameworkParser: Send + Sync {
+    /// Framework handled by this parser
+    fn framework(&self) -> CliFramework;
+
+    /// Whether a section header introduces a list of subcommands
+    fn is_subcommand_header(&self, header: &str) -> bool;
+
+    /// Whether a section's entries are not options of the command itself
+    fn skips_option_section(&self, header: &str) -> bool {
+        self.is_subcommand_header(header)
+    }
+
+    /// Parse options defined by the command
+    fn parse_options(&self, help_output: &str) -> Vec<CliOption> {
+        let text = without_sections(help_output, |header| self.skips_option_section(header));
+        CliParser::new().parse_option_lines(&text, true)
+    }
+
+    /// Parse subcommand names and descriptions
+    fn parse_subcommands(&self, help_output: &str) -> Vec<(String, String)> {
+        section_entries(
+            help_output,
+            |header| self.is_subcommand_header(header),
+            &SUBCOMMAND_PATTERN,
+        )
+    }
+}
+
+/// Fallback parser for unrecognised help layouts
+///
+/// Accepts options mentioned anywhere on a line and subcommands under any of the
+/// common section headers, ending each section at the first blank line.
+pub struct GenericParser;
+
+impl FrameworkParser for GenericParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::Unknown
+    }
+
+    fn is_subcommand_header(&self, header: &str) -> bool {
+        SUBCOMMAND_HEADERS.iter().any(|h| header.starts_with(h))
+    }
+
+    fn parse_options(&self, help_output: &str) -> Vec<CliOption> {
+        CliParser::new().parse_options(help_output)
+    }
+
+    fn parse_subcommands(&self, help_output: &str) -> Vec<(String, String)> {
+        let mut subcommands = Vec::new();
+        let mut in_subcommand_section = false;
+
+        for line in help_output.lines() {
+            // Check if we entered subcommands section
+            if !in_subcommand_section {
+                in_subcommand_section = self.is_subcommand_header(line.trim());
+                continue;
+            }
+
+            // Check if we left subcommands section (empty line or new section)
+            if line.trim().is_empty() {
+                in_subcommand_section = false;
+                continue;
+            }
+
+            // Parse subcommand line
+            if let Some(captures) = SUBCOMMAND_PATTERN.captures(line) {
+                let name = captures.get(1).unwrap().as_str().to_string();
+                let description = captures.get(2).unwrap().as_str().trim().to_string();
+
+                subcommands.push((name, description));
+            }
+        }
+
+        subcommands
+    }
+}
+
+/// clap: `Options:`/`Arguments:`/`Commands:` (clap 4) or `OPTIONS:`/`ARGS:`/`SUBCOMMANDS:` (clap 3)
+pub struct ClapParser;
+
+impl FrameworkParser for ClapParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::Clap
+    }
+
+    fn is_subcommand_header(&self, header: &str) -> bool {
+        matches!(header, "Commands:" | "Subcommands:" | "SUBCOMMANDS:")
+    }
+
+    fn skips_option_section(&self, header: &str) -> bool {
+        self.is_subcommand_header(header) || matches!(header, "Arguments:" | "ARGS:" | "USAGE:")
+    }
+}
+
+/// argparse: subcommands are subparser choices listed among the positional arguments
+///
+/// Plain positionals (`  file  input file`) share the `positional arguments:`
+/// section with subparsers (`  {init,build}` followed by one indented line per
+/// choice), so only the choices are reported as subcommands.
+pub struct ArgparseParser;
+
+impl FrameworkParser for ArgparseParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::Argparse
+    }
+
+    fn is_subcommand_header(&self, _header: &str) -> bool {
+        // Subparsers can be titled freely; see parse_subcommands
+        false
+    }
+
+    fn skips_option_section(&self, header: &str) -> bool {
+        header == "positional arguments:"
+    }
+
+    fn parse_subcommands(&self, help_output: &str) -> Vec<(String, String)> {
+        let mut subcommands: Vec<(String, String)> = Vec::new();
+        // Entry indentation of the current section
+        let mut entry_indent: Option<usize> = None;
+        // Choices of the current `{a,b}` entry, and whether any were listed below it
+        let mut choices: Vec<String> = Vec::new();
+        let mut listed = false;
+        let mut in_options = false;
+
+        for line in help_output.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let indent = line.len() - line.trim_start().len();
+
+            if indent == 0 {
+                flush_choices(&mut choices, &mut listed, &mut subcommands);
+                in_options = matches!(trimmed, "options:" | "optional arguments:");
+                entry_indent = None;
+                continue;
+            }
+            if in_options {
+                continue;
+            }
+
+            let base = *entry_indent.get_or_insert(indent);
+            if indent <= base {
+                flush_choices(&mut choices, &mut listed, &mut subcommands);
+                if let Some(cap) = ARGPARSE_SUBPARSERS.captures(trimmed) {
+                    choices = cap[1].split(',').map(str::to_string).collect();
+                }
+            } else if let Some(cap) = SUBCOMMAND_PATTERN.captures(line) {
+                // With a metavar ("command") the choices are only known from this list
+                if choices.is_empty() || choices.iter().any(|c| c == &cap[1]) {
+                    subcommands.push((cap[1].to_string(), cap[2].trim().to_string()));
+                    listed = true;
+                }
+            }
+        }
+        flush_choices(&mut choices, &mut listed, &mut subcommands);
+
+        subcommands
+    }
+}
+
+/// Report subparser choices that had no help line of their own
+fn flush_choices(
+    choices: &mut Vec<String>,
+    listed: &mut bool,
+    subcommands: &mut Vec<(String, String)>,
+) {
+    if !*listed {
+        subcommands.extend(choices.drain(..).map(|choice| (choice, String::new())));
+    }
+    choices.clear();
+    *listed = false;
+}
+
+/// Click: `Options:`/`Commands:` with upper-case value types (`INTEGER`, `[a|b]`, `PATH`)
+pub struct ClickParser;
+
+impl FrameworkParser for ClickParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::Click
+    }
+
+    fn is_subcommand_header(&self, header: &str) -> bool {
+        header == "Commands:"
+    }
+
+    fn parse_options(&self, help_output: &str) -> Vec<CliOption> {
+        let text = without_sections(help_output, |header| self.skips_option_section(header));
+        let mut options = CliParser::new().parse_option_lines(&text, true);
+
+        for line in text.lines().map(str::trim) {
+            let Some(long) = LONG_OPTION
+                .find(option_spec(line))
+                .filter(|_| line.starts_with('-'))
+            else {
+                continue;
+            };
+            let Some(option) = options
+                .iter_mut()
+                .find(|o| o.long.as_deref() == Some(long.as_str()))
+            else {
+                continue;
+            };
+
+            if let Some(cap) = CLICK_VALUE_TYPE.captures(line) {
+                option.option_type = click_type(&cap[1], line);
+            }
+            if line.contains("[required]") {
+                option.required = true;
+            }
+        }
+
+        options
+    }
+}
+
+/// Option type for a Click value type such as `INTEGER RANGE` or `[json|yaml]`
+fn click_type(value_type: &str, line: &str) -> OptionType {
+    if let Some(choices) = value_type.strip_prefix('[') {
+        return OptionType::Enum {
+            values: choices
+                .trim_end_matches(']')
+                .split('|')
+                .map(str::to_string)
+                .collect(),
+        };
+    }
+
+    match value_type {
+        "INTEGER" | "FLOAT" | "INTEGER RANGE" | "FLOAT RANGE" => {
+            let bound = |m: Option<regex::Match>| m.and_then(|m| m.as_str().parse().ok());
+            let range = CLICK_RANGE.captures(line);
+            OptionType::Numeric {
+                min: range
+                    .as_ref()
+                    .and_then(|cap| bound(cap.get(1)).or(bound(cap.get(3)))),
+                max: range.as_ref().and_then(|cap| bound(cap.get(2))),
+            }
+        }
+        "PATH" | "FILE" | "FILENAME" | "DIRECTORY" => OptionType::Path,
+        _ => OptionType::String,
+    }
+}
+
+/// cobra: local `Flags:` vs inherited `Global Flags:`, grouped `... Commands:` sections
+pub struct CobraParser;
+
+impl FrameworkParser for CobraParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::Cobra
+    }
+
+    fn is_subcommand_header(&self, header: &str) -> bool {
+        COBRA_COMMAND_HEADER.is_match(header)
+    }
+
+    fn skips_option_section(&self, header: &str) -> bool {
+        // Global flags belong to the root command and are analyzed there
+        self.is_subcommand_header(header)
+            || matches!(
+                header,
+                "Global Flags:" | "Additional help topics:" | "Examples:" | "Aliases:"
+            )
+    }
+}
+
+/// commander.js: commands with `<required>`/`[optional]` arguments and `|alias`
+pub struct CommanderParser;
+
+impl FrameworkParser for CommanderParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::Commander
+    }
+
+    fn is_subcommand_header(&self, header: &str) -> bool {
+        header == "Commands:"
+    }
+
+    fn skips_option_section(&self, header: &str) -> bool {
+        self.is_subcommand_header(header) || header == "Arguments:"
+    }
+
+    fn parse_subcommands(&self, help_output: &str) -> Vec<(String, String)> {
+        section_entries(
+            help_output,
+            |header| self.is_subcommand_header(header),
+            &COMMANDER_SUBCOMMAND,
+        )
+    }
+}
+
+/// GNU getopt_long: a flat option list without subcommands
+pub struct GnuGetoptParser;
+
+impl FrameworkParser for GnuGetoptParser {
+    fn framework(&self) -> CliFramework {
+        CliFramework::GnuGetopt
+    }
+
+    fn is_subcommand_header(&self, _header: &str) -> bool {
+        false
+    }
+}
+
+/// Help output without the sections whose header matches `skip`
+fn without_sections(help_output: &str, skip: impl Fn(&str) -> bool) -> String {
+    let mut skipping = false;
+    let mut kept = String::with_capacity(help_output.len());
+
+    for line in help_output.lines() {
+        if !line.trim().is_empty() && !line.starts_with(char::is_whitespace) {
+            skipping = skip(line.trim());
+        }
+        if !skipping {
+            kept.push_str(line);
+            kept.push('\n');
+        }
+    }
+
+    kept
+}
+
+/// Entries matching `pattern` in sections whose header matches `is_header`
+///
+/// A section runs until the next non-indented line, so blank lines inside a
+/// section do not end it.
+fn section_entries(
+    help_output: &str,
+    is_header: impl Fn(&str) -> bool,
+    pattern: &Regex,
+) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    let mut in_section = false;
+
+    for line in help_output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            in_section = is_header(line.trim());
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if let Some(cap) = pattern.captures(line) {
+            if !entries.iter().any(|(name, _)| name == &cap[1]) {
+                entries.push((cap[1].to_string(), cap[2].trim().to_string()));
+            }
+        }
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(subcommands: &[(String, String)]) -> Vec<&str> {
+        subcommands.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    fn longs(options: &[CliOption]) -> Vec<&str> {
+        options.iter().filter_map(|o| o.long.as_deref()).collect()
+    }
+
+    const CLAP_HELP: &str = r#"A tool
+
+Usage: tool [OPTIONS] <FILE> <COMMAND>
+
+Commands:
+  build  Compile the project
+  help   Print this message or the help of the given subcommand(s)
+
+Arguments:
+  <FILE>  Input file (use --stdin to read standard input)
+
+Options:
+  -v, --verbose  Enable verbose output
+  -h, --help     Print help
+"#;
+
+    const ARGPARSE_HELP: &str = r#"usage: tool.py [-h] [--verbose] file {init,build} ...
+
+positional arguments:
+  file         input file, see --verbose
+  {init,build}
+    init       Initialize project
+    build      Build project
+
+options:
+  -h, --help   show this help message and exit
+  --verbose    verbose output
+"#;
+
+    const CLICK_HELP: &str = r#"Usage: tool [OPTIONS] COMMAND [ARGS]...
+
+Options:
+  -n, --count INTEGER RANGE  Number of runs  [1<=x<=10]
+  --format [json|yaml]       Output format
+  --config PATH              Config file  [required]
+  --name TEXT                Name
+  --help                     Show this message and exit.
+
+Commands:
+  deploy  Deploy the app
+  status  Show status
+"#;
+
+    const COBRA_HELP: &str = r#"Get resources
+
+Usage:
+  kubectl get [flags]
+
+Basic Commands (Beginner):
+  create      Create a resource
+
+Available Commands:
+  pods        List pods
+
+Flags:
+  -o, --output string   Output format
+  -h, --help            help for get
+
+Global Flags:
+      --kubeconfig string   Path to the kubeconfig file
+
+Use "kubectl get [command] --help" for more information about a command.
+"#;
+
+    const COMMANDER_HELP: &str = r#"Usage: pkg [options] [command]
+
+Options:
+  -V, --version                output the version number
+  -h, --help                   display help for command
+
+Commands:
+  install|i <name> [version]   Install a package
+  publish [options]            Publish to the registry
+  help [command]               display help for command
+"#;
+
+    const GNU_HELP: &str = r#"Usage: ls [OPTION]... [FILE]...
+List information about the FILEs.
+
+  -a, --all                  do not ignore entries starting with .
+      --block-size=SIZE      with -l, scale sizes by SIZE
+  -t                         sort by time, newest first; see --time
+      --time=WORD            select which timestamp used to sort
+      --help     display this help and exit
+"#;
+
+    #[test]
+    fn test_detect_framework() {
+        assert_eq!(detect_framework(CLAP_HELP), CliFramework::Clap);
+        assert_eq!(detect_framework(ARGPARSE_HELP), CliFramework::Argparse);
+        assert_eq!(detect_framework(CLICK_HELP), CliFramework::Click);
+        assert_eq!(detect_framework(COBRA_HELP), CliFramework::Cobra);
+        assert_eq!(detect_framework(COMMANDER_HELP), CliFramework::Commander);
+        assert_eq!(detect_framework(GNU_HELP), CliFramework::GnuGetopt);
+        assert_eq!(
+            detect_framework("Usage: tool\n  -x  something\n"),
+            CliFramework::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parser_for_matches_framework() {
+        for framework in [
+            CliFramework::Clap,
+            CliFramework::Argparse,
+            CliFramework::Click,
+            CliFramework::Cobra,
+            CliFramework::Commander,
+            CliFramework::GnuGetopt,
+            CliFramework::Unknown,
+        ] {
+            assert_eq!(parser_for(framework).framework(), framework);
+        }
+    }
+
+    #[test]
+    fn test_subcommand_pattern() {
+        assert!(SUBCOMMAND_PATTERN.is_match("  help      Show help information"));
+        assert!(SUBCOMMAND_PATTERN.is_match("  config    Manage configuration"));
+        assert!(SUBCOMMAND_PATTERN.is_match("    status    Show status"));
+        assert!(!SUBCOMMAND_PATTERN.is_match("Options:"));
+        assert!(!SUBCOMMAND_PATTERN.is_match("--help"));
+    }
+
+    #[test]
+    fn test_clap_ignores_options_mentioned_in_arguments() {
+        let parser = ClapParser;
+
+        assert_eq!(
+            longs(&parser.parse_options(CLAP_HELP)),
+            vec!["--verbose", "--help"]
+        );
+        assert_eq!(
+            names(&parser.parse_subcommands(CLAP_HELP)),
+            vec!["build", "help"]
+        );
+    }
+
+    #[test]
+    fn test_argparse_separates_positionals_from_subparsers() {
+        let parser = ArgparseParser;
+
+        let subcommands = parser.parse_subcommands(ARGPARSE_HELP);
+        assert_eq!(names(&subcommands), vec!["init", "build"]);
+        assert_eq!(subcommands[0].1, "Initialize project");
+        assert_eq!(
+            longs(&parser.parse_options(ARGPARSE_HELP)),
+            vec!["--help", "--verbose"]
+        );
+
+        // Without help strings only the choices themselves are printed
+        let bare = "usage: tool [-h] {init,build} ...\n\npositional arguments:\n  {init,build}\n";
+        assert_eq!(
+            names(&parser.parse_subcommands(bare)),
+            vec!["init", "build"]
+        );
+
+        // Plain positionals are not subcommands
+        let positional =
+            "usage: tool [-h] file\n\npositional arguments:\n  file        input file\n";
+        assert!(parser.parse_subcommands(positional).is_empty());
+    }
+
+    #[test]
+    fn test_click_value_types() {
+        let options = ClickParser.parse_options(CLICK_HELP);
+        let find = |long: &str| {
+            options
+                .iter()
+                .find(|o| o.long.as_deref() == Some(long))
+                .unwrap()
+        };
+
+        assert_eq!(
+            find("--count").option_type,
+            OptionType::Numeric {
+                min: Some(1),
+                max: Some(10)
+            }
+        );
+        assert_eq!(
+            find("--format").option_type,
+            OptionType::Enum {
+                values: vec!["json".to_string(), "yaml".to_string()]
+            }
+        );
+        assert_eq!(find("--config").option_type, OptionType::Path);
+        assert!(find("--config").required);
+        assert_eq!(find("--name").option_type, OptionType::String);
+        assert_eq!(find("--help").option_type, OptionType::Flag);
+
+        assert_eq!(
+            names(&ClickParser.parse_subcommands(CLICK_HELP)),
+            vec!["deploy", "status"]
+        );
+    }
+
+    #[test]
+    fn test_cobra_excludes_global_flags() {
+        let parser = CobraParser;
+
+        assert_eq!(
+            longs(&parser.parse_options(COBRA_HELP)),
+            vec!["--output", "--help"]
+        );
+        assert_eq!(
+            names(&parser.parse_subcommands(COBRA_HELP)),
+            vec!["create", "pods"]
+        );
+    }
+
+    #[test]
+    fn test_commander_subcommands_with_arguments() {
+        let subcommands = CommanderParser.parse_subcommands(COMMANDER_HELP);
+
+        assert_eq!(names(&subcommands), vec!["install", "publish", "help"]);
+        assert_eq!(subcommands[0].1, "Install a package");
+    }
+
+    #[test]
+    fn test_gnu_getopt_has_no_subcommands() {
+        let parser = GnuGetoptParser;
+
+        assert!(parser.parse_subcommands(GNU_HELP).is_empty());
+
+        let options = parser.parse_options(GNU_HELP);
+        assert_eq!(
+            longs(&options),
+            vec!["--all", "--block-size", "--time", "--help"]
+        );
+        // "-l" in the description is not the short form of --block-size
+        assert_eq!(options[1].short, None);
+        // "see --time" in the description is not the long form of -t
+        assert_eq!(options[2].short.as_deref(), Some("-t"));
+        assert_eq!(options[2].long, None);
+        assert_eq!(options[3].option_type, OptionType::String);
+    }
+}
@@ -0,0 +1,905 @@
+use crate::analyzer::cli_parser::CliParser;
+use crate::error::Result;
+use crate::types::analysis::{
+    CliAnalysis, CliOption, EnvironmentVariable, ExitStatus, OptionSource,
+};
+use flate2::read::GzDecoder;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Man page roots searched when `MANPATH` is unset (or for its empty entries)
+const DEFAULT_MAN_DIRS: &[&str] = &[
+    "/usr/local/share/man",
+    "/usr/share/man",
+    "/usr/local/man",
+    "/opt/homebrew/share/man",
+];
+
+/// Manual sections documenting commands (user commands, admin commands, games)
+const COMMAND_SECTIONS: &[&str] = &["1", "8", "6"];
+
+/// mdoc macros that only change formatting and are dropped from rendered text
+const MDOC_INLINE_MACROS: &[&str] = &[
+    "Ad", "An", "Aq", "Ar", "At", "Bq", "Brq", "Bx", "Cm", "Dq", "Dv", "Em", "Er", "Ev", "Fa",
+    "Fn", "Ft", "Ic", "Li", "Lk", "Mt", "Nm", "No", "Oc", "Oo", "Op", "Pa", "Pq", "Ql", "Qq", "Sq",
+    "St", "Sy", "Tn", "Ux", "Va", "Xr",
+];
+
+lazy_static! {
+    /// Environment variable names starting ENVIRONMENT entry tags: NO_COLOR, http_proxy
+    static ref ENV_NAME: Regex = Regex::new(r"^(?:[A-Z][A-Z0-9_]*|[a-z][a-z0-9]*_[a-z0-9_]+)$").unwrap();
+
+    /// Exit codes described in running text: "the exit status is 0 if a line is selected"
+    static ref EXIT_STATUS_TEXT: Regex = Regex::new(r"\b(\d{1,3}) if ([^,.;]+)").unwrap();
+}
+
+/// Options, environment variables and exit statuses documented in a man page
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ManPage {
+    /// Options listed as tagged paragraphs (`.TP`, `.IP`, mdoc `.It Fl`)
+    pub options: Vec<CliOption>,
+
+    /// Variables from the ENVIRONMENT section
+    pub environment_variables: Vec<EnvironmentVariable>,
+
+    /// Codes from the EXIT STATUS (or EXIT CODES) section
+    pub exit_statuses: Vec<ExitStatus>,
+}
+
+impl ManPage {
+    /// Merge into an analysis built from `--help` output
+    ///
+    /// Help output stays authoritative: options found there only gain missing
+    /// details (short form, description, default, environment variable). Options
+    /// documented only in the man page are appended with [`OptionSource::ManPage`].
+    pub fn merge_into(self, analysis: &mut CliAnalysis) {
+        for option in self.options {
+            match analysis
+                .global_options
+                .iter_mut()
+                .find(|existing| same_option(existing, &option))
+            {
+                Some(existing) => {
+                    existing.short = existing.short.take().or(option.short);
+                    existing.description = existing.description.take().or(option.description);
+                    existing.default_value = existing.default_value.take().or(option.default_value);
+                    existing.env_var = existing.env_var.take().or(option.env_var);
+                }
+                None => analysis.global_options.push(option),
+            }
+        }
+
+        for variable in self.environment_variables {
+            match analysis
+                .environment_variables
+                .iter_mut()
+                .find(|v| v.name == variable.name)
+            {
+                Some(existing) => {
+                    existing.description = existing.description.take().or(variable.description);
+                }
+                None => analysis.environment_variables.push(variable),
+            }
+        }
+
+        if analysis.exit_statuses.is_empty() {
+            analysis.exit_statuses = self.exit_statuses;
+        }
+    }
+}
+
+/// Whether two options are the same (by long name, or short name if either lacks one)
+pub(crate) fn same_option(a: &CliOption, b: &CliOption) -> bool {
+    match (&a.long, &b.long) {
+        (Some(a_long), Some(b_long)) => a_long == b_long,
+        _ => a.short.is_some() && a.short == b.short,
+    }
+}
+
+/// Locate the man page for a binary
+///
+/// Looks for `man{1,8,6}/<name>.<section>` (optionally gzip-compressed) under
+/// `<prefix>/share/man` of the binary's installation prefix, then under each
+/// `MANPATH` entry. An empty `MANPATH` entry stands for the default directories.
+pub fn find_man_page(binary: &Path) -> Option<PathBuf> {
+    let name = binary.file_name()?.to_str()?;
+
+    let mut roots = Vec::new();
+    if let Some(prefix) = binary.parent().and_then(Path::parent) {
+        roots.push(prefix.join("share/man"));
+    }
+    roots.extend(man_dirs(env::var("MANPATH").ok().as_deref()));
+
+    for root in &roots {
+        for section in COMMAND_SECTIONS {
+            let page = root
+                .join(format!("man{}", section))
+                .join(format!("{}.{}", name, section));
+            let mut compressed = OsString::from(page.as_os_str());
+            compressed.push(".gz");
+
+            for candidate in [page, PathBuf::from(compressed)] {
+                if candidate.is_file() {
+                    log::debug!("Found man page: {}", candidate.display());
+                    return Some(candidate);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Man page roots from a `MANPATH` value
+fn man_dirs(manpath: Option<&str>) -> Vec<PathBuf> {
+    let defaults = || DEFAULT_MAN_DIRS.iter().map(PathBuf::from);
+
+    match manpath.filter(|value| !value.is_empty()) {
+        None => defaults().collect(),
+        Some(value) => value
+            .split(':')
+            .flat_map(|entry| {
+                if entry.is_empty() {
+                    defaults().collect::<Vec<_>>()
+                } else {
+                    vec![PathBuf::from(entry)]
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Read a man page, decompressing `.gz` files and following one `.so` redirect
+pub fn read_man_page(path: &Path) -> Result<String> {
+    let text = read_roff(path)?;
+
+    // Pages like `egrep.1` only contain `.so man1/grep.1`
+    if let Some(target) = text.trim().strip_prefix(".so ") {
+        if let Some(root) = path.parent().and_then(Path::parent) {
+            let target = root.join(target.trim());
+            let mut compressed = OsString::from(target.as_os_str());
+            compressed.push(".gz");
+            let compressed = PathBuf::from(compressed);
+
+            if target.is_file() {
+                return read_roff(&target);
+            } else if compressed.is_file() {
+                return read_roff(&compressed);
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+/// Read a roff file, decompressing it if it ends in `.gz`
+fn read_roff(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)?;
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+        Ok(String::from_utf8_lossy(&decompressed).into_owned())
+    } else {
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Tagged paragraph (`.TP`, `.IP`, `.It`) with its description
+struct Entry {
+    section: String,
+    subsection: String,
+    tag: String,
+    description: String,
+}
+
+impl Entry {
+    /// Whether the entry belongs to a section or subsection with this title
+    fn is_in(&self, title: &str) -> bool {
+        self.section.contains(title) || self.subsection.contains(title)
+    }
+}
+
+/// Parse a man page written with the man(7) or mdoc(7) macros
+pub fn parse_man_page(roff: &str) -> ManPage {
+    let mdoc = roff
+        .lines()
+        .any(|line| line.starts_with(".Sh ") || line.starts_with(".Dd"));
+
+    let mut entries: Vec<Entry> = Vec::new();
+    // Running text outside tagged paragraphs: (section, subsection, text)
+    let mut paragraphs: Vec<(String, String, String)> = Vec::new();
+    let mut section = String::new();
+    let mut subsection = String::new();
+    let mut current: Option<Entry> = None;
+    let mut pending_tag = false;
+    let mut standard_exit = false;
+
+    for line in roff.lines() {
+        let text = if let Some(request) = line.strip_prefix('.').or(line.strip_prefix('\'')) {
+            let args = split_args(request);
+            let Some((name, args)) = args.split_first() else {
+                continue;
+            };
+
+            match name.as_str() {
+                "SH" | "Sh" | "SS" | "Ss" => {
+                    entries.extend(current.take());
+                    pending_tag = false;
+                    let title = render(&args.join(" ")).trim_end_matches(':').to_uppercase();
+                    if name.eq_ignore_ascii_case("sh") {
+                        section = title;
+                        subsection.clear();
+                    } else {
+                        subsection = title;
+                    }
+                    continue;
+                }
+                "TP" => {
+                    entries.extend(current.take());
+                    pending_tag = true;
+                    continue;
+                }
+                // The next line is another tag for the current entry
+                "TQ" => {
+                    pending_tag = true;
+                    continue;
+                }
+                "IP" | "It" => {
+                    entries.extend(current.take());
+                    pending_tag = false;
+                    let tag = if mdoc {
+                        mdoc_text(args)
+                    } else {
+                        args.first().map(|tag| render(tag)).unwrap_or_default()
+                    };
+                    if !tag.is_empty() {
+                        current = Some(Entry {
+                            section: section.clone(),
+                            subsection: subsection.clone(),
+                            tag,
+                            description: String::new(),
+                        });
+                    }
+                    continue;
+                }
+                "PP" | "P" | "LP" | "Pp" | "RS" | "RE" | "Bl" | "El" => {
+                    entries.extend(current.take());
+                    pending_tag = false;
+                    continue;
+                }
+                "Ex" => {
+                    standard_exit = true;
+                    continue;
+                }
+                "B" | "I" | "SM" | "SB" => {
+                    args.iter().map(|a| render(a)).collect::<Vec<_>>().join(" ")
+                }
+                "BR" | "BI" | "IB" | "IR" | "RB" | "RI" => args.iter().map(|a| render(a)).collect(),
+                _ if mdoc && MDOC_INLINE_MACROS.contains(&name.as_str()) => {
+                    mdoc_text(&split_args(request))
+                }
+                // Comments and layout requests (.br, .sp, .nf, .TH, .\", ...)
+                _ => continue,
+            }
+        } else {
+            render(line)
+        };
+
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        if pending_tag {
+            pending_tag = false;
+            match current.as_mut() {
+                // .TQ adds another tag to the previous entry
+                Some(entry) if entry.description.is_empty() => {
+                    entry.tag = format!("{}, {}", entry.tag, text);
+                }
+                _ => {
+                    entries.extend(current.take());
+                    current = Some(Entry {
+                        section: section.clone(),
+                        subsection: subsection.clone(),
+                        tag: text.to_string(),
+                        description: String::new(),
+                    });
+                }
+            }
+        } else if let Some(entry) = current.as_mut() {
+            if !entry.description.is_empty() {
+                entry.description.push(' ');
+            }
+            entry.description.push_str(text);
+        } else {
+            paragraphs.push((section.clone(), subsection.clone(), text.to_string()));
+        }
+    }
+    entries.extend(current);
+
+    ManPage {
+        options: man_options(&entries),
+        environment_variables: man_environment(&entries),
+        exit_statuses: man_exit_statuses(&entries, &paragraphs, standard_exit),
+    }
+}
+
+/// Options from entries whose tag starts with a dash
+fn man_options(entries: &[Entry]) -> Vec<CliOption> {
+    let parser = CliParser::new();
+    let mut options: Vec<CliOption> = Vec::new();
+
+    for entry in entries
+        .iter()
+        .filter(|e| e.tag.starts_with('-') && !e.is_in("ENVIRONMENT"))
+    {
+        // Render as a help line so option syntax is interpreted like --help output
+        let line = format!("  {}  {}", entry.tag, entry.description);
+        let Some(mut option) = parser.parse_option_lines(&line, true).into_iter().next() else {
+            continue;
+        };
+        option.description = (!entry.description.is_empty()).then(|| entry.description.clone());
+        option.source = OptionSource::ManPage;
+
+        if !options
+            .iter()
+            .any(|existing| same_option(existing, &option))
+        {
+            options.push(option);
+        }
+    }
+
+    options
+}
+
+/// Variables from ENVIRONMENT entries
+///
+/// The tag starts with the variable name and may describe its value
+/// (`http_proxy [protocol://]<host>[:port]`).
+fn man_environment(entries: &[Entry]) -> Vec<EnvironmentVariable> {
+    let mut variables: Vec<EnvironmentVariable> = Vec::new();
+
+    for entry in entries.iter().filter(|e| e.is_in("ENVIRONMENT")) {
+        let Some(name) = entry
+            .tag
+            .split_whitespace()
+            .next()
+            .filter(|name| ENV_NAME.is_match(name))
+        else {
+            continue;
+        };
+        if variables.iter().any(|v| v.name == name) {
+            continue;
+        }
+        variables.push(EnvironmentVariable {
+            name: name.to_string(),
+            description: (!entry.description.is_empty()).then(|| entry.description.clone()),
+            option: None,
+        });
+    }
+
+    variables
+}
+
+/// Exit statuses from EXIT STATUS (or EXIT CODES) entries, falling back to the section's text
+fn man_exit_statuses(
+    entries: &[Entry],
+    paragraphs: &[(String, String, String)],
+    standard_exit: bool,
+) -> Vec<ExitStatus> {
+    let is_exit_section = |section: &str, subsection: &str| {
+        [section, subsection]
+            .iter()
+            .any(|title| title.contains("EXIT STATUS") || title.contains("EXIT CODE"))
+    };
+
+    let mut statuses: Vec<ExitStatus> = entries
+        .iter()
+        .filter(|e| is_exit_section(&e.section, &e.subsection))
+        .filter_map(|e| {
+            Some(ExitStatus {
+                code: e.tag.parse().ok()?,
+                description: e.description.clone(),
+            })
+        })
+        .collect();
+
+    if statuses.is_empty() {
+        let text = paragraphs
+            .iter()
+            .filter(|(section, subsection, _)| is_exit_section(section, subsection))
+            .map(|(_, _, text)| text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        for cap in EXIT_STATUS_TEXT.captures_iter(&text) {
+            let Ok(code) = cap[1].parse() else {
+                continue;
+            };
+            if !statuses.iter().any(|s| s.code == code) {
+                statuses.push(ExitStatus {
+                    code,
+                    description: format!("if {}", cap[2].trim()),
+                });
+            }
+        }
+    }
+
+    // mdoc's `.Ex -std`: "exits 0 on success, and >0 if an error occurs"
+    if statuses.is_empty() && standard_exit {
+        statuses.push(ExitStatus {
+            code: 0,
+            description: "on success".to_string(),
+        });
+    }
+
+    statuses
+}
+
+/// Split request arguments on unquoted whitespace, keeping escapes intact
+fn split_args(request: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = request.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    args.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        args.push(current);
+    }
+
+    args
+}
+
+/// Render mdoc inline macros: `Fl a , Fl -all Ar file` → "-a, --all file"
+fn mdoc_text(tokens: &[String]) -> String {
+    let mut out = String::new();
+    let mut attach = false;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = tokens[i].as_str();
+        i += 1;
+
+        let word = match token {
+            "Fl" => {
+                let flag = tokens
+                    .get(i)
+                    .filter(|next| !MDOC_INLINE_MACROS.contains(&next.as_str()) && *next != "Fl");
+                if flag.is_some() {
+                    i += 1;
+                }
+                format!("-{}", flag.map(|f| render(f)).unwrap_or_default())
+            }
+            "Ns" => {
+                attach = true;
+                continue;
+            }
+            "," | ";" | ":" | "." | ")" | "]" => {
+                out.push_str(token);
+                continue;
+            }
+            "(" | "[" => {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                out.push_str(token);
+                attach = true;
+                continue;
+            }
+            macro_name if MDOC_INLINE_MACROS.contains(&macro_name) => continue,
+            text => render(text),
+        };
+
+        if !attach && !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(&word);
+        attach = false;
+    }
+
+    out
+}
+
+/// Render roff escapes to plain text
+///
+/// Font changes, size changes and zero-width escapes are dropped; `\-` and the
+/// common special characters become their ASCII equivalents.
+fn render(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // Font (\fB, \f(CW, \f[BI]) and string (\*x, \*(xx, \*[name]) references
+            Some('f') | Some('*') => match chars.next() {
+                Some('(') => {
+                    chars.next();
+                    chars.next();
+                }
+                Some('[') => for _ in chars.by_ref().take_while(|&c| c != ']') {},
+                _ => {}
+            },
+            // Point size: \s0, \s+1, \s-2
+            Some('s') => {
+                if matches!(chars.peek(), Some('+') | Some('-')) {
+                    chars.next();
+                }
+                while chars.peek().is_some_and(char::is_ascii_digit) {
+                    chars.next();
+                }
+            }
+            Some('(') => {
+                let name: String = chars.by_ref().take(2).collect();
+                out.push_str(special_character(&name));
+            }
+            Some('[') => {
+                let name: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                out.push_str(special_character(&name));
+            }
+            Some('-') => out.push('-'),
+            Some('e') | Some('\\') => out.push('\\'),
+            Some(' ') | Some('~') | Some('0') => out.push(' '),
+            Some('.') => out.push('.'),
+            Some('\'') => out.push('\''),
+            // Comment until end of line
+            Some('"') => break,
+            // Zero-width escapes: \& \^ \| \, \/ \% \: \c
+            _ => {}
+        }
+    }
+
+    out
+}
+
+/// ASCII rendering of a named roff special character
+fn special_character(name: &str) -> &'static str {
+    match name {
+        "aq" | "oq" | "cq" | "aa" => "'",
+        "dq" | "lq" | "rq" => "\"",
+        "em" | "en" | "hy" | "mi" => "-",
+        "ga" => "`",
+        "bu" => "*",
+        "ti" | "ap" => "~",
+        "ha" => "^",
+        "rs" => "\\",
+        "co" => "(c)",
+        "rg" => "(R)",
+        "lB" => "[",
+        "rB" => "]",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::analysis::OptionType;
+    use std::path::PathBuf;
+
+    const GNU_PAGE: &str = r#".\" Generated by help2man
+.TH LS "1" "September 2022" "GNU coreutils 9.1" "User Commands"
+.SH NAME
+ls \- list directory contents
+.SH DESCRIPTION
+.PP
+List information about the FILEs.
+.TP
+\fB\-a\fR, \fB\-\-all\fR
+do not ignore entries starting with .
+.TP
+\fB\-\-block\-size\fR=\fI\,SIZE\/\fR
+with \fB\-l\fR, scale sizes by SIZE when printing them;
+e.g., '\-\-block\-size=M'
+.TP
+\fB\-c\fR
+sort by ctime
+.SS "Exit status:"
+.TP
+0
+if OK,
+.TP
+2
+if serious trouble (e.g., cannot access command\-line argument).
+"#;
+
+    const GREP_PAGE: &str = r#".TH GREP 1
+.SH OPTIONS
+.SS "Generic Program Information"
+.TP
+.B \-\^\-help
+Output a usage message and exit.
+.TP
+.BR \-V ", " \-\^\-version
+Output the version number of
+.B grep
+and exit.
+.TP
+.BI \-e " PATTERNS" "\fR,\fP \-\^\-regexp=" PATTERNS
+Use
+.I PATTERNS
+as the patterns.
+.SH "EXIT STATUS"
+Normally the exit status is 0 if a line is selected, 1 if no lines
+were selected, and 2 if an error occurred.
+.SH ENVIRONMENT
+The behavior of
+.B grep
+is affected by the following environment variables.
+.TP
+.B GREP_COLORS
+Controls how the
+.B \-\^\-color
+option highlights output.
+.RS
+.TP
+.B sl=
+SGR substring for whole selected lines.
+.RE
+.TP
+.B LC_ALL
+Locale override.
+.IP "http_proxy [protocol://]<host>[:port]"
+Proxy for HTTP.
+"#;
+
+    const MDOC_PAGE: &str = r#".Dd March 1, 2024
+.Dt CAT 1
+.Os
+.Sh NAME
+.Nm cat
+.Nd concatenate and print files
+.Sh DESCRIPTION
+.Bl -tag -width Ds
+.It Fl b
+Number the non-blank output lines, starting at 1.
+.It Fl n , Fl -number
+Number the output lines.
+.It Fl o Ar file
+Write to
+.Ar file .
+.El
+.Sh ENVIRONMENT
+.Bl -tag -width Ds
+.It Ev COLUMNS
+Terminal width.
+.El
+.Sh EXIT STATUS
+.Ex -std
+"#;
+
+    fn find<'a>(page: &'a ManPage, long_or_short: &str) -> &'a CliOption {
+        page.options
+            .iter()
+            .find(|o| {
+                o.long.as_deref() == Some(long_or_short)
+                    || o.short.as_deref() == Some(long_or_short)
+            })
+            .unwrap_or_else(|| panic!("{} not found", long_or_short))
+    }
+
+    #[test]
+    fn test_render_escapes() {
+        assert_eq!(
+            render(r"\fB\-\-block\-size\fR=\fI\,SIZE\/\fR"),
+            "--block-size=SIZE"
+        );
+        assert_eq!(render(r"\-\^\-color\(emauto"), "--color-auto");
+        assert_eq!(render(r"it\(aqs \f(CWcode\fP"), "it's code");
+        assert_eq!(render(r#"\s-1SMALL\s0 text \" comment"#), "SMALL text ");
+    }
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(
+            split_args(r#"BI \-e " PATTERNS" "\fR,\fP \-\^\-regexp=" PATTERNS"#),
+            vec![
+                "BI",
+                r"\-e",
+                " PATTERNS",
+                r"\fR,\fP \-\^\-regexp=",
+                "PATTERNS"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_help2man_page() {
+        let page = parse_man_page(GNU_PAGE);
+
+        assert_eq!(page.options.len(), 3);
+        let all = find(&page, "--all");
+        assert_eq!(all.short.as_deref(), Some("-a"));
+        assert_eq!(
+            all.description.as_deref(),
+            Some("do not ignore entries starting with .")
+        );
+        assert_eq!(all.source, OptionSource::ManPage);
+
+        let block_size = find(&page, "--block-size");
+        assert_eq!(block_size.short, None);
+        assert_eq!(block_size.option_type, OptionType::String);
+        assert_eq!(
+            block_size.description.as_deref(),
+            Some("with -l, scale sizes by SIZE when printing them; e.g., '--block-size=M'")
+        );
+        assert_eq!(find(&page, "-c").long, None);
+
+        assert_eq!(
+            page.exit_statuses,
+            vec![
+                ExitStatus {
+                    code: 0,
+                    description: "if OK,".to_string()
+                },
+                ExitStatus {
+                    code: 2,
+                    description: "if serious trouble (e.g., cannot access command-line argument)."
+                        .to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_font_macros_and_sections() {
+        let page = parse_man_page(GREP_PAGE);
+
+        assert_eq!(page.options.len(), 3);
+        assert_eq!(find(&page, "--version").short.as_deref(), Some("-V"));
+        assert_eq!(
+            find(&page, "--version").description.as_deref(),
+            Some("Output the version number of grep and exit.")
+        );
+        let regexp = find(&page, "--regexp");
+        assert_eq!(regexp.short.as_deref(), Some("-e"));
+        assert_eq!(regexp.option_type, OptionType::String);
+
+        // Nested .RS entries are not variables; .B tags are
+        let names: Vec<_> = page
+            .environment_variables
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["GREP_COLORS", "LC_ALL", "http_proxy"]);
+        assert_eq!(
+            page.environment_variables[0].description.as_deref(),
+            Some("Controls how the --color option highlights output.")
+        );
+
+        // Exit codes from running text
+        let codes: Vec<_> = page.exit_statuses.iter().map(|s| s.code).collect();
+        assert_eq!(codes, vec![0, 1, 2]);
+        assert_eq!(
+            page.exit_statuses[1].description,
+            "if no lines were selected"
+        );
+    }
+
+    #[test]
+    fn test_parse_tq_tags() {
+        let page = parse_man_page(
+            r#".SH OPTIONS
+.TP
+.B \-q
+.TQ
+.B \-\^\-quiet
+Suppress all output.
+.TP
+.B \-\^\-debug
+Print debug information.
+"#,
+        );
+
+        assert_eq!(page.options.len(), 2);
+        let quiet = find(&page, "--quiet");
+        assert_eq!(quiet.short.as_deref(), Some("-q"));
+        assert_eq!(quiet.description.as_deref(), Some("Suppress all output."));
+        assert_eq!(find(&page, "--debug").short, None);
+    }
+
+    #[test]
+    fn test_parse_mdoc_page() {
+        let page = parse_man_page(MDOC_PAGE);
+
+        assert_eq!(page.options.len(), 3);
+        assert_eq!(find(&page, "--number").short.as_deref(), Some("-n"));
+        assert_eq!(
+            find(&page, "-o").description.as_deref(),
+            Some("Write to file.")
+        );
+        assert_eq!(page.environment_variables[0].name, "COLUMNS");
+        assert_eq!(page.exit_statuses[0].code, 0);
+    }
+
+    #[test]
+    fn test_merge_into_analysis() {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/bin/ls"),
+            "ls".to_string(),
+            String::new(),
+        );
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--all".to_string()),
+            description: None,
+            option_type: OptionType::Flag,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        });
+
+        parse_man_page(GNU_PAGE).merge_into(&mut analysis);
+
+        assert_eq!(analysis.global_options.len(), 3);
+        let all = &analysis.global_options[0];
+        assert_eq!(all.short.as_deref(), Some("-a"));
+        assert!(all.description.is_some());
+        assert_eq!(all.source, OptionSource::Help);
+        assert_eq!(analysis.global_options[1].source, OptionSource::ManPage);
+        assert_eq!(analysis.exit_statuses.len(), 2);
+    }
+
+    #[test]
+    fn test_man_dirs() {
+        assert_eq!(man_dirs(None).len(), DEFAULT_MAN_DIRS.len());
+        assert_eq!(man_dirs(Some("/opt/man")), vec![PathBuf::from("/opt/man")]);
+        // A trailing colon appends the default directories
+        assert_eq!(
+            man_dirs(Some("/opt/man:")).len(),
+            1 + DEFAULT_MAN_DIRS.len()
+        );
+    }
+
+    #[test]
+    fn test_find_and_read_compressed_page() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let prefix = tempfile::tempdir().unwrap();
+        let man1 = prefix.path().join("share/man/man1");
+        fs::create_dir_all(&man1).unwrap();
+        fs::create_dir_all(prefix.path().join("bin")).unwrap();
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(GNU_PAGE.as_bytes()).unwrap();
+        fs::write(man1.join("mytool.1.gz"), encoder.finish().unwrap()).unwrap();
+        fs::write(man1.join("mt.1"), ".so man1/mytool.1\n").unwrap();
+
+        let page = find_man_page(&prefix.path().join("bin/mytool")).unwrap();
+        assert_eq!(page, man1.join("mytool.1.gz"));
+        assert_eq!(read_man_page(&page).unwrap(), GNU_PAGE);
+
+        // .so redirects resolve relative to the man root
+        let alias = find_man_page(&prefix.path().join("bin/mt")).unwrap();
+        assert_eq!(read_man_page(&alias).unwrap(), GNU_PAGE);
+    }
+}
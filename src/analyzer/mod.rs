@@ -3,17 +3,25 @@
 //! Provides comprehensive CLI tool analysis capabilities including:
 //!
 //! - **CLI Parsing**: Executes binaries with `--help` and extracts structured information
+//! - **Framework Detection**: Recognises clap, argparse, Click, cobra, commander.js and GNU
+//!   getopt help layouts and parses each with a framework-specific strategy
+//! - **Man Pages**: Merges options, environment variables and exit statuses from the
+//!   tool's roff man page (including gzip-compressed pages under `MANPATH`)
+//! - **Shell Completions**: Optionally parses the tool's bash/zsh/fish completion scripts
+//!   and flags options and subcommands that `--help` does not document
 //! - **Option Inference**: Automatically detects option types (flags, paths, numbers, etc.)
 //! - **Subcommand Detection**: Recursively discovers subcommands and their options
+//! - **Option Relations**: Infers conflicts, requirements and exclusive groups from usage
+//!   lines (`[--json | --yaml]`) and option descriptions ("cannot be used with --x")
 //!
 //! ## Architecture
 //!
 //! The analyzer module follows a pipeline pattern:
 //!
 //! ```text
-//! Binary Path → CliParser → Option Detection → Type Inference → CliAnalysis
-//!                              ↓
-//!                    SubcommandDetector (recursive)
+//! Binary Path → CliParser → Framework Detection → Option Detection → Man Page Merge
+//!                                                      ↓                      ↓
+//!                                            SubcommandDetector (recursive)   Completion Merge → CliAnalysis
 //! ```
 //!
 //! ## Example Usage
@@ -43,12 +51,23 @@
 //! - Memory limits (configurable)
 //! - Recursion depth limits for subcommands (max: 5 levels)
 
+pub mod analysis_validator;
 pub mod behavior_inferrer;
 pub mod cli_parser;
+pub mod completion;
+pub mod framework;
+pub mod man_page;
 pub mod option_inferrer;
 pub mod subcommand_detector;
 
+pub use analysis_validator::{validate_analysis, AnalysisIssue};
 pub use behavior_inferrer::BehaviorInferrer;
 pub use cli_parser::CliParser;
-pub use option_inferrer::{apply_numeric_constraints, load_enum_values, OptionInferrer};
+pub use completion::{parse_completion_script, CompletionCommand, CompletionShell, Completions};
+pub use framework::{detect_framework, parser_for, FrameworkParser};
+pub use man_page::{find_man_page, parse_man_page, read_man_page, ManPage};
+pub use option_inferrer::{
+    apply_numeric_constraints, enum_rules, load_enum_values, numeric_range, EnumRules,
+    NumericRange, OptionInferrer,
+};
 pub use subcommand_detector::SubcommandDetector;
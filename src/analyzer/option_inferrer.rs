@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::types::analysis::{CliOption, OptionType};
+use crate::types::analysis::{CliOption, OptionType, PositionalArg};
 use lazy_static::lazy_static;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -37,7 +37,6 @@ struct NumericConstraint {
     min: i64,
     max: i64,
     #[serde(rename = "type")]
-    #[allow(dead_code)]
     constraint_type: String,
     #[allow(dead_code)]
     unit: Option<String>,
@@ -57,7 +56,6 @@ struct DefaultNumericConstraints {
     min: i64,
     max: i64,
     #[serde(rename = "type")]
-    #[allow(dead_code)]
     constraint_type: String,
 }
 
@@ -66,7 +64,6 @@ struct DefaultNumericConstraints {
 struct EnumDefinition {
     aliases: Vec<String>,
     values: Vec<String>,
-    #[allow(dead_code)]
     case_sensitive: bool,
     #[allow(dead_code)]
     description: String,
@@ -76,12 +73,10 @@ struct EnumDefinition {
 #[derive(Debug, Clone, Deserialize)]
 struct EnumDefinitionsConfig {
     enums: HashMap<String, EnumDefinition>,
-    #[allow(dead_code)]
     default_enum: DefaultEnumConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
-#[allow(dead_code)]
 struct DefaultEnumConfig {
     case_sensitive: bool,
     allow_partial_match: bool,
@@ -149,16 +144,45 @@ impl OptionInferrer {
             return OptionType::Flag;
         }
 
+        // Choices, numeric and path types printed in the help text beat keyword heuristics
+        match &option.option_type {
+            OptionType::Enum { values } if !values.is_empty() => {
+                return option.option_type.clone();
+            }
+            OptionType::Numeric { .. } | OptionType::Path => return option.option_type.clone(),
+            _ => {}
+        }
+
         // Extract option name for pattern matching
         let option_name = self.extract_option_name(option);
+        self.infer_name_type(&option_name)
+    }
 
+    /// Infer types of positional arguments from their names (`PORT`, `FILE`)
+    ///
+    /// Choices from the usage line (Enum) are kept. A boolean match makes no
+    /// sense for a positional and leaves it a String.
+    pub fn infer_positional_types(&self, args: &mut [PositionalArg]) {
+        for arg in args.iter_mut() {
+            if arg.arg_type != OptionType::String {
+                continue;
+            }
+            arg.arg_type = match self.infer_name_type(&arg.name) {
+                OptionType::Flag => OptionType::String,
+                inferred => inferred,
+            };
+        }
+    }
+
+    /// Infer a value type from a name using the keyword patterns
+    fn infer_name_type(&self, option_name: &str) -> OptionType {
         // Sort patterns by priority (higher first)
         let mut sorted_patterns = self.patterns.clone();
-        sorted_patterns.sort_by(|a, b| b.priority.cmp(&a.priority));
+        sorted_patterns.sort_by_key(|p| std::cmp::Reverse(p.priority));
 
         // Try to match against patterns
         for pattern in &sorted_patterns {
-            if self.matches_pattern(&option_name, pattern) {
+            if self.matches_pattern(option_name, pattern) {
                 return self.pattern_type_to_option_type(&pattern.pattern_type);
             }
         }
@@ -245,13 +269,27 @@ impl Default for OptionInferrer {
     }
 }
 
+/// Numeric constraints built into the binary, used when
+/// config/numeric-constraints.yaml is not in the current directory
+const BUNDLED_NUMERIC_CONSTRAINTS: &str = include_str!("../../config/numeric-constraints.yaml");
+
 /// Load numeric constraints configuration from YAML (with caching)
+///
+/// config/numeric-constraints.yaml in the current directory overrides the
+/// bundled copy, so generation and native runs agree wherever they start.
 fn load_numeric_constraints_config() -> Result<NumericConstraintsConfig> {
     let mut cache = NUMERIC_CONSTRAINTS_CACHE.lock().unwrap();
 
     if cache.is_none() {
         // Load and parse YAML config
-        let config_content = std::fs::read_to_string("config/numeric-constraints.yaml")?;
+        let config_content = match std::fs::read_to_string("config/numeric-constraints.yaml") {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                log::debug!("config/numeric-constraints.yaml not found, using bundled constraints");
+                BUNDLED_NUMERIC_CONSTRAINTS.to_string()
+            }
+            Err(e) => return Err(e.into()),
+        };
         let config: NumericConstraintsConfig =
             crate::utils::deserialize_yaml_safe(&config_content)?;
         *cache = Some(config);
@@ -279,40 +317,95 @@ pub fn apply_numeric_constraints(options: &mut [CliOption]) {
     };
 
     for option in options.iter_mut() {
+        // Try to match against constraint aliases, else apply default constraints
+        let (constraint_min, constraint_max) = match matching_constraint(&config, option) {
+            Some(constraint) => (constraint.min, constraint.max),
+            None => (
+                config.default_constraints.min,
+                config.default_constraints.max,
+            ),
+        };
+
         if let OptionType::Numeric {
             ref mut min,
             ref mut max,
         } = option.option_type
         {
-            let option_name = option
-                .long
-                .as_ref()
-                .or(option.short.as_ref())
-                .map(|s| s.trim_start_matches('-').to_lowercase())
-                .unwrap_or_default();
+            *min = Some(constraint_min);
+            *max = Some(constraint_max);
+        }
+    }
+}
 
-            // Try to match against constraint aliases
-            let mut matched = false;
-            for constraint in config.constraints.values() {
-                if constraint
-                    .aliases
-                    .iter()
-                    .any(|alias| option_name.contains(&alias.to_lowercase()))
-                {
-                    *min = Some(constraint.min);
-                    *max = Some(constraint.max);
-                    matched = true;
-                    break;
-                }
-            }
+/// Constraint whose aliases occur in the option's name
+fn matching_constraint<'a>(
+    config: &'a NumericConstraintsConfig,
+    option: &CliOption,
+) -> Option<&'a NumericConstraint> {
+    let option_name = option
+        .long
+        .as_ref()
+        .or(option.short.as_ref())
+        .map(|s| s.trim_start_matches('-').to_lowercase())
+        .unwrap_or_default();
+
+    config.constraints.values().find(|constraint| {
+        constraint
+            .aliases
+            .iter()
+            .any(|alias| option_name.contains(&alias.to_lowercase()))
+    })
+}
+
+/// Accepted values of a numeric option
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumericRange {
+    /// Smallest accepted value
+    pub min: i64,
+
+    /// Largest accepted value
+    pub max: i64,
+
+    /// Whether fractional values are accepted (`type: float`)
+    pub float: bool,
+
+    /// Whether `max` was parsed from the help text or configured for this
+    /// option, rather than taken from `default_constraints`
+    pub explicit_max: bool,
+}
+
+/// Resolve the accepted range of a numeric option
+///
+/// Bounds parsed from the help text (`OptionType::Numeric { min, max }`) win.
+/// Missing bounds and the value type come from the matching constraint in
+/// numeric-constraints.yaml, else from its `default_constraints`. Returns `None`
+/// for non-numeric options, or when a bound is unknown and the YAML is missing.
+pub fn numeric_range(option: &CliOption) -> Option<NumericRange> {
+    let OptionType::Numeric { min, max } = option.option_type else {
+        return None;
+    };
 
-            // Apply default constraints if no match found
-            if !matched {
-                *min = Some(config.default_constraints.min);
-                *max = Some(config.default_constraints.max);
+    let inferred = match load_numeric_constraints_config() {
+        Ok(config) => Some(match matching_constraint(&config, option) {
+            Some(c) => (c.min, c.max, c.constraint_type == "float", true),
+            None => {
+                let d = &config.default_constraints;
+                (d.min, d.max, d.constraint_type == "float", false)
             }
+        }),
+        Err(e) => {
+            log::warn!("Numeric constraints unavailable: {}", e);
+            None
         }
-    }
+    };
+
+    let range = NumericRange {
+        min: min.or(inferred.map(|(min, _, _, _)| min))?,
+        max: max.or(inferred.map(|(_, max, _, _)| max))?,
+        float: inferred.is_some_and(|(_, _, float, _)| float),
+        explicit_max: max.is_some() || inferred.is_some_and(|(_, _, _, configured)| configured),
+    };
+    (range.min <= range.max).then_some(range)
 }
 
 /// Load enum definitions configuration from YAML (with caching)
@@ -336,6 +429,8 @@ fn load_enum_definitions_config() -> Result<EnumDefinitionsConfig> {
 /// - log-level: debug, info, warn, error
 /// - protocol: http, https, ftp, ssh
 ///
+/// Options whose values were already parsed from the help text are left unchanged.
+///
 /// Uses global cache for performance (loaded once, reused for all subsequent calls).
 pub fn load_enum_values(options: &mut [CliOption]) {
     // Load config from cache (or file if not cached)
@@ -349,6 +444,10 @@ pub fn load_enum_values(options: &mut [CliOption]) {
 
     for option in options.iter_mut() {
         if let OptionType::Enum { ref mut values } = option.option_type {
+            if !values.is_empty() {
+                continue;
+            }
+
             let option_name = option
                 .long
                 .as_ref()
@@ -357,23 +456,89 @@ pub fn load_enum_values(options: &mut [CliOption]) {
                 .unwrap_or_default();
 
             // Try to match against enum aliases
-            for enum_def in config.enums.values() {
-                if enum_def
-                    .aliases
-                    .iter()
-                    .any(|alias| option_name.contains(&alias.to_lowercase()))
-                {
-                    *values = enum_def.values.clone();
-                    break;
-                }
+            if let Some(enum_def) = matching_enum(&config, &option_name) {
+                *values = enum_def.values.clone();
             }
         }
     }
 }
 
+/// Enum definition whose aliases occur in the (lowercased, dash-less) option name
+fn matching_enum<'a>(
+    config: &'a EnumDefinitionsConfig,
+    option_name: &str,
+) -> Option<&'a EnumDefinition> {
+    config.enums.values().find(|enum_def| {
+        enum_def
+            .aliases
+            .iter()
+            .any(|alias| option_name.contains(&alias.to_lowercase()))
+    })
+}
+
+/// How an enum option is expected to match its values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumRules {
+    /// Whether `JSON` and `json` are different values
+    pub case_sensitive: bool,
+
+    /// Whether an unambiguous prefix (`js` for `json`) selects a value
+    pub allow_prefix: bool,
+}
+
+/// Resolve the matching rules of an enum option
+///
+/// An enum-definitions.yaml entry decides case sensitivity only when its
+/// aliases match the option name and it lists every value of the option.
+/// Other values were parsed from the help text (clap `[possible values]`,
+/// argparse choices) and follow `default_enum`, which is case-sensitive like
+/// those frameworks. Prefix matching also comes from `default_enum`. Without the YAML, values
+/// are matched exactly (case-sensitive, no prefixes). Returns `None` for
+/// non-enum options.
+pub fn enum_rules(option: &CliOption) -> Option<EnumRules> {
+    let OptionType::Enum { values } = &option.option_type else {
+        return None;
+    };
+
+    let config = match load_enum_definitions_config() {
+        Ok(config) => config,
+        Err(e) => {
+            log::debug!("Enum definitions unavailable: {}", e);
+            return Some(EnumRules {
+                case_sensitive: true,
+                allow_prefix: false,
+            });
+        }
+    };
+
+    let option_name = option
+        .long
+        .as_ref()
+        .or(option.short.as_ref())
+        .map(|s| s.trim_start_matches('-').to_lowercase())
+        .unwrap_or_default();
+
+    let definition = matching_enum(&config, &option_name).filter(|enum_def| {
+        values.iter().all(|value| {
+            enum_def
+                .values
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(value))
+        })
+    });
+
+    Some(EnumRules {
+        case_sensitive: definition.map_or(config.default_enum.case_sensitive, |enum_def| {
+            enum_def.case_sensitive
+        }),
+        allow_prefix: config.default_enum.allow_partial_match,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::analysis::OptionSource;
 
     #[test]
     fn test_extract_option_name() {
@@ -386,6 +551,8 @@ mod tests {
             option_type: OptionType::String,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         assert_eq!(inferrer.extract_option_name(&option), "timeout");
@@ -402,6 +569,8 @@ mod tests {
             option_type: OptionType::String,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         assert_eq!(inferrer.extract_option_name(&option), "p");
@@ -418,6 +587,8 @@ mod tests {
             option_type: OptionType::String,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         let inferred_type = inferrer.infer_type(&option);
@@ -447,6 +618,8 @@ mod tests {
             option_type: OptionType::String,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         let inferred_type = inferrer.infer_type(&option);
@@ -466,6 +639,8 @@ mod tests {
             option_type: OptionType::String,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         let inferred_type = inferrer.infer_type(&option);
@@ -485,6 +660,8 @@ mod tests {
             option_type: OptionType::Flag,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         let inferred_type = inferrer.infer_type(&option);
@@ -493,6 +670,58 @@ mod tests {
         assert!(matches!(inferred_type, OptionType::Flag));
     }
 
+    #[test]
+    fn test_infer_type_keeps_parsed_values() {
+        let inferrer = OptionInferrer::default();
+
+        let option = CliOption {
+            short: None,
+            long: Some("--format".to_string()),
+            description: None,
+            option_type: OptionType::Enum {
+                values: vec!["pretty".to_string(), "compact".to_string()],
+            },
+            required: false,
+            default_value: Some("pretty".to_string()),
+            env_var: None,
+            source: OptionSource::Help,
+        };
+
+        assert_eq!(inferrer.infer_type(&option), option.option_type);
+
+        let mut options = vec![option.clone()];
+        load_enum_values(&mut options);
+        assert_eq!(options[0].option_type, option.option_type);
+    }
+
+    #[test]
+    fn test_infer_positional_types() {
+        let inferrer = OptionInferrer::default();
+        let positional = |name: &str, arg_type: OptionType| PositionalArg {
+            name: name.to_string(),
+            index: 0,
+            required: true,
+            variadic: false,
+            arg_type,
+        };
+        let choices = OptionType::Enum {
+            values: vec!["fast".to_string()],
+        };
+
+        let mut args = vec![
+            positional("PORT", OptionType::String),
+            positional("FILE", OptionType::String),
+            positional("{fast}", choices.clone()),
+            positional("NAME", OptionType::String),
+        ];
+        inferrer.infer_positional_types(&mut args);
+
+        assert!(matches!(args[0].arg_type, OptionType::Numeric { .. }));
+        assert_eq!(args[1].arg_type, OptionType::Path);
+        assert_eq!(args[2].arg_type, choices);
+        assert_ne!(args[3].arg_type, OptionType::Flag);
+    }
+
     #[test]
     fn test_apply_numeric_constraints_port() {
         let mut options = vec![CliOption {
@@ -505,6 +734,8 @@ mod tests {
             },
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         }];
 
         apply_numeric_constraints(&mut options);
@@ -517,6 +748,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_numeric_range() {
+        let numeric = |long: &str, min: Option<i64>, max: Option<i64>| CliOption {
+            short: None,
+            long: Some(long.to_string()),
+            description: None,
+            option_type: OptionType::Numeric { min, max },
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        };
+
+        // Parsed bounds win over the YAML constraint
+        assert_eq!(
+            numeric_range(&numeric("--port", Some(1024), None)),
+            Some(NumericRange {
+                min: 1024,
+                max: 65535,
+                float: false,
+                explicit_max: true
+            })
+        );
+        assert!(
+            numeric_range(&numeric("--percent", None, None))
+                .unwrap()
+                .float
+        );
+        assert_eq!(
+            numeric_range(&numeric("--level", None, None)),
+            Some(NumericRange {
+                min: 0,
+                max: 2147483647,
+                float: false,
+                explicit_max: false
+            })
+        );
+
+        let mut flag = numeric("--verbose", None, None);
+        flag.option_type = OptionType::Flag;
+        assert_eq!(numeric_range(&flag), None);
+    }
+
+    #[test]
+    fn test_bundled_numeric_constraints_parse() {
+        let config: NumericConstraintsConfig =
+            crate::utils::deserialize_yaml_safe(BUNDLED_NUMERIC_CONSTRAINTS).unwrap();
+
+        assert!(config.constraints.values().any(|c| c.max == 65535));
+    }
+
     #[test]
     fn test_load_enum_values_format() {
         let mut options = vec![CliOption {
@@ -526,6 +808,8 @@ mod tests {
             option_type: OptionType::Enum { values: vec![] },
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         }];
 
         load_enum_values(&mut options);
@@ -539,6 +823,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_enum_rules() {
+        let option = |long: &str, option_type: OptionType| CliOption {
+            short: None,
+            long: Some(long.to_string()),
+            description: None,
+            option_type,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        };
+        let enum_type = |values: &[&str]| OptionType::Enum {
+            values: values.iter().map(|v| v.to_string()).collect(),
+        };
+
+        let format = enum_rules(&option("--format", enum_type(&["json", "yaml"]))).unwrap();
+        assert!(!format.case_sensitive);
+        assert!(!format.allow_prefix);
+
+        assert!(
+            enum_rules(&option("--comparison", enum_type(&["eq"])))
+                .unwrap()
+                .case_sensitive
+        );
+
+        // Values from the help text that no definition lists are case-sensitive
+        assert!(
+            enum_rules(&option("--format", enum_type(&["pretty", "compact"])))
+                .unwrap()
+                .case_sensitive
+        );
+        assert!(
+            enum_rules(&option("--mode", enum_type(&["fast", "slow"])))
+                .unwrap()
+                .case_sensitive
+        );
+        assert_eq!(enum_rules(&option("--verbose", OptionType::Flag)), None);
+    }
+
     #[test]
     fn test_partial_match() {
         let inferrer = OptionInferrer::default();
@@ -550,6 +874,8 @@ mod tests {
             option_type: OptionType::String,
             required: false,
             default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         let inferred_type = inferrer.infer_type(&option);
@@ -1,48 +1,31 @@
 use crate::analyzer::cli_parser::CliParser;
+use crate::analyzer::framework::{parser_for, FrameworkParser};
 use crate::analyzer::option_inferrer::OptionInferrer;
 use crate::error::Result;
-use crate::types::analysis::Subcommand;
+use crate::types::analysis::{CliFramework, Subcommand};
 use crate::utils::{execute_with_timeout, ResourceLimits};
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::collections::HashSet;
-use std::path::Path;
-
-lazy_static! {
-    /// Regex pattern for subcommand lines in help output
-    /// Matches lines like:
-    /// - "  help      Show help information" (standard format)
-    /// - "  config    Manage configuration" (standard format)
-    /// - "  publish [options] [project-path]  Publish package to registry" (Commander.js format)
-    ///
-    /// Pattern breakdown:
-    /// - `^\s{2,}` - Line starts with 2+ spaces (indentation)
-    /// - `([a-z][a-z0-9-]+)` - Subcommand name (lowercase, alphanumeric, hyphens)
-    /// - `(?:\s+\[[^\]]+\])*` - Optional argument specifications like [options], [path] (Commander.js)
-    /// - `\s{2,}` - 2+ spaces separating command from description
-    /// - `(.+)$` - Description text
-    static ref SUBCOMMAND_PATTERN: Regex = Regex::new(r"^\s{2,}([a-z][a-z0-9-]+)(?:\s+\[[^\]]+\])*\s{2,}(.+)$").unwrap();
-
-    /// Common section headers that indicate subcommands section
-    static ref SUBCOMMAND_HEADERS: Vec<&'static str> = vec![
-        "Commands:",
-        "Available Commands:",
-        "Subcommands:",
-        "Available subcommands:",
-        "COMMANDS:",
-        "SUBCOMMANDS:",
-        "positional arguments:",  // Python argparse
-    ];
-}
+use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Maximum recursion depth for subcommand detection
 const MAX_RECURSION_DEPTH: u8 = 3;
 
+/// Where subcommand help output comes from
+enum HelpSource<'a> {
+    /// Execute `<binary> <subcommand>... --help`
+    Binary(&'a Path),
+
+    /// Read captured help text from `<dir>/sub/<subcommand>/help.txt`
+    Directory(&'a Path),
+}
+
 /// Subcommand Detector - Recursively detects CLI subcommands
 pub struct SubcommandDetector {
     resource_limits: ResourceLimits,
     option_inferrer: OptionInferrer,
     max_depth: u8,
+    parser: Box<dyn FrameworkParser>,
 }
 
 impl SubcommandDetector {
@@ -52,6 +35,7 @@ impl SubcommandDetector {
             resource_limits: ResourceLimits::default(),
             option_inferrer: OptionInferrer::new()?,
             max_depth: MAX_RECURSION_DEPTH,
+            parser: parser_for(CliFramework::Unknown),
         })
     }
 
@@ -61,20 +45,52 @@ impl SubcommandDetector {
             resource_limits: ResourceLimits::default(),
             option_inferrer: OptionInferrer::new()?,
             max_depth,
+            parser: parser_for(CliFramework::Unknown),
         })
     }
 
+    /// Parse subcommand help with the strategy for `framework`
+    ///
+    /// Subcommands inherit the framework detected for the root command.
+    pub fn with_framework(mut self, framework: CliFramework) -> Self {
+        self.parser = parser_for(framework);
+        self
+    }
+
     /// Detect subcommands from help output
     pub fn detect(&self, binary: &Path, help_output: &str) -> Result<Vec<Subcommand>> {
         log::info!("Detecting subcommands for {}", binary.display());
-        self.detect_recursive(binary, help_output, 0, &mut HashSet::new())
+        self.detect_recursive(
+            &HelpSource::Binary(binary),
+            help_output,
+            &[],
+            0,
+            &mut HashSet::new(),
+        )
+    }
+
+    /// Detect subcommands from captured help text without executing anything
+    ///
+    /// Help for subcommand `a` is read from `<dir>/sub/a/help.txt`, for its nested
+    /// subcommand `b` from `<dir>/sub/a/sub/b/help.txt`. Subcommands without a
+    /// help file are skipped.
+    pub fn detect_from_dir(&self, dir: &Path, help_output: &str) -> Result<Vec<Subcommand>> {
+        log::info!("Detecting subcommands from {}", dir.display());
+        self.detect_recursive(
+            &HelpSource::Directory(dir),
+            help_output,
+            &[],
+            0,
+            &mut HashSet::new(),
+        )
     }
 
     /// Recursively detect subcommands
     fn detect_recursive(
         &self,
-        binary: &Path,
+        source: &HelpSource,
         help_output: &str,
+        parents: &[String],
         current_depth: u8,
         visited: &mut HashSet<String>,
     ) -> Result<Vec<Subcommand>> {
@@ -100,16 +116,27 @@ impl SubcommandDetector {
         let mut subcommands = Vec::new();
 
         for (name, description) in subcommand_candidates {
-            // Skip if already visited (prevent circular references)
-            let visit_key = format!("{}-{}", binary.display(), name);
+            let mut path = parents.to_vec();
+            path.push(name.clone());
+
+            // Skip if already visited (prevent circular references); keyed by the
+            // full path so `remote add` and `config add` are both detected
+            let visit_key = match source {
+                HelpSource::Binary(binary) => format!("{}-{}", binary.display(), path.join(" ")),
+                HelpSource::Directory(dir) => format!("{}-{}", dir.display(), path.join(" ")),
+            };
             if visited.contains(&visit_key) {
-                log::debug!("Skipping already visited subcommand: {}", name);
+                log::debug!("Skipping already visited subcommand: {}", path.join(" "));
                 continue;
             }
             visited.insert(visit_key);
 
             // Get help output for this subcommand
-            let subcommand_help = match self.get_subcommand_help(binary, &name) {
+            let subcommand_help = match source {
+                HelpSource::Binary(binary) => self.get_subcommand_help(binary, &path),
+                HelpSource::Directory(dir) => read_subcommand_help(dir, &path),
+            };
+            let subcommand_help = match subcommand_help {
                 Ok(help) => help,
                 Err(e) => {
                     log::warn!("Failed to get help for subcommand '{}': {}", name, e);
@@ -119,23 +146,33 @@ impl SubcommandDetector {
 
             // Parse options for this subcommand
             let cli_parser = CliParser::new();
-            let mut options = cli_parser.parse_options(&subcommand_help);
+            let mut options = self.parser.parse_options(&subcommand_help);
 
             // Infer option types
             self.option_inferrer.infer_types(&mut options);
 
-            // Parse required positional arguments
-            let required_args = cli_parser.parse_required_args(&subcommand_help);
+            // Parse positional arguments
+            let mut positional_args = cli_parser.parse_positional_args(&subcommand_help);
+            self.option_inferrer
+                .infer_positional_types(&mut positional_args);
+            let required_args = positional_args
+                .iter()
+                .filter(|arg| arg.required)
+                .map(|arg| arg.name.clone())
+                .collect();
+            let option_relations = cli_parser.parse_option_relations(&subcommand_help, &options);
 
             // Recursively detect nested subcommands
             let nested_subcommands =
-                self.detect_recursive(binary, &subcommand_help, current_depth + 1, visited)?;
+                self.detect_recursive(source, &subcommand_help, &path, current_depth + 1, visited)?;
 
             subcommands.push(Subcommand {
                 name,
-                description: Some(description),
+                description: (!description.is_empty()).then_some(description),
                 options,
                 required_args,
+                positional_args,
+                option_relations,
                 subcommands: nested_subcommands,
                 depth: current_depth,
             });
@@ -152,76 +189,49 @@ impl SubcommandDetector {
 
     /// Parse subcommand names and descriptions from help output
     fn parse_subcommands(&self, help_output: &str) -> Vec<(String, String)> {
-        let mut subcommands = Vec::new();
-        let mut in_subcommand_section = false;
-
-        for line in help_output.lines() {
-            // Check if we entered subcommands section
-            if !in_subcommand_section {
-                for header in SUBCOMMAND_HEADERS.iter() {
-                    if line.trim().starts_with(header) {
-                        in_subcommand_section = true;
-                        break;
-                    }
-                }
-                continue;
-            }
-
-            // Check if we left subcommands section (empty line or new section)
-            if line.trim().is_empty() {
-                in_subcommand_section = false;
-                continue;
-            }
-
-            // Parse subcommand line
-            if let Some(captures) = SUBCOMMAND_PATTERN.captures(line) {
-                let name = captures.get(1).unwrap().as_str().to_string();
-                let description = captures.get(2).unwrap().as_str().trim().to_string();
-
-                subcommands.push((name, description));
-            }
-        }
-
-        subcommands
+        self.parser.parse_subcommands(help_output)
     }
 
-    /// Get help output for a specific subcommand
-    fn get_subcommand_help(&self, binary: &Path, subcommand: &str) -> Result<String> {
-        log::debug!("Getting help for subcommand: {}", subcommand);
-
-        // Try: <binary> <subcommand> --help
-        if let Ok(output) = execute_with_timeout(
-            binary,
-            &[subcommand, "--help"],
-            self.resource_limits.timeout(),
-        ) {
-            if !output.trim().is_empty() {
-                return Ok(output);
+    /// Get help output for a specific (possibly nested) subcommand
+    fn get_subcommand_help(&self, binary: &Path, path: &[String]) -> Result<String> {
+        log::debug!("Getting help for subcommand: {}", path.join(" "));
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+
+        // Try: <binary> <subcommand> --help, <binary> <subcommand> -h, <binary> help <subcommand>
+        let attempts = [
+            [path.as_slice(), &["--help"]].concat(),
+            [path.as_slice(), &["-h"]].concat(),
+            [&["help"], path.as_slice()].concat(),
+        ];
+        for args in &attempts {
+            if let Ok(output) = execute_with_timeout(binary, args, self.resource_limits.timeout()) {
+                if !output.trim().is_empty() {
+                    return Ok(output);
+                }
             }
         }
 
-        // Try: <binary> <subcommand> -h
-        if let Ok(output) =
-            execute_with_timeout(binary, &[subcommand, "-h"], self.resource_limits.timeout())
-        {
-            if !output.trim().is_empty() {
-                return Ok(output);
-            }
-        }
+        Err(crate::error::CliTestError::InvalidHelpOutput)
+    }
+}
 
-        // Try: <binary> help <subcommand>
-        if let Ok(output) = execute_with_timeout(
-            binary,
-            &["help", subcommand],
-            self.resource_limits.timeout(),
-        ) {
-            if !output.trim().is_empty() {
-                return Ok(output);
-            }
-        }
+/// Path of the captured help file for a (possibly nested) subcommand
+fn subcommand_help_path(dir: &Path, path: &[String]) -> PathBuf {
+    let mut file = dir.to_path_buf();
+    for name in path {
+        file.push("sub");
+        file.push(name);
+    }
+    file.join("help.txt")
+}
 
-        Err(crate::error::CliTestError::InvalidHelpOutput)
+/// Read captured help output for a subcommand
+fn read_subcommand_help(dir: &Path, path: &[String]) -> Result<String> {
+    let output = fs::read_to_string(subcommand_help_path(dir, path))?;
+    if output.trim().is_empty() {
+        return Err(crate::error::CliTestError::InvalidHelpOutput);
     }
+    Ok(output)
 }
 
 impl Default for SubcommandDetector {
@@ -230,6 +240,7 @@ impl Default for SubcommandDetector {
             resource_limits: ResourceLimits::default(),
             option_inferrer: OptionInferrer::default(),
             max_depth: MAX_RECURSION_DEPTH,
+            parser: parser_for(CliFramework::Unknown),
         })
     }
 }
@@ -238,15 +249,6 @@ impl Default for SubcommandDetector {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_subcommand_pattern() {
-        assert!(SUBCOMMAND_PATTERN.is_match("  help      Show help information"));
-        assert!(SUBCOMMAND_PATTERN.is_match("  config    Manage configuration"));
-        assert!(SUBCOMMAND_PATTERN.is_match("    status    Show status"));
-        assert!(!SUBCOMMAND_PATTERN.is_match("Options:"));
-        assert!(!SUBCOMMAND_PATTERN.is_match("--help"));
-    }
-
     #[test]
     fn test_parse_subcommands_basic() {
         let detector = SubcommandDetector::default();
@@ -370,6 +372,20 @@ Commands:
         assert!(visited.contains("/bin/test-help"));
     }
 
+    #[test]
+    fn test_subcommand_help_path() {
+        let dir = Path::new("captured");
+
+        assert_eq!(
+            subcommand_help_path(dir, &["remote".to_string()]),
+            Path::new("captured/sub/remote/help.txt")
+        );
+        assert_eq!(
+            subcommand_help_path(dir, &["remote".to_string(), "add".to_string()]),
+            Path::new("captured/sub/remote/sub/add/help.txt")
+        );
+    }
+
     #[test]
     fn test_max_depth_limit() {
         let detector = SubcommandDetector::with_max_depth(2).unwrap();
@@ -1,6 +1,8 @@
+use clap::builder::ArgPredicate;
 use clap::{Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// CLI Testing Specialist - Comprehensive testing framework for CLI tools
 #[derive(Parser, Debug)]
@@ -26,10 +28,14 @@ pub enum Commands {
     /// Analyze a CLI tool and extract its structure
     #[command(about = "Analyze CLI tool structure and options")]
     Analyze {
-        /// Path to the CLI binary to analyze
+        /// Path to the CLI binary to analyze (with --from-help-dir: only recorded, not executed)
         #[arg(value_name = "BINARY")]
         binary: PathBuf,
 
+        /// Build the analysis from captured help text (help.txt, version.txt, sub/<name>/help.txt)
+        #[arg(long, value_name = "DIR")]
+        from_help_dir: Option<PathBuf>,
+
         /// Output JSON file path
         #[arg(short, long, default_value = "cli-analysis.json")]
         output: PathBuf,
@@ -41,6 +47,10 @@ pub enum Commands {
         /// Enable parallel processing
         #[arg(long)]
         parallel: bool,
+
+        /// Also parse the bash/zsh/fish completion scripts the binary can generate
+        #[arg(long)]
+        completions: bool,
     },
 
     /// Generate test cases from analysis results
@@ -69,11 +79,28 @@ pub enum Commands {
     },
 
     /// Run BATS tests and generate reports
-    #[command(about = "Execute BATS tests and generate reports")]
+    #[command(about = "Execute tests (BATS or native runner) and generate reports")]
     Run {
-        /// Test directory containing BATS files
-        #[arg(value_name = "TEST_DIR")]
-        test_dir: PathBuf,
+        /// Test directory containing BATS files (BATS runner)
+        #[arg(
+            value_name = "TEST_DIR",
+            required_unless_present = "analysis",
+            required_if_eq("runner", "bats"),
+            conflicts_with = "analysis"
+        )]
+        test_dir: Option<PathBuf>,
+
+        /// Analysis JSON file to generate the tests from (native runner)
+        #[arg(long, value_name = "FILE", required_if_eq("runner", "native"))]
+        analysis: Option<PathBuf>,
+
+        /// Test categories to run with the native runner (comma-separated or "all")
+        #[arg(short, long, default_value = "all")]
+        categories: String,
+
+        /// Include resource-intensive tests with the native runner (directory-traversal)
+        #[arg(long)]
+        include_intensive: bool,
 
         /// Report format to generate
         #[arg(short, long, default_value = "markdown")]
@@ -83,13 +110,74 @@ pub enum Commands {
         #[arg(short, long, default_value = "reports")]
         output: PathBuf,
 
-        /// Timeout per test suite in seconds (default: 300)
+        /// Timeout per test suite in seconds (BATS runner)
         #[arg(short = 't', long, default_value = "300")]
         timeout: u64,
 
+        /// Default timeout per test in seconds (default: global.timeout from .cli-test-config.yml; native runner: 30, BATS: none)
+        #[arg(long, value_name = "SECS")]
+        test_timeout: Option<u64>,
+
         /// Skip specific test categories (comma-separated)
         #[arg(short = 's', long)]
         skip: Option<String>,
+
+        /// Test runner (bats: execute .bats files, native: run tests from analysis JSON without bash/BATS; default: native with --analysis)
+        #[arg(
+            short,
+            long,
+            default_value = "bats",
+            default_value_if("analysis", ArgPredicate::IsPresent, "native")
+        )]
+        runner: Runner,
+
+        /// Number of concurrent workers (BATS: suites, native: tests)
+        #[arg(short = 'j', long, default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+
+        /// Record performance history per binary in DIR and compare against it (e.g. .cli-test/history)
+        #[arg(long, value_name = "DIR")]
+        history_dir: Option<PathBuf>,
+
+        /// Compare performance against the latest run of this binary version (default: previous run)
+        #[arg(long, value_name = "VERSION", requires = "history_dir")]
+        baseline: Option<String>,
+
+        /// Exit non-zero when mean startup time rises significantly by at least PERCENT
+        #[arg(long, value_name = "PERCENT", value_parser = parse_percent, requires = "history_dir")]
+        fail_on_regression: Option<f64>,
+    },
+
+    /// Fuzz a CLI tool with command lines generated from its analysis
+    #[command(about = "Fuzz a CLI tool and save minimized crashing inputs")]
+    Fuzz {
+        /// Analysis JSON file path
+        #[arg(value_name = "ANALYSIS")]
+        analysis: PathBuf,
+
+        /// How long to fuzz (e.g. 90, 30s, 10m, 1h)
+        #[arg(short, long, default_value = "1m", value_parser = parse_duration)]
+        duration: Duration,
+
+        /// Directory for minimized crashing test cases (replayed before fuzzing)
+        #[arg(short, long, default_value = "fuzz-corpus")]
+        corpus: PathBuf,
+
+        /// Seed for the input generator (default: random, printed for repeat runs)
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Timeout per execution in seconds
+        #[arg(short = 't', long, default_value = "5", value_parser = clap::value_parser!(u64).range(1..))]
+        timeout: u64,
+
+        /// Memory limit per execution in MB (0: no limit, needed for AddressSanitizer builds)
+        #[arg(long, value_name = "MB", default_value = "500")]
+        max_memory: u64,
+
+        /// Stop after this many executions
+        #[arg(long)]
+        max_runs: Option<u64>,
     },
 
     /// Validate analysis JSON file
@@ -124,6 +212,9 @@ pub enum ReportFormat {
     /// JUnit XML format
     Junit,
 
+    /// SARIF 2.1.0 format (security findings)
+    Sarif,
+
     /// All formats
     All,
 }
@@ -136,11 +227,22 @@ impl ReportFormat {
             Self::Json => "json",
             Self::Html => "html",
             Self::Junit => "xml",
+            Self::Sarif => "sarif",
             Self::All => "all",
         }
     }
 }
 
+/// Test runner
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Runner {
+    /// BATS (requires bash and bats-core)
+    Bats,
+
+    /// Native Rust runner (executes generated test cases directly)
+    Native,
+}
+
 /// Test framework format
 #[derive(ValueEnum, Clone, Debug)]
 pub enum TestFormat {
@@ -175,6 +277,40 @@ impl TestFormat {
     }
 }
 
+/// Parse a non-negative percentage for `--fail-on-regression`
+fn parse_percent(value: &str) -> Result<f64, String> {
+    let percent: f64 = value
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| format!("`{}` is not a number", value))?;
+    if percent.is_finite() && percent >= 0.0 {
+        Ok(percent)
+    } else {
+        Err(format!("`{}` must be a non-negative percentage", value))
+    }
+}
+
+/// Parse a duration for `fuzz --duration` (`90`, `30s`, `10m`, `1h`)
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => value.split_at(index),
+        None => (value, "s"),
+    };
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("`{}` is not a duration", value))?;
+    let seconds = match unit {
+        "s" => Some(number),
+        "m" => number.checked_mul(60),
+        "h" => number.checked_mul(3600),
+        _ => return Err(format!("`{}` has an unknown unit (use s, m or h)", value)),
+    };
+    match seconds {
+        Some(seconds) if seconds > 0 => Ok(Duration::from_secs(seconds)),
+        _ => Err(format!("`{}` must be a positive duration", value)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,5 +321,86 @@ mod tests {
         assert_eq!(ReportFormat::Json.extension(), "json");
         assert_eq!(ReportFormat::Html.extension(), "html");
         assert_eq!(ReportFormat::Junit.extension(), "xml");
+        assert_eq!(ReportFormat::Sarif.extension(), "sarif");
+    }
+
+    #[test]
+    fn test_run_inputs_per_runner() {
+        let cli = Cli::try_parse_from(["cli-test", "run", "test-output"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run {
+                test_dir: Some(_),
+                analysis: None,
+                ..
+            }
+        ));
+
+        let cli = Cli::try_parse_from([
+            "cli-test",
+            "run",
+            "--runner",
+            "native",
+            "--analysis",
+            "analysis.json",
+            "-c",
+            "security,directory-traversal",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run { test_dir: None, analysis: Some(_), ref categories, .. }
+                if categories == "security,directory-traversal"
+        ));
+
+        // --analysis alone selects the native runner
+        let cli = Cli::try_parse_from(["cli-test", "run", "--analysis", "analysis.json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run {
+                runner: Runner::Native,
+                ..
+            }
+        ));
+
+        // Each runner needs its own input
+        assert!(Cli::try_parse_from(["cli-test", "run"]).is_err());
+        assert!(Cli::try_parse_from([
+            "cli-test",
+            "run",
+            "--runner",
+            "bats",
+            "--analysis",
+            "analysis.json"
+        ])
+        .is_err());
+        assert!(Cli::try_parse_from(["cli-test", "run", "--runner", "native"]).is_err());
+        assert!(Cli::try_parse_from([
+            "cli-test",
+            "run",
+            "test-output",
+            "--analysis",
+            "analysis.json"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_percent() {
+        assert_eq!(parse_percent("10"), Ok(10.0));
+        assert_eq!(parse_percent("2.5%"), Ok(2.5));
+        assert!(parse_percent("-5").is_err());
+        assert!(parse_percent("fast").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("10m"), Ok(Duration::from_secs(600)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert!(parse_duration("0m").is_err());
+        assert!(parse_duration("10d").is_err());
+        assert!(parse_duration("soon").is_err());
     }
 }
@@ -1,9 +1,13 @@
 use crate::error::Result;
+use crate::generator::test_generator::{
+    allowed_exit_codes, boundary_cases, combination_cases, enum_cases, env_cases, positional_cases,
+    relation_cases, slugify, ValueCase,
+};
 use crate::generator::test_generator_trait::TestGenerator as TestGeneratorTrait;
 use crate::types::analysis::CliAnalysis;
 use crate::types::test_case::TestCategory;
 use handlebars::Handlebars;
-use serde_json::json;
+use serde_json::{json, Value};
 
 /// Generator for assert_cmd-based Rust tests
 ///
@@ -27,7 +31,9 @@ use serde_json::json;
 /// ```
 pub struct AssertCmdGenerator {
     handlebars: Handlebars<'static>,
-    cli_name: String,
+
+    /// Binary name for `Command::cargo_bin` (default: the analysis' binary name)
+    cli_name: Option<String>,
 }
 
 impl AssertCmdGenerator {
@@ -41,6 +47,12 @@ impl AssertCmdGenerator {
     ///
     /// New AssertCmdGenerator instance
     pub fn new(analysis: &CliAnalysis) -> Result<Self> {
+        Ok(Self::from_analyses()?.with_cli_name(analysis.binary_name.clone()))
+    }
+
+    /// Create a generator that takes the CLI name from each analysis passed to
+    /// `generate` (used by the generator registry)
+    pub(crate) fn from_analyses() -> Result<Self> {
         let mut handlebars = Handlebars::new();
 
         // Register templates
@@ -51,10 +63,16 @@ impl AssertCmdGenerator {
 
         Ok(Self {
             handlebars,
-            cli_name: analysis.binary_name.clone(),
+            cli_name: None,
         })
     }
 
+    /// Set the binary name passed to `Command::cargo_bin`
+    pub fn with_cli_name(mut self, cli_name: String) -> Self {
+        self.cli_name = Some(cli_name);
+        self
+    }
+
     /// Register all test templates
     fn register_templates(handlebars: &mut Handlebars) -> Result<()> {
         // Basic tests template
@@ -99,9 +117,161 @@ impl AssertCmdGenerator {
             include_str!("../templates/assert_cmd/multi_shell.hbs"),
         )?;
 
+        // Environment tests template
+        handlebars.register_template_string(
+            "environment",
+            include_str!("../templates/assert_cmd/environment.hbs"),
+        )?;
+
+        // Option relation tests template
+        handlebars.register_template_string(
+            "option_relations",
+            include_str!("../templates/assert_cmd/option_relations.hbs"),
+        )?;
+
+        // Pairwise combination tests template
+        handlebars.register_template_string(
+            "combinatorial",
+            include_str!("../templates/assert_cmd/combinatorial.hbs"),
+        )?;
+
         Ok(())
     }
 
+    /// Template data for options bound to an environment variable
+    ///
+    /// `value` is null for flags (the variable is set to `1`); `invalid` is null
+    /// when the option has no value that must be rejected. `path` and `required`
+    /// are the arguments of the command owning the option.
+    pub(crate) fn environment_data(analysis: &CliAnalysis) -> Vec<Value> {
+        let sanitize_all = |args: &[String]| {
+            args.iter()
+                .map(|arg| Self::sanitize_for_rust_string(arg))
+                .collect::<Vec<_>>()
+        };
+
+        env_cases(analysis)
+            .into_iter()
+            .filter(|case| case.is_flag || case.value.is_some())
+            .map(|case| {
+                json!({
+                    "name": Self::sanitize_for_rust_string(&case.var),
+                    "ident": case
+                        .path
+                        .iter()
+                        .chain([&case.var])
+                        .map(|part| slugify(part).replace('-', "_"))
+                        .collect::<Vec<_>>()
+                        .join("_"),
+                    "flag": Self::sanitize_for_rust_string(&case.flag),
+                    "value": case.value.as_deref().map(Self::sanitize_for_rust_string),
+                    "invalid": case.invalid.as_deref().map(Self::sanitize_for_rust_string),
+                    "path": sanitize_all(&case.path),
+                    "required": sanitize_all(&case.required),
+                })
+            })
+            .collect()
+    }
+
+    /// Template data for invocations with missing, extra or wrongly typed positionals
+    pub(crate) fn positional_data(analysis: &CliAnalysis) -> Vec<Value> {
+        positional_cases(analysis)
+            .into_iter()
+            .map(|case| {
+                json!({
+                    "ident": case.id.trim_start_matches("positional-").replace('-', "_"),
+                    "name": Self::sanitize_for_rust_string(&case.name),
+                    "args": case
+                        .args
+                        .iter()
+                        .map(|arg| Self::sanitize_for_rust_string(arg))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect()
+    }
+
+    /// Template data for numeric options given boundary values
+    pub(crate) fn boundary_data(analysis: &CliAnalysis) -> Vec<Value> {
+        Self::value_case_data(boundary_cases(analysis), "boundary-")
+    }
+
+    /// Template data for enum options given declared values and their variants
+    pub(crate) fn enum_data(analysis: &CliAnalysis) -> Vec<Value> {
+        Self::value_case_data(enum_cases(analysis), "enum-")
+    }
+
+    /// Whether any boundary or enum case only checks the option parser's verdict
+    pub(crate) fn has_parser_checks(analysis: &CliAnalysis) -> bool {
+        boundary_cases(analysis)
+            .iter()
+            .chain(&enum_cases(analysis))
+            .any(|case| case.parser_check)
+    }
+
+    /// Template data for [`ValueCase`]s; `accepted` selects `.success()` or
+    /// `.failure()`, `parser_check` calls `assert_value_not_rejected` instead
+    fn value_case_data(cases: Vec<ValueCase>, id_prefix: &str) -> Vec<Value> {
+        cases
+            .into_iter()
+            .map(|case| {
+                json!({
+                    "ident": case.id.trim_start_matches(id_prefix).replace('-', "_"),
+                    "name": Self::sanitize_for_rust_string(&case.name),
+                    "args": case
+                        .args
+                        .iter()
+                        .map(|arg| Self::sanitize_for_rust_string(arg))
+                        .collect::<Vec<_>>(),
+                    "option": Self::sanitize_for_rust_string(&case.option),
+                    "value": Self::sanitize_for_rust_string(&case.value),
+                    "accepted": case.accepted,
+                    "parser_check": case.parser_check,
+                })
+            })
+            .collect()
+    }
+
+    /// Template data for pairwise option combinations
+    pub(crate) fn combination_data(analysis: &CliAnalysis) -> Vec<Value> {
+        combination_cases(analysis)
+            .into_iter()
+            .map(|case| {
+                json!({
+                    "ident": case.id.trim_start_matches("combinatorial-").replace('-', "_"),
+                    "name": Self::sanitize_for_rust_string(&case.name),
+                    "args": case
+                        .args
+                        .iter()
+                        .map(|arg| Self::sanitize_for_rust_string(arg))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect()
+    }
+
+    /// Template data for invocations that violate option relations
+    ///
+    /// `requires` is the missing option the error message must name, or null
+    /// for conflicts.
+    pub(crate) fn relation_data(analysis: &CliAnalysis) -> Vec<Value> {
+        relation_cases(analysis)
+            .into_iter()
+            .map(|case| {
+                json!({
+                    "ident": case.id.trim_start_matches("relation-").replace('-', "_"),
+                    "name": Self::sanitize_for_rust_string(&case.name),
+                    "args": case
+                        .args
+                        .iter()
+                        .map(|arg| Self::sanitize_for_rust_string(arg))
+                        .collect::<Vec<_>>(),
+                    "requires": case.requires.map(|r| Self::sanitize_for_rust_string(&r)),
+                })
+            })
+            .collect()
+    }
+
     /// Sanitize string for Rust code generation
     ///
     /// Escapes special characters to prevent code injection and ensure valid Rust syntax.
@@ -141,11 +311,16 @@ impl TestGeneratorTrait for AssertCmdGenerator {
             TestCategory::DirectoryTraversal => "security", // Reuse security template
             TestCategory::Performance => "performance",
             TestCategory::MultiShell => "multi_shell",
+            TestCategory::Environment => "environment",
+            TestCategory::OptionRelations => "option_relations",
+            TestCategory::Combinatorial => "combinatorial",
         };
 
         // Prepare template data
         let data = json!({
-            "cli_name": Self::sanitize_for_rust_string(&self.cli_name),
+            "cli_name": Self::sanitize_for_rust_string(
+                self.cli_name.as_deref().unwrap_or(&analysis.binary_name)
+            ),
             "version": analysis.version.as_ref().map(|v| Self::sanitize_for_rust_string(v)),
             "subcommands": analysis.subcommands.iter().map(|sc| {
                 json!({
@@ -153,6 +328,14 @@ impl TestGeneratorTrait for AssertCmdGenerator {
                     "description": sc.description.as_ref().map(|d| Self::sanitize_for_rust_string(d)),
                 })
             }).collect::<Vec<_>>(),
+            "environment_variables": Self::environment_data(analysis),
+            "positional_tests": Self::positional_data(analysis),
+            "boundary_tests": Self::boundary_data(analysis),
+            "enum_tests": Self::enum_data(analysis),
+            "parser_checks": Self::has_parser_checks(analysis),
+            "relation_tests": Self::relation_data(analysis),
+            "combination_tests": Self::combination_data(analysis),
+            "allowed_exit_codes": allowed_exit_codes(analysis),
         });
 
         // Render template
@@ -173,6 +356,8 @@ impl TestGeneratorTrait for AssertCmdGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::analysis::{CliOption, OptionSource, OptionType};
+    use std::path::PathBuf;
 
     #[test]
     fn test_sanitize_for_rust_string() {
@@ -207,4 +392,131 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn test_cli_name() {
+        let analysis = CliAnalysis::new(
+            PathBuf::from("/usr/local/bin/test-cli"),
+            "test-cli".to_string(),
+            String::new(),
+        );
+
+        let code = AssertCmdGenerator::new(&analysis)
+            .unwrap()
+            .generate(&analysis, TestCategory::Basic)
+            .unwrap();
+        assert!(code.contains("Command::cargo_bin(\"test-cli\")"));
+
+        let code = AssertCmdGenerator::new(&analysis)
+            .unwrap()
+            .with_cli_name("my-cli".to_string())
+            .generate(&analysis, TestCategory::Basic)
+            .unwrap();
+        assert!(code.contains("Command::cargo_bin(\"my-cli\")"));
+        assert!(!code.contains("test-cli"));
+    }
+
+    #[test]
+    fn test_generate_input_validation_enum_tests() {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/local/bin/test-cli"),
+            "test-cli".to_string(),
+            String::new(),
+        );
+        let generator = AssertCmdGenerator::new(&analysis).unwrap();
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--comparison".to_string()),
+            description: None,
+            option_type: OptionType::Enum {
+                values: vec!["eq".to_string(), ">=".to_string()],
+            },
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        });
+
+        let code = generator
+            .generate(&analysis, TestCategory::InputValidation)
+            .unwrap();
+
+        // Arguments are Rust-escaped only, never HTML-escaped
+        assert!(code.contains(
+            "fn test_enum_comparison_value_2() {\n    let mut cmd = Command::cargo_bin(\"test-cli\").unwrap();\n    cmd\n        .arg(\"--comparison\")\n        .arg(\">=\")\n        .assert()\n        .success();"
+        ));
+        // Comparison operators are case-sensitive in enum-definitions.yaml
+        assert!(code.contains(
+            "fn test_enum_comparison_uppercase() {\n    let mut cmd = Command::cargo_bin(\"test-cli\").unwrap();\n    cmd\n        .arg(\"--comparison\")\n        .arg(\"EQ\")\n        .assert()\n        .failure();"
+        ));
+        assert!(!code.contains("fn assert_value_not_rejected("));
+
+        // Declared values on a subcommand only have to get past the parser
+        let comparison = analysis.global_options[0].clone();
+        analysis.subcommands.push(crate::types::Subcommand {
+            name: "filter".to_string(),
+            description: None,
+            options: vec![comparison],
+            required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
+            subcommands: vec![],
+            depth: 1,
+        });
+        let code = generator
+            .generate(&analysis, TestCategory::InputValidation)
+            .unwrap();
+
+        assert!(code.contains(
+            "fn assert_value_not_rejected(cmd: &mut Command, option: &str, value: &str) {"
+        ));
+        assert!(code.contains(
+            "fn test_enum_filter_comparison_value_2() {\n    let mut cmd = Command::cargo_bin(\"test-cli\").unwrap();\n    assert_value_not_rejected(\n        cmd\n            .arg(\"filter\")\n            .arg(\"--comparison\")\n            .arg(\">=\"),\n        \"--comparison\",\n        \">=\",\n    );\n}"
+        ));
+        assert!(code.contains(
+            "fn test_enum_filter_comparison_uppercase() {\n    let mut cmd = Command::cargo_bin(\"test-cli\").unwrap();\n    cmd\n        .arg(\"filter\")"
+        ));
+    }
+
+    #[test]
+    fn test_generate_environment_tests_for_subcommand_options() {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/local/bin/test-cli"),
+            "test-cli".to_string(),
+            String::new(),
+        );
+        let generator = AssertCmdGenerator::new(&analysis).unwrap();
+        analysis.subcommands.push(crate::types::Subcommand {
+            name: "serve".to_string(),
+            description: None,
+            options: vec![CliOption {
+                short: None,
+                long: Some("--port".to_string()),
+                description: None,
+                option_type: OptionType::Numeric {
+                    min: Some(1),
+                    max: Some(65535),
+                },
+                required: false,
+                default_value: Some("10".to_string()),
+                env_var: Some("SERVE_PORT".to_string()),
+                source: OptionSource::Help,
+            }],
+            required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
+            subcommands: vec![],
+            depth: 1,
+        });
+
+        let code = generator
+            .generate(&analysis, TestCategory::Environment)
+            .unwrap();
+
+        // Invoked through the subcommand, with a value other than the default
+        assert!(code.contains(
+            "fn test_env_serve_serve_port_matches_flag() {\n    let from_flag = Command::cargo_bin(\"test-cli\")\n        .unwrap()\n        .arg(\"serve\")\n        .arg(\"--port\")\n        .arg(\"11\")"
+        ));
+        assert!(code.contains("        .arg(\"serve\")\n        .env(\"SERVE_PORT\", \"11\")"));
+    }
 }
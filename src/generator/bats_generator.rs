@@ -0,0 +1,189 @@
+use crate::error::Result;
+use crate::generator::bats_writer::{render_bats_file, validate_bats_content};
+use crate::generator::test_generator::TestGenerator as TestCaseGenerator;
+use crate::generator::test_generator_trait::{GeneratedTests, TestGenerator as TestGeneratorTrait};
+use crate::types::analysis::CliAnalysis;
+use crate::types::test_case::{TestCase, TestCategory};
+use std::path::PathBuf;
+
+/// Generator for BATS (Bash Automated Testing System) test scripts
+///
+/// Adapts the test case pipeline (`TestGenerator` + `BatsWriter` rendering) to the
+/// common [`TestGeneratorTrait`] interface so BATS is selectable through
+/// [`create_generator`](crate::generator::test_generator_trait::create_generator)
+/// like every other format.
+///
+/// Returns an empty string for categories that produce no test cases, so callers
+/// can skip writing a file.
+pub struct BatsGenerator {
+    /// Explicit `.cli-test-config.yml` path (auto-detected when `None`)
+    config_path: Option<PathBuf>,
+}
+
+impl BatsGenerator {
+    /// Create a new BatsGenerator that auto-detects `.cli-test-config.yml`
+    pub fn new() -> Self {
+        Self { config_path: None }
+    }
+
+    /// Create a new BatsGenerator using an explicit configuration file
+    pub fn with_config_path(config_path: PathBuf) -> Self {
+        Self {
+            config_path: Some(config_path),
+        }
+    }
+
+    /// Generate test cases for all categories with one `TestGenerator`
+    ///
+    /// The configuration is loaded once and the parallel strategy is chosen for
+    /// the whole workload rather than per category.
+    fn generate_test_cases(
+        &self,
+        analysis: &CliAnalysis,
+        categories: &[TestCategory],
+    ) -> Result<Vec<TestCase>> {
+        let generator = TestCaseGenerator::with_config(
+            analysis.clone(),
+            categories.to_vec(),
+            self.config_path.as_deref(),
+        )?;
+
+        generator.generate_with_strategy()
+    }
+
+    /// Render and validate the BATS file for one category
+    fn render(
+        &self,
+        analysis: &CliAnalysis,
+        category: TestCategory,
+        tests: &[&TestCase],
+    ) -> Result<String> {
+        let content = render_bats_file(
+            &analysis.binary_name,
+            &analysis.binary_path,
+            category,
+            tests,
+        )?;
+
+        validate_bats_content(&content)?;
+
+        Ok(content)
+    }
+}
+
+impl Default for BatsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestGeneratorTrait for BatsGenerator {
+    fn generate(&self, analysis: &CliAnalysis, category: TestCategory) -> Result<String> {
+        let test_cases = self.generate_test_cases(analysis, &[category])?;
+
+        if test_cases.is_empty() {
+            log::debug!("No BATS tests generated for category {:?}", category);
+            return Ok(String::new());
+        }
+
+        let tests: Vec<&TestCase> = test_cases.iter().collect();
+        self.render(analysis, category, &tests)
+    }
+
+    fn generate_categories(
+        &self,
+        analysis: &CliAnalysis,
+        categories: &[TestCategory],
+    ) -> Result<Vec<GeneratedTests>> {
+        let test_cases = self.generate_test_cases(analysis, categories)?;
+        log::info!("Generated {} test cases", test_cases.len());
+
+        let mut results = Vec::new();
+        for &category in categories {
+            let tests: Vec<&TestCase> = test_cases
+                .iter()
+                .filter(|test| test.category == category)
+                .collect();
+            if tests.is_empty() {
+                log::debug!("No BATS tests generated for category {:?}", category);
+                continue;
+            }
+
+            results.push(GeneratedTests {
+                category,
+                code: self.render(analysis, category, &tests)?,
+                test_count: Some(tests.len()),
+            });
+        }
+
+        Ok(results)
+    }
+
+    fn file_extension(&self) -> &str {
+        "bats"
+    }
+
+    fn name(&self) -> &str {
+        "bats"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_analysis() -> CliAnalysis {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/bin/test-cli"),
+            "test-cli".to_string(),
+            "Usage: test-cli [OPTIONS]\n\nOptions:\n  -h, --help  Print help\n".to_string(),
+        );
+        analysis.version = Some("1.0.0".to_string());
+        analysis
+    }
+
+    #[test]
+    fn test_generate_basic_bats() {
+        let generator = BatsGenerator::new();
+        let analysis = create_test_analysis();
+
+        let content = generator.generate(&analysis, TestCategory::Basic).unwrap();
+
+        assert!(content.starts_with("#!/usr/bin/env bats"));
+        assert!(content.contains("@test \"[basic]"));
+        assert!(content.contains("CLI_BINARY=\"/usr/bin/test-cli\""));
+        assert!(validate_bats_content(&content).is_ok());
+    }
+
+    #[test]
+    fn test_generate_categories_counts_tests() {
+        let generator = BatsGenerator::new();
+        let analysis = create_test_analysis();
+        let categories = [
+            TestCategory::Basic,
+            TestCategory::DestructiveOps,
+            TestCategory::Performance,
+        ];
+
+        let generated = generator
+            .generate_categories(&analysis, &categories)
+            .unwrap();
+
+        // No subcommands, so there are no destructive operations to test
+        let generated_categories: Vec<_> = generated.iter().map(|t| t.category).collect();
+        assert_eq!(
+            generated_categories,
+            vec![TestCategory::Basic, TestCategory::Performance]
+        );
+        for tests in &generated {
+            assert_eq!(tests.test_count, Some(tests.code.matches("@test ").count()));
+        }
+    }
+
+    #[test]
+    fn test_bats_generator_metadata() {
+        let generator = BatsGenerator::default();
+        assert_eq!(generator.file_extension(), "bats");
+        assert_eq!(generator.name(), "bats");
+    }
+}
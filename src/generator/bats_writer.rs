@@ -1,6 +1,7 @@
 use crate::error::{Error, Result};
+use crate::generator::shells::ShellDialect;
 use crate::generator::TemplateEngine;
-use crate::types::{Assertion, TestCase, TestCategory};
+use crate::types::{Assertion, PerformanceBudget, TestCase, TestCategory};
 use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{BufWriter, Write};
@@ -110,24 +111,15 @@ impl BatsWriter {
             category
         );
 
+        let content = self.render_category(category, &tests)?;
+
         let file = File::create(&output_path)
             .map_err(|e| Error::Config(format!("Failed to create BATS file: {}", e)))?;
 
         let mut writer = BufWriter::new(file);
-
-        // Write file header
-        self.write_header(&mut writer, category)?;
-
-        // Write setup function
-        self.write_setup(&mut writer)?;
-
-        // Write teardown function
-        self.write_teardown(&mut writer)?;
-
-        // Write test cases
-        for test in tests {
-            self.write_test_case(&mut writer, test)?;
-        }
+        writer
+            .write_all(content.as_bytes())
+            .map_err(|e| Error::Config(format!("Failed to write BATS file: {}", e)))?;
 
         writer
             .flush()
@@ -137,206 +129,516 @@ impl BatsWriter {
         Ok(output_path)
     }
 
-    /// Write BATS file header
-    fn write_header(&self, writer: &mut BufWriter<File>, category: TestCategory) -> Result<()> {
-        writeln!(writer, "#!/usr/bin/env bats")?;
-        writeln!(writer, "#")?;
-        writeln!(
-            writer,
-            "# BATS Test Suite: {}",
-            category.as_str().to_uppercase()
-        )?;
-        writeln!(writer, "# Generated by CLI Testing Specialist")?;
-        writeln!(writer, "# Target CLI: {}", self.binary_name)?;
-        writeln!(writer, "#")?;
-        writeln!(writer)?;
+    /// Render a complete BATS file for a category without touching the filesystem
+    pub fn render_category(&self, category: TestCategory, tests: &[&TestCase]) -> Result<String> {
+        render_bats_file(&self.binary_name, &self.binary_path, category, tests)
+    }
+
+    /// Validate generated BATS file syntax
+    pub fn validate_bats_file(&self, file_path: &Path) -> Result<()> {
+        // Check if file exists
+        if !file_path.exists() {
+            return Err(Error::Validation(format!(
+                "BATS file does not exist: {}",
+                file_path.display()
+            )));
+        }
+
+        // Read file content
+        let content = fs::read_to_string(file_path)
+            .map_err(|e| Error::Config(format!("Failed to read BATS file: {}", e)))?;
 
+        validate_bats_content(&content)?;
+
+        log::debug!("BATS file validation passed: {}", file_path.display());
         Ok(())
     }
+}
+
+/// Render a complete BATS file (header, setup, teardown and test cases) for a category
+///
+/// Shared by [`BatsWriter`] and the trait-based `BatsGenerator`.
+pub fn render_bats_file(
+    binary_name: &str,
+    binary_path: &Path,
+    category: TestCategory,
+    tests: &[&TestCase],
+) -> Result<String> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    write_header(&mut buffer, binary_name, category)?;
+    write_setup(&mut buffer, binary_name, binary_path)?;
+    write_teardown(&mut buffer)?;
+    if tests.iter().any(|test| test.timeout.is_some()) {
+        write_timeout_helper(&mut buffer)?;
+    }
 
-    /// Write setup function
-    fn write_setup(&self, writer: &mut BufWriter<File>) -> Result<()> {
-        writeln!(writer, "# Setup function (runs before each test)")?;
-        writeln!(writer, "setup() {{")?;
-        writeln!(writer, "    # Set CLI binary path")?;
-        writeln!(writer, "    CLI_BINARY=\"{}\"", self.binary_path.display())?;
-        writeln!(writer, "    BINARY_BASENAME=\"{}\"", self.binary_name)?;
-        writeln!(writer)?;
-        writeln!(
-            writer,
-            "    # Export CLI_BINARY for subshell tests (multi-shell compatibility)"
-        )?;
-        writeln!(writer, "    export CLI_BINARY")?;
+    for test in tests {
+        write_test_case(&mut buffer, test)?;
+    }
+
+    String::from_utf8(buffer)
+        .map_err(|e| Error::Config(format!("Generated BATS file is not valid UTF-8: {}", e)))
+}
+
+/// Validate BATS file content (shebang, test blocks, balanced braces)
+pub fn validate_bats_content(content: &str) -> Result<()> {
+    // Basic validation checks
+    if !content.starts_with("#!/usr/bin/env bats") {
+        return Err(Error::Validation(
+            "BATS file missing shebang line".to_string(),
+        ));
+    }
+
+    // Check for at least one @test block
+    if !content.contains("@test") {
+        return Err(Error::Validation(
+            "BATS file contains no test cases".to_string(),
+        ));
+    }
+
+    // Check for balanced braces (simple check)
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+
+    if open_braces != close_braces {
+        return Err(Error::Validation(format!(
+            "Unbalanced braces: {} open, {} close",
+            open_braces, close_braces
+        )));
+    }
+
+    Ok(())
+}
+
+/// Write BATS file header
+fn write_header<W: Write>(writer: &mut W, binary_name: &str, category: TestCategory) -> Result<()> {
+    writeln!(writer, "#!/usr/bin/env bats")?;
+    writeln!(writer, "#")?;
+    writeln!(
+        writer,
+        "# BATS Test Suite: {}",
+        category.as_str().to_uppercase()
+    )?;
+    writeln!(writer, "# Generated by CLI Testing Specialist")?;
+    writeln!(writer, "# Target CLI: {}", binary_name)?;
+    writeln!(writer, "#")?;
+    writeln!(writer)?;
+
+    Ok(())
+}
+
+/// Write setup function
+fn write_setup<W: Write>(writer: &mut W, binary_name: &str, binary_path: &Path) -> Result<()> {
+    writeln!(writer, "# Setup function (runs before each test)")?;
+    writeln!(writer, "setup() {{")?;
+    writeln!(writer, "    # Set CLI binary path")?;
+    writeln!(writer, "    CLI_BINARY=\"{}\"", binary_path.display())?;
+    writeln!(writer, "    BINARY_BASENAME=\"{}\"", binary_name)?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    # Export CLI_BINARY for subshell tests (multi-shell compatibility)"
+    )?;
+    writeln!(writer, "    export CLI_BINARY")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    # Create temporary directory for test artifacts"
+    )?;
+    writeln!(writer, "    TEST_TEMP_DIR=\"$(mktemp -d)\"")?;
+    writeln!(writer, "    export TEST_TEMP_DIR")?;
+    writeln!(writer)?;
+    writeln!(writer, "    # Set secure umask")?;
+    writeln!(writer, "    umask 077")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+
+    Ok(())
+}
+
+/// Write teardown function
+fn write_teardown<W: Write>(writer: &mut W) -> Result<()> {
+    writeln!(writer, "# Teardown function (runs after each test)")?;
+    writeln!(writer, "teardown() {{")?;
+    writeln!(writer, "    # Cleanup temporary directory")?;
+    writeln!(
+        writer,
+        "    if [[ -n \"${{TEST_TEMP_DIR:-}}\" ]] && [[ -d \"$TEST_TEMP_DIR\" ]]; then"
+    )?;
+    writeln!(writer, "        rm -rf \"$TEST_TEMP_DIR\"")?;
+    writeln!(writer, "    fi")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+
+    Ok(())
+}
+
+/// Write `run_with_timeout`, a portable replacement for GNU `timeout`
+///
+/// macOS and some BSDs ship no `timeout`; the helper falls back to `gtimeout`
+/// (Homebrew coreutils) and then to a bash watchdog. Like `timeout`, it exits
+/// with status 124 when the limit is hit.
+fn write_timeout_helper<W: Write>(writer: &mut W) -> Result<()> {
+    const HELPER: &str = r#"# Run a command with a time limit (exit status 124 on timeout)
+run_with_timeout() {
+    local seconds="$1"
+    shift
+    if command -v timeout >/dev/null 2>&1; then
+        timeout --kill-after=5 "$seconds" "$@"
+        return
+    fi
+    if command -v gtimeout >/dev/null 2>&1; then
+        gtimeout --kill-after=5 "$seconds" "$@"
+        return
+    fi
+
+    # No timeout(1): kill the command from a background watchdog
+    local marker="$TEST_TEMP_DIR/timed-out"
+    rm -f "$marker"
+    "$@" &
+    local pid=$!
+    (
+        sleep "$seconds"
+        touch "$marker"
+        kill -TERM "$pid" 2>/dev/null
+        sleep 5
+        kill -KILL "$pid" 2>/dev/null
+    ) >/dev/null 2>&1 3>&- &
+    local watchdog=$!
+    local status=0
+    wait "$pid" || status=$?
+    kill "$watchdog" 2>/dev/null
+    if [ -e "$marker" ]; then
+        rm -f "$marker"
+        return 124
+    fi
+    return "$status"
+}
+"#;
+    writeln!(writer, "{}", HELPER)?;
+
+    Ok(())
+}
+
+/// Write a single test case
+fn write_test_case<W: Write>(writer: &mut W, test: &TestCase) -> Result<()> {
+    // Write test annotation
+    writeln!(
+        writer,
+        "@test \"[{}] {}\" {{",
+        test.category.as_str(),
+        test.name
+    )?;
+
+    // Write test description comment
+    writeln!(writer, "    # Test ID: {}", test.id)?;
+    if !test.tags.is_empty() {
+        writeln!(writer, "    # Tags: {}", test.tags.join(", "))?;
+    }
+    writeln!(writer, "    # Priority: {}", test.priority.as_str())?;
+    writeln!(writer)?;
+
+    // Skip (rather than fail) when a required program is not installed
+    if !test.requires.is_empty() {
+        writeln!(writer, "    # Skip if required programs are missing")?;
+        for program in &test.requires {
+            writeln!(
+                writer,
+                "    command -v {} >/dev/null 2>&1 || skip \"{} is not installed\"",
+                program, program
+            )?;
+        }
         writeln!(writer)?;
+    }
+
+    if test.budget.as_ref().is_some_and(|b| b.skip_in_ci) {
+        writeln!(writer, "    # Performance tests are skipped in CI")?;
         writeln!(
             writer,
-            "    # Create temporary directory for test artifacts"
+            "    if [ -n \"${{CI:-}}\" ] && [ \"$CI\" != \"false\" ] && [ \"$CI\" != \"0\" ]; then"
         )?;
-        writeln!(writer, "    TEST_TEMP_DIR=\"$(mktemp -d)\"")?;
-        writeln!(writer, "    export TEST_TEMP_DIR")?;
-        writeln!(writer)?;
-        writeln!(writer, "    # Set secure umask")?;
-        writeln!(writer, "    umask 077")?;
-        writeln!(writer, "}}")?;
-        writeln!(writer)?;
-
-        Ok(())
-    }
-
-    /// Write teardown function
-    fn write_teardown(&self, writer: &mut BufWriter<File>) -> Result<()> {
-        writeln!(writer, "# Teardown function (runs after each test)")?;
-        writeln!(writer, "teardown() {{")?;
-        writeln!(writer, "    # Cleanup temporary directory")?;
         writeln!(
             writer,
-            "    if [[ -n \"${{TEST_TEMP_DIR:-}}\" ]] && [[ -d \"$TEST_TEMP_DIR\" ]]; then"
+            "        skip \"performance tests are skipped in CI\""
         )?;
-        writeln!(writer, "        rm -rf \"$TEST_TEMP_DIR\"")?;
         writeln!(writer, "    fi")?;
-        writeln!(writer, "}}")?;
         writeln!(writer)?;
+    }
 
-        Ok(())
+    // Write command execution
+    match test.timeout {
+        Some(seconds) => write_timed_command(writer, &test.command, seconds)?,
+        None => {
+            writeln!(writer, "    # Execute command")?;
+            writeln!(writer, "    run {}", test.command)?;
+        }
+    }
+    writeln!(writer)?;
+
+    // Write exit code assertion
+    writeln!(writer, "    # Assert exit code")?;
+    match (test.allowed_exit_codes(), test.expected_exit) {
+        (Some(codes), _) => {
+            let codes: Vec<String> = codes.iter().map(|code| code.to_string()).collect();
+            writeln!(
+                writer,
+                "    [[ \" {} \" == *\" $status \"* ]]",
+                codes.join(" ")
+            )?
+        }
+        (None, _) if !test.checks_expected_exit() => {
+            if test
+                .assertions
+                .iter()
+                .any(|assertion| matches!(assertion, Assertion::ValueNotRejected { .. }))
+            {
+                writeln!(
+                    writer,
+                    "    # Only a rejection of the value fails, see below"
+                )?
+            } else {
+                writeln!(writer, "    # Compared with the reference command below")?
+            }
+        }
+        (None, Some(code)) => writeln!(writer, "    [ \"$status\" -eq {} ]", code)?,
+        (None, None) => writeln!(writer, "    [ \"$status\" -ne 0 ]")?,
     }
 
-    /// Write a single test case
-    fn write_test_case(&self, writer: &mut BufWriter<File>, test: &TestCase) -> Result<()> {
-        // Write test annotation
-        writeln!(
-            writer,
-            "@test \"[{}] {}\" {{",
-            test.category.as_str(),
-            test.name
-        )?;
+    // Write additional assertions
+    if !test.assertions.is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "    # Additional assertions")?;
 
-        // Write test description comment
-        writeln!(writer, "    # Test ID: {}", test.id)?;
-        if !test.tags.is_empty() {
-            writeln!(writer, "    # Tags: {}", test.tags.join(", "))?;
+        for assertion in &test.assertions {
+            write_assertion(writer, assertion)?;
         }
-        writeln!(writer)?;
+    }
 
-        // Write command execution
-        writeln!(writer, "    # Execute command")?;
-        writeln!(writer, "    run {}", test.command)?;
+    // The run above doubles as the warm-up for performance budgets
+    if let Some(budget) = &test.budget {
         writeln!(writer)?;
+        write_benchmark(writer, &test.command, budget)?;
+    }
 
-        // Write exit code assertion
-        writeln!(writer, "    # Assert exit code")?;
-        match test.expected_exit {
-            Some(code) => writeln!(writer, "    [ \"$status\" -eq {} ]", code)?,
-            None => writeln!(writer, "    [ \"$status\" -ne 0 ]")?,
-        }
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
 
-        // Write additional assertions
-        if !test.assertions.is_empty() {
-            writeln!(writer)?;
-            writeln!(writer, "    # Additional assertions")?;
+    Ok(())
+}
 
-            for assertion in &test.assertions {
-                self.write_assertion(writer, assertion)?;
-            }
-        }
+/// Write a command wrapped in `run_with_timeout` for tests with a per-test timeout override
+///
+/// Exit status 124 means the timeout fired; the test fails with a
+/// "timeout after Ns" diagnostic that `BatsExecutor` reports as `TestStatus::Timeout`.
+fn write_timed_command<W: Write>(writer: &mut W, command: &str, seconds: u64) -> Result<()> {
+    writeln!(writer, "    # Execute command (timeout: {}s)", seconds)?;
+    writeln!(
+        writer,
+        "    run run_with_timeout {} bash -c '{}'",
+        seconds,
+        command.replace('\'', "'\\''")
+    )?;
+    writeln!(writer, "    if [ \"$status\" -eq 124 ]; then")?;
+    writeln!(writer, "        echo \"$output\"")?;
+    writeln!(writer, "        echo \"timeout after {}s\"", seconds)?;
+    writeln!(writer, "        return 1")?;
+    writeln!(writer, "    fi")?;
+
+    Ok(())
+}
 
-        writeln!(writer, "}}")?;
+/// Write a benchmark loop that enforces a performance budget
+///
+/// Wall time is measured with `$EPOCHREALTIME` (bash 5+) and peak RSS with GNU
+/// `/usr/bin/time` when available. The statistics are written to FD 3 as a
+/// `# perf: ...` TAP diagnostic (including every sample) that `BatsExecutor`
+/// stores in the report.
+fn write_benchmark<W: Write>(
+    writer: &mut W,
+    command: &str,
+    budget: &PerformanceBudget,
+) -> Result<()> {
+    let iterations = budget.iterations.max(1);
+
+    writeln!(writer, "    # Benchmark: {} runs", iterations)?;
+    writeln!(
+        writer,
+        "    [ -n \"${{EPOCHREALTIME:-}}\" ] || skip \"bash 5+ is required for timing\""
+    )?;
+    writeln!(writer, "    local samples=() start end i")?;
+    writeln!(writer, "    for ((i = 0; i < {}; i++)); do", iterations)?;
+    writeln!(writer, "        start=${{EPOCHREALTIME//[.,]/}}")?;
+    writeln!(writer, "        {} >/dev/null 2>&1 || true", command)?;
+    writeln!(writer, "        end=${{EPOCHREALTIME//[.,]/}}")?;
+    writeln!(writer, "        samples+=($((end - start)))")?;
+    writeln!(writer, "    done")?;
+    writeln!(
+        writer,
+        "    local sorted=($(printf '%s\\n' \"${{samples[@]}}\" | sort -n))"
+    )?;
+    writeln!(writer, "    local total=0 sample")?;
+    writeln!(
+        writer,
+        "    for sample in \"${{samples[@]}}\"; do total=$((total + sample)); done"
+    )?;
+    writeln!(writer, "    local mean=$((total / {}))", iterations)?;
+    writeln!(
+        writer,
+        "    local p95=${{sorted[{}]}}",
+        (iterations as usize * 95).div_ceil(100).max(1) - 1
+    )?;
+    writeln!(writer, "    local max=${{sorted[{}]}}", iterations - 1)?;
+    writeln!(writer)?;
+    writeln!(writer, "    # Peak RSS (GNU time only)")?;
+    writeln!(writer, "    local rss_kb=\"\"")?;
+    writeln!(
+        writer,
+        "    if /usr/bin/time -f %M -o \"$TEST_TEMP_DIR/rss\" true 2>/dev/null; then"
+    )?;
+    writeln!(
+        writer,
+        "        /usr/bin/time -f %M -o \"$TEST_TEMP_DIR/rss\" bash -c '{}' >/dev/null 2>&1 || true",
+        command.replace('\'', "'\\''")
+    )?;
+    writeln!(writer, "        rss_kb=$(tail -n 1 \"$TEST_TEMP_DIR/rss\")")?;
+    writeln!(writer, "    fi")?;
+    writeln!(writer)?;
+    writeln!(writer, "    local samples_ms=\"\"")?;
+    writeln!(writer, "    for sample in \"${{samples[@]}}\"; do")?;
+    writeln!(
+        writer,
+        "        samples_ms+=\"${{samples_ms:+,}}$(printf '%d.%03d' $((sample / 1000)) $((sample % 1000)))\""
+    )?;
+    writeln!(writer, "    done")?;
+    writeln!(
+        writer,
+        "    printf '# perf: iterations={} mean_ms=%d.%03d p95_ms=%d.%03d max_ms=%d.%03d%s samples_ms=%s\\n' \\",
+        iterations
+    )?;
+    writeln!(
+        writer,
+        "        $((mean / 1000)) $((mean % 1000)) $((p95 / 1000)) $((p95 % 1000)) \\"
+    )?;
+    writeln!(
+        writer,
+        "        $((max / 1000)) $((max % 1000)) \"${{rss_kb:+ max_rss_kb=$rss_kb}}\" \"$samples_ms\" >&3"
+    )?;
+
+    if let Some(max_ms) = budget.max_startup_ms {
         writeln!(writer)?;
+        writeln!(writer, "    if [ \"$p95\" -gt {} ]; then", max_ms * 1000)?;
+        writeln!(
+            writer,
+            "        echo \"p95 startup time $((p95 / 1000))ms exceeds budget of {}ms\"",
+            max_ms
+        )?;
+        writeln!(writer, "        return 1")?;
+        writeln!(writer, "    fi")?;
+    }
 
-        Ok(())
+    if let Some(max_mb) = budget.max_memory_mb {
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "    if [ -n \"$rss_kb\" ] && [ \"$rss_kb\" -gt {} ]; then",
+            max_mb * 1024
+        )?;
+        writeln!(
+            writer,
+            "        echo \"peak RSS $((rss_kb / 1024))MB exceeds budget of {}MB\"",
+            max_mb
+        )?;
+        writeln!(writer, "        return 1")?;
+        writeln!(writer, "    fi")?;
     }
 
-    /// Write an assertion
-    fn write_assertion(&self, writer: &mut BufWriter<File>, assertion: &Assertion) -> Result<()> {
-        match assertion {
-            Assertion::ExitCode(code) => {
-                writeln!(writer, "    [ \"$status\" -eq {} ]", code)?;
-            }
-            Assertion::OutputContains(text) => {
-                // Special case for "Usage:" - support both uppercase and lowercase
-                // Python argparse uses "usage:" (lowercase)
-                // Most other CLIs use "Usage:" (uppercase)
-                if text == "Usage:" {
-                    writeln!(
-                        writer,
-                        "    [[ \"$output\" =~ \"Usage:\" ]] || [[ \"$output\" =~ \"usage:\" ]] || [[ \"$stderr\" =~ \"Usage:\" ]] || [[ \"$stderr\" =~ \"usage:\" ]]"
-                    )?;
-                } else {
-                    writeln!(
-                        writer,
-                        "    [[ \"$output\" =~ \"{}\" ]] || [[ \"$stderr\" =~ \"{}\" ]]",
-                        escape_regex(text),
-                        escape_regex(text)
-                    )?;
-                }
-            }
-            Assertion::OutputMatches(pattern) => {
+    Ok(())
+}
+
+/// Write an assertion
+fn write_assertion<W: Write>(writer: &mut W, assertion: &Assertion) -> Result<()> {
+    match assertion {
+        Assertion::ExitCode(code) => {
+            writeln!(writer, "    [ \"$status\" -eq {} ]", code)?;
+        }
+        Assertion::OutputContains(text) => {
+            // Special case for "Usage:" - support both uppercase and lowercase
+            // Python argparse uses "usage:" (lowercase)
+            // Most other CLIs use "Usage:" (uppercase)
+            if text == "Usage:" {
                 writeln!(
                     writer,
-                    "    [[ \"$output\" =~ {} ]] || [[ \"$stderr\" =~ {} ]]",
-                    pattern, pattern
+                    "    [[ \"$output\" =~ \"Usage:\" ]] || [[ \"$output\" =~ \"usage:\" ]] || [[ \"$stderr\" =~ \"Usage:\" ]] || [[ \"$stderr\" =~ \"usage:\" ]]"
                 )?;
-            }
-            Assertion::OutputNotContains(text) => {
+            } else {
                 writeln!(
                     writer,
-                    "    ! [[ \"$output\" =~ \"{}\" ]] && ! [[ \"$stderr\" =~ \"{}\" ]]",
+                    "    [[ \"$output\" =~ \"{}\" ]] || [[ \"$stderr\" =~ \"{}\" ]]",
                     escape_regex(text),
                     escape_regex(text)
                 )?;
             }
-            Assertion::FileExists(path) => {
-                writeln!(writer, "    [ -f \"{}\" ]", path.display())?;
-            }
-            Assertion::FileNotExists(path) => {
-                writeln!(writer, "    [ ! -f \"{}\" ]", path.display())?;
-            }
         }
-
-        Ok(())
-    }
-
-    /// Validate generated BATS file syntax
-    pub fn validate_bats_file(&self, file_path: &Path) -> Result<()> {
-        // Check if file exists
-        if !file_path.exists() {
-            return Err(Error::Validation(format!(
-                "BATS file does not exist: {}",
-                file_path.display()
-            )));
+        Assertion::OutputMatches(pattern) => {
+            writeln!(
+                writer,
+                "    [[ \"$output\" =~ {} ]] || [[ \"$stderr\" =~ {} ]]",
+                pattern, pattern
+            )?;
         }
-
-        // Read file content
-        let content = fs::read_to_string(file_path)
-            .map_err(|e| Error::Config(format!("Failed to read BATS file: {}", e)))?;
-
-        // Basic validation checks
-        if !content.starts_with("#!/usr/bin/env bats") {
-            return Err(Error::Validation(
-                "BATS file missing shebang line".to_string(),
-            ));
+        Assertion::OutputNotContains(text) => {
+            writeln!(
+                writer,
+                "    ! [[ \"$output\" =~ \"{}\" ]] && ! [[ \"$stderr\" =~ \"{}\" ]]",
+                escape_regex(text),
+                escape_regex(text)
+            )?;
         }
-
-        // Check for at least one @test block
-        if !content.contains("@test") {
-            return Err(Error::Validation(
-                "BATS file contains no test cases".to_string(),
-            ));
+        Assertion::FileExists(path) => {
+            writeln!(writer, "    [ -f \"{}\" ]", path.display())?;
         }
-
-        // Check for balanced braces (simple check)
-        let open_braces = content.matches('{').count();
-        let close_braces = content.matches('}').count();
-
-        if open_braces != close_braces {
-            return Err(Error::Validation(format!(
-                "Unbalanced braces: {} open, {} close",
-                open_braces, close_braces
-            )));
+        Assertion::FileNotExists(path) => {
+            writeln!(writer, "    [ ! -f \"{}\" ]", path.display())?;
+        }
+        // Written in place of the expected_exit check
+        Assertion::ExitCodeIn(_) => {}
+        Assertion::SameOutcomeAs(command) => {
+            // Re-runs `run`, so $status and $output refer to the reference afterwards
+            writeln!(
+                writer,
+                "    local actual_status=\"$status\" actual_output=\"$output\""
+            )?;
+            writeln!(writer, "    run {}", command)?;
+            writeln!(writer, "    [ \"$status\" -eq \"$actual_status\" ]")?;
+            writeln!(writer, "    [ \"$output\" = \"$actual_output\" ]")?;
+        }
+        Assertion::ValueNotRejected { option, value } => {
+            // `run` merges stderr into $output; a rejection names option and value on one line
+            let (option_arg, value_arg) = (
+                ShellDialect::Bash.quote(option),
+                ShellDialect::Bash.quote(value),
+            );
+            writeln!(
+                writer,
+                "    [ \"$status\" -lt 128 ] || {{ echo \"terminated by a signal\"; return 1; }}"
+            )?;
+            writeln!(
+                writer,
+                "    if [ \"$status\" -ne 0 ] && grep -F -- {} <<< \"$output\" | grep -qF -- {}; then",
+                option_arg, value_arg
+            )?;
+            writeln!(
+                writer,
+                "        echo {}",
+                ShellDialect::Bash.quote(&format!("value '{}' for {} was rejected", value, option))
+            )?;
+            writeln!(writer, "        return 1")?;
+            writeln!(writer, "    fi")?;
         }
-
-        log::debug!("BATS file validation passed: {}", file_path.display());
-        Ok(())
     }
+
+    Ok(())
 }
 
 /// Escape special regex characters for bash pattern matching
@@ -532,4 +834,159 @@ mod tests {
         assert!(content.contains("test-cli --help"));
         assert!(content.contains("[ \"$status\" -eq 0 ]"));
     }
+
+    #[test]
+    fn test_timeout_override_wraps_command() {
+        let test = TestCase::new(
+            "performance-001".to_string(),
+            "Startup time".to_string(),
+            TestCategory::Performance,
+            "\"$CLI_BINARY\" --name 'x'".to_string(),
+        )
+        .with_timeout(7);
+
+        let content = render_bats_file(
+            "test-cli",
+            Path::new("/usr/bin/test-cli"),
+            TestCategory::Performance,
+            &[&test],
+        )
+        .unwrap();
+
+        assert!(
+            content.contains(r#"run run_with_timeout 7 bash -c '"$CLI_BINARY" --name '\''x'\'''"#)
+        );
+        assert!(content.contains("echo \"timeout after 7s\""));
+        assert_eq!(content.matches("run_with_timeout() {").count(), 1);
+        assert!(content.contains("gtimeout --kill-after=5"));
+        assert!(validate_bats_content(&content).is_ok());
+
+        // The helper is only written when a test needs it
+        let untimed = TestCase::new(
+            "basic-001".to_string(),
+            "Help".to_string(),
+            TestCategory::Basic,
+            "\"$CLI_BINARY\" --help".to_string(),
+        );
+        let content = render_bats_file(
+            "test-cli",
+            Path::new("/usr/bin/test-cli"),
+            TestCategory::Basic,
+            &[&untimed],
+        )
+        .unwrap();
+        assert!(!content.contains("run_with_timeout"));
+    }
+
+    #[test]
+    fn test_missing_required_program_skips() {
+        let test = TestCase::new(
+            "multi-shell-fish".to_string(),
+            "Run --help in fish".to_string(),
+            TestCategory::MultiShell,
+            "fish -c '$CLI_BINARY --help'".to_string(),
+        )
+        .with_requirement("fish".to_string());
+
+        let content = render_bats_file(
+            "test-cli",
+            Path::new("/usr/bin/test-cli"),
+            TestCategory::MultiShell,
+            &[&test],
+        )
+        .unwrap();
+
+        assert!(content
+            .contains("    command -v fish >/dev/null 2>&1 || skip \"fish is not installed\"\n"));
+        assert!(content.contains("    run fish -c '$CLI_BINARY --help'"));
+        assert!(validate_bats_content(&content).is_ok());
+    }
+
+    #[test]
+    fn test_same_outcome_assertion() {
+        let test = TestCase::new(
+            "env-001-flag".to_string(),
+            "NO_COLOR has the same effect as --no-color".to_string(),
+            TestCategory::Environment,
+            "env NO_COLOR=1 \"$CLI_BINARY\"".to_string(),
+        )
+        .with_assertion(Assertion::SameOutcomeAs(
+            "\"$CLI_BINARY\" --no-color".to_string(),
+        ));
+
+        let content = render_bats_file(
+            "test-cli",
+            Path::new("/usr/bin/test-cli"),
+            TestCategory::Environment,
+            &[&test],
+        )
+        .unwrap();
+
+        assert!(content.contains(
+            "    run env NO_COLOR=1 \"$CLI_BINARY\"\n\n    # Assert exit code\n    # Compared with the reference command below\n\n    # Additional assertions\n    local actual_status=\"$status\" actual_output=\"$output\"\n    run \"$CLI_BINARY\" --no-color\n    [ \"$status\" -eq \"$actual_status\" ]\n    [ \"$output\" = \"$actual_output\" ]\n"
+        ));
+        assert!(validate_bats_content(&content).is_ok());
+    }
+
+    #[test]
+    fn test_value_not_rejected_assertion() {
+        let test = TestCase::new(
+            "input-run-jobs-valid".to_string(),
+            "Accept valid numeric value for --jobs in subcommand run".to_string(),
+            TestCategory::InputValidation,
+            "\"$CLI_BINARY\" run --jobs 10".to_string(),
+        )
+        .with_assertion(Assertion::ValueNotRejected {
+            option: "--jobs".to_string(),
+            value: "10".to_string(),
+        });
+
+        let content = render_bats_file(
+            "test-cli",
+            Path::new("/usr/bin/test-cli"),
+            TestCategory::InputValidation,
+            &[&test],
+        )
+        .unwrap();
+
+        assert!(content.contains("    # Only a rejection of the value fails, see below\n"));
+        assert!(content.contains(
+            "    if [ \"$status\" -ne 0 ] && grep -F -- --jobs <<< \"$output\" | grep -qF -- 10; then\n        echo 'value '\\''10'\\'' for --jobs was rejected'\n        return 1\n    fi\n"
+        ));
+        assert!(!content.contains("[ \"$status\" -eq 0 ]"));
+        assert!(validate_bats_content(&content).is_ok());
+    }
+
+    #[test]
+    fn test_performance_budget_benchmark() {
+        let test = TestCase::new(
+            "perf-001".to_string(),
+            "Startup time for --help < 100ms".to_string(),
+            TestCategory::Performance,
+            "\"$CLI_BINARY\" --help".to_string(),
+        )
+        .with_budget(PerformanceBudget {
+            iterations: 20,
+            max_startup_ms: Some(100),
+            max_memory_mb: Some(64),
+            skip_in_ci: true,
+        });
+
+        let content = render_bats_file(
+            "test-cli",
+            Path::new("/usr/bin/test-cli"),
+            TestCategory::Performance,
+            &[&test],
+        )
+        .unwrap();
+
+        assert!(content.contains("skip \"performance tests are skipped in CI\""));
+        assert!(content.contains("    for ((i = 0; i < 20; i++)); do"));
+        assert!(content.contains("        \"$CLI_BINARY\" --help >/dev/null 2>&1 || true"));
+        assert!(content.contains("    local p95=${sorted[18]}"));
+        assert!(content.contains("    if [ \"$p95\" -gt 100000 ]; then"));
+        assert!(content.contains("[ \"$rss_kb\" -gt 65536 ]"));
+        assert!(content.contains("# perf: iterations=20"));
+        assert!(validate_bats_content(&content).is_ok());
+    }
 }
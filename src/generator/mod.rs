@@ -14,7 +14,10 @@
 //! - **DestructiveOps**: Operations requiring confirmation
 //! - **DirectoryTraversal**: Path traversal prevention
 //! - **Performance**: Response time validation
-//! - **MultiShell**: Cross-shell compatibility (bash, zsh, fish)
+//! - **MultiShell**: Cross-shell compatibility (bash, zsh, sh, dash, ksh, fish, busybox)
+//! - **Environment**: Environment variables behave like their flags
+//! - **OptionRelations**: Conflicting options are rejected, required options are enforced
+//! - **Combinatorial**: Pairwise option combinations do not crash (opt-in)
 //!
 //! ## Example Usage
 //!
@@ -52,7 +55,10 @@
 //! Templates are validated at compile time for correctness.
 
 pub mod assert_cmd_generator;
+pub mod bats_generator;
 pub mod bats_writer;
+pub mod shells;
+pub mod snapbox_generator;
 pub mod templates;
 pub mod test_generator;
 pub mod test_generator_trait;
@@ -60,9 +66,14 @@ mod test_level_parallel;
 
 // Re-export commonly used types
 pub use assert_cmd_generator::AssertCmdGenerator;
+pub use bats_generator::BatsGenerator;
 pub use bats_writer::BatsWriter;
+pub use snapbox_generator::SnapboxGenerator;
 pub use templates::TemplateEngine;
 pub use test_generator::TestGenerator;
 pub use test_generator_trait::TestGenerator as TestGeneratorTrait;
+pub use test_generator_trait::{
+    create_generator, register_generator, registered_generators, GeneratedTests, GeneratorFactory,
+};
 // test_level_parallel module contains helper functions for future use
 // Currently used directly in test_generator.rs via rayon::par_iter()
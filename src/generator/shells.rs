@@ -0,0 +1,184 @@
+//! Shell dialects for multi-shell compatibility tests
+//!
+//! Generated commands are executed by bash (BATS) or the native runner, so each
+//! multi-shell test is written as `env NAME='value' <shell> -c '<script>'`. The
+//! inner script is quoted for the target shell; the outer layer uses POSIX quoting.
+
+use std::collections::BTreeMap;
+
+/// Shells supported by the multi-shell test category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellDialect {
+    Bash,
+    Zsh,
+    Sh,
+    Dash,
+    Ksh,
+    /// MirBSD Korn shell, installed as `mksh` next to (or instead of) `ksh`
+    Mksh,
+    Fish,
+    /// BusyBox `sh` (ash), the default `/bin/sh` on Alpine
+    Busybox,
+}
+
+impl ShellDialect {
+    /// Parse a shell name from `test_adjustments.multi_shell.shells`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "bash" => Some(Self::Bash),
+            "zsh" => Some(Self::Zsh),
+            "sh" => Some(Self::Sh),
+            "dash" => Some(Self::Dash),
+            "ksh" => Some(Self::Ksh),
+            "mksh" => Some(Self::Mksh),
+            "fish" => Some(Self::Fish),
+            "busybox" | "busybox-sh" | "ash" => Some(Self::Busybox),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Zsh => "zsh",
+            Self::Sh => "sh",
+            Self::Dash => "dash",
+            Self::Ksh => "ksh",
+            Self::Mksh => "mksh",
+            Self::Fish => "fish",
+            Self::Busybox => "busybox",
+        }
+    }
+
+    /// Program that must be installed for this shell's tests to run
+    pub fn program(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Command prefix that runs a script string in this shell
+    fn invocation(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash -c",
+            Self::Zsh => "zsh -c",
+            Self::Sh => "sh -c",
+            Self::Dash => "dash -c",
+            Self::Ksh => "ksh -c",
+            Self::Mksh => "mksh -c",
+            Self::Fish => "fish -c",
+            Self::Busybox => "busybox sh -c",
+        }
+    }
+
+    /// Reference to an environment variable as a single argument
+    ///
+    /// fish never word-splits variables, so quoting is unnecessary there.
+    fn variable(&self, name: &str) -> String {
+        match self {
+            Self::Fish => format!("${}", name),
+            _ => format!("\"${}\"", name),
+        }
+    }
+
+    /// Quote a literal argument for this shell
+    ///
+    /// POSIX shells cannot escape inside single quotes (`'\''` closes and reopens
+    /// the string); fish allows `\'` and `\\` inside single quotes instead.
+    pub fn quote(&self, arg: &str) -> String {
+        if !arg.is_empty() && arg.chars().all(is_safe_char) {
+            return arg.to_string();
+        }
+
+        match self {
+            Self::Fish => format!("'{}'", arg.replace('\\', "\\\\").replace('\'', "\\'")),
+            _ => posix_quote(arg),
+        }
+    }
+
+    /// Build a command that runs `$CLI_BINARY` with `args` inside this shell
+    ///
+    /// `env` is injected with `env NAME='value'` so it works both under BATS `run`
+    /// and the native runner. `CLI_BINARY` is read from the environment by the
+    /// inner shell rather than expanded by the outer one.
+    pub fn command(&self, args: &[&str], env: &BTreeMap<String, String>) -> String {
+        let mut script = self.variable("CLI_BINARY");
+        for arg in args {
+            script.push(' ');
+            script.push_str(&self.quote(arg));
+        }
+
+        let mut command = String::new();
+        if !env.is_empty() {
+            command.push_str("env ");
+            for (name, value) in env {
+                command.push_str(&format!("{}={} ", name, posix_quote(value)));
+            }
+        }
+        command.push_str(&format!("{} {}", self.invocation(), posix_quote(&script)));
+        command
+    }
+}
+
+fn is_safe_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c)
+}
+
+/// Single-quote a string for POSIX shells
+fn posix_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(ShellDialect::from_name("bash"), Some(ShellDialect::Bash));
+        assert_eq!(ShellDialect::from_name("Fish"), Some(ShellDialect::Fish));
+        assert_eq!(
+            ShellDialect::from_name("busybox"),
+            Some(ShellDialect::Busybox)
+        );
+        assert_eq!(ShellDialect::from_name("mksh"), Some(ShellDialect::Mksh));
+        assert_eq!(ShellDialect::Mksh.program(), "mksh");
+        assert_eq!(ShellDialect::from_name("tcsh"), None);
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(ShellDialect::Bash.quote("--help"), "--help");
+        assert_eq!(ShellDialect::Dash.quote("it's"), "'it'\\''s'");
+        assert_eq!(ShellDialect::Fish.quote("it's"), "'it\\'s'");
+        assert_eq!(ShellDialect::Fish.quote("a\\b c"), "'a\\\\b c'");
+        assert_eq!(ShellDialect::Ksh.quote(""), "''");
+    }
+
+    #[test]
+    fn test_command() {
+        let env = BTreeMap::new();
+        assert_eq!(
+            ShellDialect::Bash.command(&["--help"], &env),
+            "bash -c '\"$CLI_BINARY\" --help'"
+        );
+        assert_eq!(
+            ShellDialect::Fish.command(&["--help"], &env),
+            "fish -c '$CLI_BINARY --help'"
+        );
+        assert_eq!(
+            ShellDialect::Busybox.command(&["--help"], &env),
+            "busybox sh -c '\"$CLI_BINARY\" --help'"
+        );
+    }
+
+    #[test]
+    fn test_command_with_env() {
+        let mut env = BTreeMap::new();
+        env.insert("SHELL_NAME".to_string(), "it's zsh".to_string());
+        env.insert("A".to_string(), "1".to_string());
+
+        assert_eq!(
+            ShellDialect::Zsh.command(&["say hi"], &env),
+            "env A='1' SHELL_NAME='it'\\''s zsh' zsh -c '\"$CLI_BINARY\" '\\''say hi'\\'''"
+        );
+    }
+}
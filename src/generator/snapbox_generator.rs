@@ -0,0 +1,445 @@
+use crate::error::Result;
+use crate::generator::assert_cmd_generator::AssertCmdGenerator;
+use crate::generator::test_generator::allowed_exit_codes;
+use crate::generator::test_generator_trait::TestGenerator as TestGeneratorTrait;
+use crate::types::analysis::CliAnalysis;
+use crate::types::test_case::TestCategory;
+use handlebars::Handlebars;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::json;
+
+lazy_static! {
+    /// Version-like tokens (e.g. "1.2.3", "v0.10.0-beta.1")
+    static ref VERSION_TOKEN: Regex =
+        Regex::new(r"\bv?\d+\.\d+(?:\.\d+)?(?:[-+][0-9A-Za-z.-]+)?\b").unwrap();
+
+    /// Absolute filesystem paths (e.g. "/usr/local/bin/tool", "/home/user/.config")
+    static ref ABSOLUTE_PATH: Regex = Regex::new(r"(?:^|[\s=(\[])(/[^\s\])]+)").unwrap();
+}
+
+/// Generator for snapbox-based Rust snapshot tests
+///
+/// Generates Rust tests using `snapbox::cmd::Command` that compare stdout/stderr
+/// against inline snapshots. Volatile content (versions, absolute paths) is
+/// redacted with snapbox's `[..]` wildcard so snapshots remain stable.
+///
+/// Snapshots are refreshed with `SNAPSHOTS=overwrite cargo test`.
+///
+/// # Example Output
+///
+/// ```rust,ignore
+/// use snapbox::cmd::Command;
+/// use snapbox::str;
+///
+/// #[test]
+/// fn test_help_option() {
+///     Command::new(snapbox::cmd::cargo_bin!("my-cli"))
+///         .arg("--help")
+///         .assert()
+///         .success()
+///         .stdout_eq(str![[r#"
+/// Usage: my-cli [..]
+/// "#]]);
+/// }
+/// ```
+pub struct SnapboxGenerator {
+    handlebars: Handlebars<'static>,
+}
+
+impl SnapboxGenerator {
+    /// Create a new SnapboxGenerator
+    ///
+    /// # Returns
+    ///
+    /// New SnapboxGenerator instance
+    pub fn new() -> Result<Self> {
+        let mut handlebars = Handlebars::new();
+
+        // Register templates
+        Self::register_templates(&mut handlebars)?;
+
+        // Configure Handlebars
+        handlebars.set_strict_mode(true);
+
+        // Snapshot content is emitted inside raw string literals, so HTML escaping
+        // must be disabled. All values are sanitized explicitly before rendering.
+        handlebars.register_escape_fn(handlebars::no_escape);
+
+        Ok(Self { handlebars })
+    }
+
+    /// Register all test templates
+    fn register_templates(handlebars: &mut Handlebars) -> Result<()> {
+        // Basic tests template
+        handlebars
+            .register_template_string("basic", include_str!("../templates/snapbox/basic.hbs"))?;
+
+        // Security tests template
+        handlebars.register_template_string(
+            "security",
+            include_str!("../templates/snapbox/security.hbs"),
+        )?;
+
+        // Help tests template
+        handlebars
+            .register_template_string("help", include_str!("../templates/snapbox/help.hbs"))?;
+
+        // Path tests template
+        handlebars
+            .register_template_string("path", include_str!("../templates/snapbox/path.hbs"))?;
+
+        // InputValidation tests template
+        handlebars.register_template_string(
+            "input_validation",
+            include_str!("../templates/snapbox/input_validation.hbs"),
+        )?;
+
+        // DestructiveOps tests template
+        handlebars.register_template_string(
+            "destructive_ops",
+            include_str!("../templates/snapbox/destructive_ops.hbs"),
+        )?;
+
+        // Performance tests template
+        handlebars.register_template_string(
+            "performance",
+            include_str!("../templates/snapbox/performance.hbs"),
+        )?;
+
+        // MultiShell tests template
+        handlebars.register_template_string(
+            "multi_shell",
+            include_str!("../templates/snapbox/multi_shell.hbs"),
+        )?;
+
+        // Environment tests template
+        handlebars.register_template_string(
+            "environment",
+            include_str!("../templates/snapbox/environment.hbs"),
+        )?;
+
+        // Option relation tests template
+        handlebars.register_template_string(
+            "option_relations",
+            include_str!("../templates/snapbox/option_relations.hbs"),
+        )?;
+
+        // Pairwise combination tests template
+        handlebars.register_template_string(
+            "combinatorial",
+            include_str!("../templates/snapbox/combinatorial.hbs"),
+        )?;
+
+        Ok(())
+    }
+
+    /// Redact volatile content from captured output
+    ///
+    /// Replaces the binary path, the detected version, version-like tokens and
+    /// absolute paths with snapbox's `[..]` wildcard.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - Raw captured output
+    /// * `analysis` - CLI analysis (provides binary path and version)
+    ///
+    /// # Returns
+    ///
+    /// Redacted output suitable for an inline snapshot
+    pub fn redact(output: &str, analysis: &CliAnalysis) -> String {
+        let mut redacted = output.replace("\r\n", "\n");
+
+        let binary_path = analysis.binary_path.to_string_lossy();
+        if !binary_path.is_empty() {
+            redacted = redacted.replace(binary_path.as_ref(), "[..]");
+        }
+
+        if let Some(version) = analysis.version.as_deref().filter(|v| !v.is_empty()) {
+            redacted = redacted.replace(version, "[..]");
+        }
+
+        redacted = VERSION_TOKEN.replace_all(&redacted, "[..]").into_owned();
+
+        redacted = ABSOLUTE_PATH
+            .replace_all(&redacted, |caps: &regex::Captures| {
+                let whole = caps.get(0).unwrap().as_str();
+                let path = caps.get(1).unwrap().as_str();
+                format!("{}[..]", &whole[..whole.len() - path.len()])
+            })
+            .into_owned();
+
+        redacted
+    }
+
+    /// Render a string as a Rust raw string literal for `str![[...]]`
+    ///
+    /// Picks enough `#` delimiters so that the content cannot terminate the literal early.
+    pub fn to_raw_string_literal(content: &str) -> String {
+        let mut hashes = 1;
+        while content.contains(&format!("\"{}", "#".repeat(hashes))) {
+            hashes += 1;
+        }
+        let delimiter = "#".repeat(hashes);
+
+        let mut body = content.to_string();
+        if !body.ends_with('\n') {
+            body.push('\n');
+        }
+
+        // snapbox strips the leading newline of inline snapshots
+        format!("[r{d}\"\n{body}\"{d}]", d = delimiter, body = body)
+    }
+
+    /// Convert a subcommand name into a valid Rust identifier fragment
+    fn to_ident(name: &str) -> String {
+        name.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
+
+impl TestGeneratorTrait for SnapboxGenerator {
+    fn generate(&self, analysis: &CliAnalysis, category: TestCategory) -> Result<String> {
+        let template_name = match category {
+            TestCategory::Basic => "basic",
+            TestCategory::Security => "security",
+            TestCategory::Help => "help",
+            TestCategory::Path => "path",
+            TestCategory::InputValidation => "input_validation",
+            TestCategory::DestructiveOps => "destructive_ops",
+            TestCategory::DirectoryTraversal => "security", // Reuse security template
+            TestCategory::Performance => "performance",
+            TestCategory::MultiShell => "multi_shell",
+            TestCategory::Environment => "environment",
+            TestCategory::OptionRelations => "option_relations",
+            TestCategory::Combinatorial => "combinatorial",
+        };
+
+        let help_snapshot =
+            Self::to_raw_string_literal(&Self::redact(&analysis.help_output, analysis));
+
+        // Prepare template data
+        let data = json!({
+            "cli_name": AssertCmdGenerator::sanitize_for_rust_string(&analysis.binary_name),
+            "version": analysis.version.as_ref().map(|v| AssertCmdGenerator::sanitize_for_rust_string(v)),
+            "help_snapshot": help_snapshot,
+            "subcommands": analysis.subcommands.iter().map(|sc| {
+                json!({
+                    "name": AssertCmdGenerator::sanitize_for_rust_string(&sc.name),
+                    "ident": Self::to_ident(&sc.name),
+                    "description": sc.description.as_ref().map(|d| AssertCmdGenerator::sanitize_for_rust_string(d)),
+                })
+            }).collect::<Vec<_>>(),
+            "environment_variables": AssertCmdGenerator::environment_data(analysis),
+            "positional_tests": AssertCmdGenerator::positional_data(analysis),
+            "boundary_tests": AssertCmdGenerator::boundary_data(analysis),
+            "enum_tests": AssertCmdGenerator::enum_data(analysis),
+            "parser_checks": AssertCmdGenerator::has_parser_checks(analysis),
+            "relation_tests": AssertCmdGenerator::relation_data(analysis),
+            "combination_tests": AssertCmdGenerator::combination_data(analysis),
+            "allowed_exit_codes": allowed_exit_codes(analysis),
+        });
+
+        // Render template
+        let test_code = self.handlebars.render(template_name, &data)?;
+
+        Ok(test_code)
+    }
+
+    fn file_extension(&self) -> &str {
+        "rs"
+    }
+
+    fn name(&self) -> &str {
+        "snapbox"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::analysis::{CliOption, OptionSource, OptionType, PositionalArg, Subcommand};
+    use std::path::PathBuf;
+
+    fn create_test_analysis() -> CliAnalysis {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/local/bin/test-cli"),
+            "test-cli".to_string(),
+            "test-cli 2.4.1\nUsage: /usr/local/bin/test-cli [OPTIONS]\n\nOptions:\n  --config <FILE>  Config file [default: /etc/test-cli.toml]\n".to_string(),
+        );
+        analysis.version = Some("2.4.1".to_string());
+        analysis.subcommands = vec![Subcommand {
+            name: "run-all".to_string(),
+            description: Some("Run everything".to_string()),
+            options: vec![],
+            required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
+            subcommands: vec![],
+            depth: 0,
+        }];
+        analysis
+    }
+
+    #[test]
+    fn test_redact_versions_and_paths() {
+        let analysis = create_test_analysis();
+        let redacted = SnapboxGenerator::redact(&analysis.help_output, &analysis);
+
+        assert!(!redacted.contains("2.4.1"));
+        assert!(!redacted.contains("/usr/local/bin/test-cli"));
+        assert!(!redacted.contains("/etc/test-cli.toml"));
+        assert!(redacted.contains("test-cli [..]"));
+        assert!(redacted.contains("Usage: [..] [OPTIONS]"));
+        assert!(redacted.contains("[default: [..]]"));
+    }
+
+    #[test]
+    fn test_raw_string_literal_delimiters() {
+        let literal = SnapboxGenerator::to_raw_string_literal("plain");
+        assert_eq!(literal, "[r#\"\nplain\n\"#]");
+
+        let literal = SnapboxGenerator::to_raw_string_literal("tricky \"# content");
+        assert!(literal.starts_with("[r##\""));
+        assert!(literal.ends_with("\"##]"));
+    }
+
+    #[test]
+    fn test_to_ident() {
+        assert_eq!(SnapboxGenerator::to_ident("run-all"), "run_all");
+        assert_eq!(SnapboxGenerator::to_ident("Build"), "build");
+    }
+
+    #[test]
+    fn test_generate_all_categories() {
+        let generator = SnapboxGenerator::new().unwrap();
+        let analysis = create_test_analysis();
+
+        for category in TestCategory::all() {
+            let code = generator.generate(&analysis, category).unwrap();
+            assert!(code.contains("snapbox::cmd"), "{:?}", category);
+            assert!(
+                code.contains("snapbox::cmd::cargo_bin!(\"test-cli\")"),
+                "{:?}",
+                category
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_basic_contains_help_snapshot() {
+        let generator = SnapboxGenerator::new().unwrap();
+        let analysis = create_test_analysis();
+
+        let code = generator.generate(&analysis, TestCategory::Basic).unwrap();
+
+        assert!(code.contains("stdout_eq(str![[r#\""));
+        assert!(code.contains("Usage: [..] [OPTIONS]"));
+        assert!(code.contains("fn test_subcommand_run_all_help()"));
+        assert!(code.contains("SNAPSHOTS=overwrite"));
+        assert_eq!(generator.file_extension(), "rs");
+        assert_eq!(generator.name(), "snapbox");
+    }
+
+    #[test]
+    fn test_generate_input_validation_positional_tests() {
+        let generator = SnapboxGenerator::new().unwrap();
+        let mut analysis = create_test_analysis();
+        analysis.subcommands[0].positional_args = vec![PositionalArg {
+            name: "TARGET".to_string(),
+            index: 0,
+            required: true,
+            variadic: false,
+            arg_type: OptionType::String,
+        }];
+
+        let code = generator
+            .generate(&analysis, TestCategory::InputValidation)
+            .unwrap();
+
+        assert!(code.contains("fn test_positional_run_all_missing()"));
+        assert!(code.contains("fn test_positional_run_all_too_many()"));
+        assert!(code.contains(".arg(\"unexpected-extra-arg\")"));
+    }
+
+    #[test]
+    fn test_generate_input_validation_boundary_tests() {
+        let generator = SnapboxGenerator::new().unwrap();
+        let mut analysis = create_test_analysis();
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--retries".to_string()),
+            description: Some("Retry count".to_string()),
+            option_type: OptionType::Numeric {
+                min: Some(1),
+                max: Some(5),
+            },
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        });
+
+        let code = generator
+            .generate(&analysis, TestCategory::InputValidation)
+            .unwrap();
+
+        assert!(code.contains(
+            "fn test_boundary_retries_max() {\n    Command::new(snapbox::cmd::cargo_bin!(\"test-cli\"))\n        .arg(\"--retries\")\n        .arg(\"5\")\n        .assert()\n        .success();"
+        ));
+        assert!(code.contains(
+            "fn test_boundary_retries_below_min() {\n    Command::new(snapbox::cmd::cargo_bin!(\"test-cli\"))\n        .arg(\"--retries\")\n        .arg(\"0\")\n        .assert()\n        .failure();"
+        ));
+        assert!(code.contains("fn test_boundary_retries_overflow()"));
+        assert!(!code.contains("fn assert_value_not_rejected("));
+
+        // Subcommands run with placeholder positionals: only a parser rejection fails
+        let retries = analysis.global_options.last().unwrap().clone();
+        analysis.subcommands[0].options.push(retries);
+        let code = generator
+            .generate(&analysis, TestCategory::InputValidation)
+            .unwrap();
+
+        assert!(code
+            .contains("fn assert_value_not_rejected(cmd: Command, option: &str, value: &str) {"));
+        assert!(code.contains(
+            "fn test_boundary_run_all_retries_max() {\n    assert_value_not_rejected(\n        Command::new(snapbox::cmd::cargo_bin!(\"test-cli\"))\n            .arg(\"run-all\")\n            .arg(\"--retries\")\n            .arg(\"5\"),\n        \"--retries\",\n        \"5\",\n    );\n}"
+        ));
+        assert!(code.contains("fn test_boundary_run_all_retries_below_min() {\n    Command::new("));
+    }
+
+    #[test]
+    fn test_generate_combinatorial_tests() {
+        let generator = SnapboxGenerator::new().unwrap();
+        let mut analysis = create_test_analysis();
+        for long in ["--verbose", "--quiet"] {
+            analysis.global_options.push(CliOption {
+                short: None,
+                long: Some(long.to_string()),
+                description: None,
+                option_type: OptionType::Flag,
+                required: false,
+                default_value: None,
+                env_var: None,
+                source: OptionSource::Help,
+            });
+        }
+
+        let code = generator
+            .generate(&analysis, TestCategory::Combinatorial)
+            .unwrap();
+
+        assert!(code.contains("const ALLOWED_EXIT_CODES: &[i32] = &[0, 1, 2];"));
+        assert!(code.contains("fn test_help_does_not_crash()"));
+        assert!(code.contains("fn test_combination_001()"));
+        assert!(code.contains(".arg(\"--verbose\")"));
+        assert!(code.contains("panicked at"));
+    }
+}
@@ -1,14 +1,25 @@
-use crate::analyzer::BehaviorInferrer;
+use crate::analyzer::{enum_rules, numeric_range, BehaviorInferrer, EnumRules, NumericRange};
 use crate::config::load_config;
 use crate::error::Result;
+use crate::generator::shells::ShellDialect;
 use crate::types::{
-    Assertion, CliAnalysis, CliOption, CliTestConfig, NoArgsBehavior, OptionType, TestCase,
-    TestCategory, TestPriority,
+    Assertion, CliAnalysis, CliOption, CliTestConfig, EnumMatrix, NoArgsBehavior, OptionRelation,
+    OptionType, PerformanceBudget, PositionalArg, Subcommand, TestCase, TestCategory, TestPriority,
 };
 use crate::utils::{choose_strategy, ParallelStrategy, Workload};
 use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::path::Path;
 
+/// Default p95 startup budget when `performance.max_startup_time` is not configured
+const DEFAULT_MAX_STARTUP_MS: u64 = 100;
+
+/// Default peak RSS budget when `performance.max_memory_mb` is not configured
+const DEFAULT_MAX_MEMORY_MB: u64 = 100;
+
+/// Measured runs per performance test
+const BENCHMARK_ITERATIONS: u32 = 20;
+
 /// Test generator for creating test cases from CLI analysis
 pub struct TestGenerator {
     /// CLI analysis to generate tests from
@@ -89,12 +100,17 @@ impl TestGenerator {
                 TestCategory::DirectoryTraversal => self.generate_directory_traversal_tests()?,
                 TestCategory::Performance => self.generate_performance_tests()?,
                 TestCategory::MultiShell => self.generate_multi_shell_tests()?,
+                TestCategory::Environment => self.generate_environment_tests()?,
+                TestCategory::OptionRelations => self.generate_option_relation_tests()?,
+                TestCategory::Combinatorial => self.generate_combinatorial_tests()?,
             };
 
             log::info!("Generated {} tests for {:?}", tests.len(), category);
             all_tests.extend(tests);
         }
 
+        self.apply_timeout_overrides(&mut all_tests);
+
         log::info!("Total tests generated: {}", all_tests.len());
         Ok(all_tests)
     }
@@ -144,15 +160,34 @@ impl TestGenerator {
                 TestCategory::DirectoryTraversal => self.generate_directory_traversal_tests(),
                 TestCategory::Performance => self.generate_performance_tests(),
                 TestCategory::MultiShell => self.generate_multi_shell_tests(),
+                TestCategory::Environment => self.generate_environment_tests(),
+                TestCategory::OptionRelations => self.generate_option_relation_tests(),
+                TestCategory::Combinatorial => self.generate_combinatorial_tests(),
             })
             .collect();
 
-        let all_tests: Vec<TestCase> = results?.into_iter().flatten().collect();
+        let mut all_tests: Vec<TestCase> = results?.into_iter().flatten().collect();
+
+        self.apply_timeout_overrides(&mut all_tests);
 
         log::info!("Total tests generated (parallel): {}", all_tests.len());
         Ok(all_tests)
     }
 
+    /// Apply per-test timeout overrides from `global.test_timeouts` in the config
+    fn apply_timeout_overrides(&self, tests: &mut [TestCase]) {
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        for test in tests.iter_mut() {
+            if let Some(&timeout) = config.global.test_timeouts.get(&test.id) {
+                log::debug!("Timeout override for {}: {}s", test.id, timeout);
+                test.timeout = Some(timeout);
+            }
+        }
+    }
+
     /// Generate tests with automatic strategy selection
     ///
     /// This is the recommended method for test generation. It automatically
@@ -436,72 +471,21 @@ impl TestGenerator {
             })
             .unwrap_or_default();
 
-        // Find a string option for testing (prefer --config, --file, or first string option)
+        // Find a string option for testing (first string or path option)
         // Skip options that are in skip_options list
-        let string_option = self
-            .analysis
-            .global_options
-            .iter()
-            .find(|opt| {
-                matches!(opt.option_type, OptionType::String | OptionType::Path)
-                    && opt.long.is_some()
-                    && !skip_options.iter().any(|skip_name| {
-                        opt.long
-                            .as_ref()
-                            .is_some_and(|long| long.trim_start_matches("--") == skip_name)
-                    })
-            })
-            .and_then(|opt| opt.long.as_ref())
-            .unwrap_or(&"--invalid-option".to_string())
-            .clone();
-
-        // Test 1: Command injection via option
-        // MUST reject malicious input (any non-zero exit code)
-        tests.push(
-            TestCase::new(
-                "security-001".to_string(),
-                "Reject command injection in option value".to_string(),
-                TestCategory::Security,
-                format!("\"$CLI_BINARY\" {} 'test; rm -rf /'", string_option),
-            )
-            .expect_nonzero_exit() // Accept exit code 1, 2, or any non-zero
-            .with_priority(TestPriority::SecurityCheck)
-            .with_tag("injection".to_string())
-            .with_tag("critical".to_string()),
-        );
-
-        // Test 2: Null byte injection
-        // MUST reject malicious input (any non-zero exit code)
-        tests.push(
-            TestCase::new(
-                "security-002".to_string(),
-                "Reject null byte in option value".to_string(),
-                TestCategory::Security,
-                format!(
-                    r#""$CLI_BINARY" {} $'/tmp/test\x00malicious'"#,
-                    string_option
-                ),
-            )
-            .expect_nonzero_exit() // Accept exit code 1, 2, or any non-zero
-            .with_priority(TestPriority::SecurityCheck)
-            .with_tag("injection".to_string())
-            .with_tag("critical".to_string()),
-        );
-
-        // Test 3: Path traversal
-        // MUST reject path traversal attempt (any non-zero exit code)
-        tests.push(
-            TestCase::new(
-                "security-003".to_string(),
-                "Reject path traversal attempt".to_string(),
-                TestCategory::Security,
-                format!("\"$CLI_BINARY\" {} ../../../etc/passwd", string_option),
-            )
-            .expect_nonzero_exit() // Accept exit code 1, 2, or any non-zero
-            .with_priority(TestPriority::SecurityCheck)
-            .with_tag("path-traversal".to_string())
-            .with_tag("critical".to_string()),
-        );
+        let string_option = security_option(&self.analysis.global_options, &skip_options)
+            .unwrap_or("--invalid-option");
+        tests.extend(injection_tests(string_option, None));
+
+        // Subcommand string options, invoked with placeholders for required arguments
+        for scope in subcommand_scopes(&self.analysis) {
+            if scope.destructive {
+                continue;
+            }
+            if let Some(option) = security_option(&scope.subcommand.options, &skip_options) {
+                tests.extend(injection_tests(option, Some(&scope)));
+            }
+        }
 
         // Test 4: Long input (buffer overflow test)
         // NOTE: Disabled by default due to platform-dependent behavior
@@ -614,102 +598,28 @@ impl TestGenerator {
 
     /// Generate input validation tests
     fn generate_input_validation_tests(&self) -> Result<Vec<TestCase>> {
-        let mut tests = Vec::new();
-
-        // Find numeric options
-        let numeric_options: Vec<&CliOption> = self
-            .analysis
-            .global_options
-            .iter()
-            .filter(|opt| matches!(opt.option_type, OptionType::Numeric { .. }))
-            .collect();
-
-        for (idx, option) in numeric_options.iter().enumerate() {
-            let flag = option.long.as_ref().or(option.short.as_ref()).unwrap();
+        let mut tests = option_validation_tests(&self.analysis.global_options, None);
 
-            // Test 1: Valid value
-            tests.push(
-                TestCase::new(
-                    format!("input-{:03}-valid", idx + 1),
-                    format!("Accept valid numeric value for {}", flag),
-                    TestCategory::InputValidation,
-                    format!("\"$CLI_BINARY\" {} 10", flag),
-                )
-                .with_tag("numeric".to_string()),
-            );
-
-            // Test 2: Non-numeric value
-            tests.push(
-                TestCase::new(
-                    format!("input-{:03}-invalid", idx + 1),
-                    format!("Reject non-numeric value for {}", flag),
-                    TestCategory::InputValidation,
-                    format!("\"$CLI_BINARY\" {} 'not-a-number'", flag),
-                )
-                .with_exit_code(1)
-                .with_tag("numeric".to_string())
-                .with_tag("validation".to_string()),
-            );
-
-            // Test 3: Negative value (if min >= 0)
-            if let OptionType::Numeric {
-                min: Some(min_val), ..
-            } = &option.option_type
-            {
-                if *min_val >= 0 {
-                    tests.push(
-                        TestCase::new(
-                            format!("input-{:03}-negative", idx + 1),
-                            format!("Reject negative value for {}", flag),
-                            TestCategory::InputValidation,
-                            format!("\"$CLI_BINARY\" {} -1", flag),
-                        )
-                        .with_exit_code(1)
-                        .with_tag("numeric".to_string())
-                        .with_tag("validation".to_string()),
-                    );
-                }
+        // Subcommand options, invoked with placeholders for required arguments
+        for scope in subcommand_scopes(&self.analysis) {
+            if scope.destructive {
+                continue;
             }
+            tests.extend(option_validation_tests(
+                &scope.subcommand.options,
+                Some(&scope),
+            ));
         }
 
-        // Find enum options
-        let enum_options: Vec<&CliOption> = self
-            .analysis
-            .global_options
-            .iter()
-            .filter(|opt| matches!(opt.option_type, OptionType::Enum { .. }))
-            .collect();
-
-        for (idx, option) in enum_options.iter().enumerate() {
-            let flag = option.long.as_ref().or(option.short.as_ref()).unwrap();
-
-            if let OptionType::Enum { values } = &option.option_type {
-                if let Some(first_value) = values.first() {
-                    // Test valid enum value
-                    tests.push(
-                        TestCase::new(
-                            format!("enum-{:03}-valid", idx + 1),
-                            format!("Accept valid enum value for {}", flag),
-                            TestCategory::InputValidation,
-                            format!("\"$CLI_BINARY\" {} {}", flag, first_value),
-                        )
-                        .with_tag("enum".to_string()),
-                    );
-                }
-
-                // Test invalid enum value
-                tests.push(
-                    TestCase::new(
-                        format!("enum-{:03}-invalid", idx + 1),
-                        format!("Reject invalid enum value for {}", flag),
-                        TestCategory::InputValidation,
-                        format!("\"$CLI_BINARY\" {} 'invalid-value-xyz'", flag),
-                    )
-                    .with_exit_code(1)
-                    .with_tag("enum".to_string())
+        // Positional arguments: missing, extra and wrongly typed
+        for case in positional_cases(&self.analysis) {
+            let command = command_line(&case.args);
+            tests.push(
+                TestCase::new(case.id, case.name, TestCategory::InputValidation, command)
+                    .expect_nonzero_exit()
+                    .with_tag("positional".to_string())
                     .with_tag("validation".to_string()),
-                );
-            }
+            );
         }
 
         Ok(tests)
@@ -735,14 +645,8 @@ impl TestGenerator {
             .unwrap_or(1); // Default to 1 if not specified
 
         // Look for destructive subcommands (delete, remove, clean, destroy, etc.)
-        let destructive_keywords = ["delete", "remove", "clean", "destroy", "purge", "drop"];
-
         for subcommand in &self.analysis.subcommands {
-            let is_destructive = destructive_keywords
-                .iter()
-                .any(|keyword| subcommand.name.to_lowercase().contains(keyword));
-
-            if is_destructive {
+            if is_destructive(&subcommand.name) {
                 // Generate dummy values for required arguments
                 let dummy_args = subcommand
                     .required_args
@@ -935,187 +839,2219 @@ impl TestGenerator {
     }
 
     /// Generate performance tests
+    ///
+    /// Budgets come from `test_adjustments.performance` (default: 100ms p95 startup,
+    /// 100MB peak RSS); each test benchmarks the command over repeated runs.
     fn generate_performance_tests(&self) -> Result<Vec<TestCase>> {
+        let performance = self
+            .config
+            .as_ref()
+            .and_then(|c| c.test_adjustments.performance.as_ref());
+
+        let max_startup_ms = performance
+            .and_then(|p| p.max_startup_time)
+            .unwrap_or(DEFAULT_MAX_STARTUP_MS);
+        let max_memory_mb = performance
+            .and_then(|p| p.max_memory_mb)
+            .unwrap_or(DEFAULT_MAX_MEMORY_MB);
+        let skip_in_ci = performance.is_some_and(|p| p.skip_in_ci);
+
+        let startup_budget = PerformanceBudget {
+            iterations: BENCHMARK_ITERATIONS,
+            max_startup_ms: Some(max_startup_ms),
+            max_memory_mb: None,
+            skip_in_ci,
+        };
+
         let tests = vec![
             // Test 1: Startup time (help should be fast)
             TestCase::new(
                 "perf-001".to_string(),
-                "Startup time for --help < 100ms".to_string(),
+                format!("Startup time for --help < {}ms", max_startup_ms),
                 TestCategory::Performance,
                 "\"$CLI_BINARY\" --help".to_string(),
             )
             .with_exit_code(0)
             .with_tag("startup".to_string())
-            .with_tag("benchmark".to_string()),
+            .with_tag("benchmark".to_string())
+            .with_budget(startup_budget),
             // Test 2: Memory usage
             TestCase::new(
                 "perf-002".to_string(),
-                "Memory usage stays within reasonable limits".to_string(),
+                format!("Memory usage for --help < {}MB", max_memory_mb),
                 TestCategory::Performance,
                 "\"$CLI_BINARY\" --help".to_string(),
             )
             .with_exit_code(0)
             .with_tag("memory".to_string())
-            .with_tag("benchmark".to_string()),
+            .with_tag("benchmark".to_string())
+            .with_budget(PerformanceBudget {
+                iterations: BENCHMARK_ITERATIONS,
+                max_startup_ms: None,
+                max_memory_mb: Some(max_memory_mb),
+                skip_in_ci,
+            }),
         ];
 
         Ok(tests)
     }
 
     /// Generate multi-shell compatibility tests
+    ///
+    /// Shells come from `test_adjustments.multi_shell.shells` (default: bash, zsh, sh).
+    /// Each test requires its shell on `PATH` and is skipped when it is not installed.
     fn generate_multi_shell_tests(&self) -> Result<Vec<TestCase>> {
         let mut tests = Vec::new();
 
-        // Test basic command in different shells (bash, zsh, sh)
-        // Note: Use double quotes to allow variable expansion, escape inner quotes
-        for shell in &["bash", "zsh", "sh"] {
+        let multi_shell = self
+            .config
+            .as_ref()
+            .and_then(|c| c.test_adjustments.multi_shell.as_ref());
+
+        let shells: Vec<String> = match multi_shell {
+            Some(m) if !m.shells.is_empty() => m.shells.clone(),
+            _ => vec!["bash".to_string(), "zsh".to_string(), "sh".to_string()],
+        };
+
+        for name in &shells {
+            let Some(shell) = ShellDialect::from_name(name) else {
+                log::warn!("Skipping unsupported shell in multi_shell.shells: {}", name);
+                continue;
+            };
+
+            // Per-shell environment: bash_env/zsh_env, then shell_env overrides
+            let mut env = BTreeMap::new();
+            if let Some(m) = multi_shell {
+                let legacy = match shell {
+                    ShellDialect::Bash => Some(&m.bash_env),
+                    ShellDialect::Zsh => Some(&m.zsh_env),
+                    _ => None,
+                };
+                env.extend(
+                    legacy
+                        .into_iter()
+                        .flatten()
+                        .map(|(k, v)| (k.clone(), v.clone())),
+                );
+                if let Some(vars) = m.shell_env.get(name.as_str()) {
+                    env.extend(vars.iter().map(|(k, v)| (k.clone(), v.clone())));
+                }
+            }
+
             tests.push(
                 TestCase::new(
-                    format!("multi-shell-{}", shell),
-                    format!("Run --help in {}", shell),
+                    format!("multi-shell-{}", shell.as_str()),
+                    format!("Run --help in {}", shell.as_str()),
                     TestCategory::MultiShell,
-                    format!("{} -c \"\\\"$CLI_BINARY\\\" --help\"", shell),
+                    shell.command(&["--help"], &env),
                 )
                 .with_exit_code(0)
-                .with_tag(shell.to_string()),
+                .with_tag(shell.as_str().to_string())
+                .with_requirement(shell.program().to_string()),
             );
         }
 
         Ok(tests)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::types::Subcommand;
-    use std::path::PathBuf;
 
-    fn create_test_analysis() -> CliAnalysis {
-        let mut analysis = CliAnalysis::new(
-            PathBuf::from("/usr/bin/test-cli"),
-            "test-cli".to_string(),
-            "Test CLI help output".to_string(),
-        );
+    /// Generate environment variable tests
+    ///
+    /// `--help` must work with an empty environment. For each option bound to a
+    /// variable (`[env: NAME=]`), setting the variable to a non-default value must
+    /// behave exactly like passing the flag with it (same exit code and output,
+    /// whatever the code is), and invalid numeric or enum values in the variable
+    /// must be rejected with a non-zero exit. Subcommand options
+    /// are run through their subcommand.
+    fn generate_environment_tests(&self) -> Result<Vec<TestCase>> {
+        let mut tests = vec![TestCase::new(
+            "env-empty".to_string(),
+            "Display help without any environment variables".to_string(),
+            TestCategory::Environment,
+            "env -i \"$CLI_BINARY\" --help".to_string(),
+        )
+        .with_exit_code(0)
+        .with_assertion(Assertion::OutputContains("Usage:".to_string()))
+        .with_tag("env".to_string())];
+
+        for case in env_cases(&self.analysis) {
+            let plain = command_line(&case.plain_args());
+
+            // Test 1: Variable has the same effect as the flag
+            if let Some(env_value) = case.env_value() {
+                tests.push(
+                    TestCase::new(
+                        format!("{}-flag", case.id),
+                        format!(
+                            "{} has the same effect as {}{}",
+                            case.var, case.flag, case.label
+                        ),
+                        TestCategory::Environment,
+                        format!(
+                            "env {}={} {}",
+                            case.var,
+                            ShellDialect::Sh.quote(&env_value),
+                            plain
+                        ),
+                    )
+                    .with_assertion(Assertion::SameOutcomeAs(command_line(
+                        &case.flag_args(case.value.as_deref()),
+                    )))
+                    .with_tag("env".to_string())
+                    .with_tag(case.var.clone()),
+                );
+            }
 
-        analysis.version = Some("1.0.0".to_string());
+            // Test 2: Invalid value in the variable is rejected
+            if let Some(value) = &case.invalid {
+                tests.push(
+                    TestCase::new(
+                        format!("{}-invalid", case.id),
+                        format!("Reject invalid value in {}{}", case.var, case.label),
+                        TestCategory::Environment,
+                        format!(
+                            "env {}={} {}",
+                            case.var,
+                            ShellDialect::Sh.quote(value),
+                            plain
+                        ),
+                    )
+                    .expect_nonzero_exit()
+                    .with_tag("env".to_string())
+                    .with_tag(case.var.clone())
+                    .with_tag("validation".to_string()),
+                );
+            }
+        }
 
-        // Add a numeric option
-        analysis.global_options.push(CliOption {
-            short: Some("-t".to_string()),
-            long: Some("--timeout".to_string()),
-            description: Some("Timeout in seconds".to_string()),
-            option_type: OptionType::Numeric {
-                min: Some(0),
-                max: Some(3600),
-            },
-            required: false,
-            default_value: Some("30".to_string()),
-        });
+        Ok(tests)
+    }
 
-        // Add a path option
-        analysis.global_options.push(CliOption {
-            short: Some("-f".to_string()),
-            long: Some("--file".to_string()),
-            description: Some("Input file".to_string()),
-            option_type: OptionType::Path,
-            required: false,
-            default_value: None,
-        });
+    /// Generate option relation tests
+    ///
+    /// Conflicting options (and any two members of an exclusive group) given
+    /// together must be rejected. An option given without the option it requires
+    /// must be rejected with a message naming the missing option.
+    fn generate_option_relation_tests(&self) -> Result<Vec<TestCase>> {
+        let mut tests = Vec::new();
 
-        // Add an enum option
-        analysis.global_options.push(CliOption {
-            short: None,
-            long: Some("--format".to_string()),
-            description: Some("Output format".to_string()),
-            option_type: OptionType::Enum {
-                values: vec!["json".to_string(), "yaml".to_string(), "text".to_string()],
-            },
-            required: false,
-            default_value: Some("text".to_string()),
-        });
+        for case in relation_cases(&self.analysis) {
+            let command = command_line(&case.args);
+            let mut test =
+                TestCase::new(case.id, case.name, TestCategory::OptionRelations, command)
+                    .expect_nonzero_exit();
+            test = match case.requires {
+                Some(requires) => test
+                    .with_assertion(Assertion::OutputContains(requires))
+                    .with_tag("requires".to_string()),
+                None => test.with_tag("conflict".to_string()),
+            };
+            tests.push(test);
+        }
 
-        // Add a subcommand
-        analysis.subcommands.push(Subcommand {
-            name: "delete".to_string(),
-            description: Some("Delete resources".to_string()),
-            options: vec![CliOption {
-                short: None,
-                long: Some("--yes".to_string()),
-                description: Some("Skip confirmation".to_string()),
-                option_type: OptionType::Flag,
-                required: false,
-                default_value: None,
-            }],
-            required_args: vec![],
-            subcommands: vec![],
-            depth: 0,
-        });
+        if tests.is_empty() {
+            log::debug!("No option relations detected");
+        }
 
-        analysis
+        Ok(tests)
     }
 
-    #[test]
-    fn test_generator_creation() {
-        let analysis = create_test_analysis();
-        let categories = vec![TestCategory::Basic];
-        let generator = TestGenerator::new(analysis, categories);
+    /// Generate pairwise option combination tests (opt-in)
+    ///
+    /// Every pair of settings of two options (absent, given, or each value) of a
+    /// command appears together in at least one invocation. No combination may
+    /// crash: the exit code must be one the CLI documents and the output must not
+    /// contain a Rust panic message.
+    fn generate_combinatorial_tests(&self) -> Result<Vec<TestCase>> {
+        let allowed = allowed_exit_codes(&self.analysis);
+
+        let tests: Vec<TestCase> = combination_cases(&self.analysis)
+            .into_iter()
+            .map(|case| {
+                TestCase::new(
+                    case.id,
+                    case.name,
+                    TestCategory::Combinatorial,
+                    command_line(&case.args),
+                )
+                .with_assertion(Assertion::ExitCodeIn(allowed.clone()))
+                .with_assertion(Assertion::OutputNotContains(PANIC_MARKER.to_string()))
+                .with_tag("combinatorial".to_string())
+                .with_tag("pairwise".to_string())
+            })
+            .collect();
 
-        assert_eq!(generator.categories.len(), 1);
-    }
+        if tests.is_empty() {
+            log::debug!("No command has two or more combinable options");
+        }
 
-    #[test]
-    fn test_generate_basic_tests() {
-        let analysis = create_test_analysis();
-        let generator = TestGenerator::new(analysis, vec![]);
+        Ok(tests)
+    }
+}
 
-        let tests = generator.generate_basic_tests().unwrap();
+/// `value` moved into `[min, max]`, for picking a numeric value an option accepts
+///
+/// The minimum wins when the bounds cross.
+fn clamp_number(value: i64, min: Option<i64>, max: Option<i64>) -> i64 {
+    let value = max.map_or(value, |max| value.min(max));
+    min.map_or(value, |min| value.max(min))
+}
 
-        assert!(!tests.is_empty());
-        assert!(tests.iter().any(|t| t.command.contains("--help")));
-        assert!(tests.iter().any(|t| t.command.contains("--version")));
+/// Valid and invalid sample values for an option set through its environment variable
+///
+/// The valid value differs from the option's default, so a variable that is
+/// ignored cannot pass for one that works; it is `None` when the default is the
+/// only valid value. Flags have no value (the variable is set to `1`). Only
+/// numeric and enum options have a value that must be rejected.
+pub(crate) fn env_var_values(option: &CliOption) -> (Option<String>, Option<String>) {
+    let default = option.default_value.as_deref().map(str::trim);
+    let non_default = |candidates: Vec<String>| {
+        candidates.into_iter().find(|value| match default {
+            Some(default) => match (value.parse::<i64>(), default.parse::<i64>()) {
+                (Ok(value), Ok(default)) => value != default,
+                _ => value != default,
+            },
+            None => true,
+        })
+    };
+
+    match &option.option_type {
+        OptionType::Flag => (None, None),
+        OptionType::Numeric { min, max } => {
+            let clamp = |value: i64| clamp_number(value, *min, *max);
+            let base = clamp(10);
+            let candidates = [
+                base,
+                clamp(base.saturating_add(1)),
+                clamp(base.saturating_sub(1)),
+            ]
+            .into_iter()
+            .chain(*min)
+            .chain(*max)
+            .map(|value| value.to_string())
+            .collect();
+            (non_default(candidates), Some("not-a-number".to_string()))
+        }
+        OptionType::Enum { values } => (
+            non_default(values.clone()),
+            Some("invalid-value-xyz".to_string()),
+        ),
+        OptionType::Path => (non_default(vec![".".to_string(), "/tmp".to_string()]), None),
+        OptionType::String => (
+            non_default(vec!["test-value".to_string(), "other-value".to_string()]),
+            None,
+        ),
     }
+}
 
-    #[test]
-    fn test_generate_security_tests() {
-        let analysis = create_test_analysis();
-        let generator = TestGenerator::new(analysis, vec![]);
+/// An option bound to an environment variable, with the command that owns it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EnvCase {
+    /// Test ID prefix (`env-001` for global options, `env-<path>-<var>` otherwise)
+    pub id: String,
 
-        let tests = generator.generate_security_tests().unwrap();
+    /// Test-name suffix naming the subcommand (empty for global options)
+    pub label: String,
 
-        assert!(!tests.is_empty());
-        assert!(tests
-            .iter()
-            .any(|t| t.tags.contains(&"injection".to_string())));
-    }
+    /// Environment variable name
+    pub var: String,
 
-    #[test]
-    fn test_generate_input_validation_tests() {
-        let analysis = create_test_analysis();
-        let generator = TestGenerator::new(analysis, vec![]);
+    /// Flag the variable stands for
+    pub flag: String,
 
-        let tests = generator.generate_input_validation_tests().unwrap();
+    /// Whether the option is a flag (the variable is set to `1`)
+    pub is_flag: bool,
 
-        assert!(!tests.is_empty());
-        // Should have tests for numeric, path, and enum options
-        assert!(tests
-            .iter()
-            .any(|t| t.tags.contains(&"numeric".to_string())));
-        assert!(tests.iter().any(|t| t.tags.contains(&"enum".to_string())));
-    }
+    /// Valid non-default value (`None` for flags, or when there is none)
+    pub value: Option<String>,
 
-    #[test]
-    fn test_generate_destructive_ops_tests() {
-        let analysis = create_test_analysis();
-        let generator = TestGenerator::new(analysis, vec![]);
+    /// Value that must be rejected (`None` if any value is accepted)
+    pub invalid: Option<String>,
 
-        let tests = generator.generate_destructive_ops_tests().unwrap();
+    /// Subcommand path leading to the option
+    pub path: Vec<String>,
 
-        assert!(!tests.is_empty());
-        assert!(tests.iter().any(|t| t.command.contains("delete")));
-    }
+    /// Placeholders for the owning subcommand's required arguments
+    pub required: Vec<String>,
+}
 
-    #[test]
+impl EnvCase {
+    /// Value to put in the variable (`None` if no valid non-default value exists)
+    pub fn env_value(&self) -> Option<String> {
+        if self.is_flag {
+            Some("1".to_string())
+        } else {
+            self.value.clone()
+        }
+    }
+
+    /// Arguments without the option (the variable supplies it)
+    pub fn plain_args(&self) -> Vec<String> {
+        self.path.iter().chain(&self.required).cloned().collect()
+    }
+
+    /// Arguments passing the option as a flag, with `value` unless it is a flag
+    pub fn flag_args(&self, value: Option<&str>) -> Vec<String> {
+        let mut args = self.path.clone();
+        args.push(self.flag.clone());
+        if !self.is_flag {
+            args.extend(value.map(str::to_string));
+        }
+        args.extend(self.required.iter().cloned());
+        args
+    }
+}
+
+/// Every option bound to an environment variable, run through its own command
+///
+/// Global options keep their numbered IDs. Subcommand options are invoked through
+/// the subcommand path with placeholders for its required arguments; destructive
+/// subcommands are skipped.
+pub(crate) fn env_cases(analysis: &CliAnalysis) -> Vec<EnvCase> {
+    let case = |option: &CliOption, id: String, scope: Option<&CommandScope>| {
+        let var = option.env_var.clone()?;
+        let flag = option.long.as_ref().or(option.short.as_ref())?.clone();
+        let (value, invalid) = env_var_values(option);
+        Some(EnvCase {
+            id,
+            label: scope.map(CommandScope::label).unwrap_or_default(),
+            var,
+            flag,
+            is_flag: option.option_type == OptionType::Flag,
+            value,
+            invalid,
+            path: scope.map(|scope| scope.path.clone()).unwrap_or_default(),
+            required: scope.map(CommandScope::required_values).unwrap_or_default(),
+        })
+    };
+
+    let mut cases: Vec<EnvCase> = analysis
+        .global_options
+        .iter()
+        .filter(|option| option.env_var.is_some())
+        .enumerate()
+        .filter_map(|(idx, option)| case(option, format!("env-{:03}", idx + 1), None))
+        .collect();
+
+    for scope in subcommand_scopes(analysis) {
+        if scope.destructive {
+            continue;
+        }
+        for option in &scope.subcommand.options {
+            let Some(var) = &option.env_var else {
+                continue;
+            };
+            let id = format!("env-{}-{}", scope.slug(), slugify(var));
+            cases.extend(case(option, id, Some(&scope)));
+        }
+    }
+
+    cases
+}
+
+/// Subcommand keywords that mark an operation as destructive
+const DESTRUCTIVE_KEYWORDS: [&str; 6] = ["delete", "remove", "clean", "destroy", "purge", "drop"];
+
+/// Whether a subcommand name looks like a destructive operation
+fn is_destructive(name: &str) -> bool {
+    let name = name.to_lowercase();
+    DESTRUCTIVE_KEYWORDS
+        .iter()
+        .any(|keyword| name.contains(keyword))
+}
+
+/// A subcommand of the analysis together with the path that invokes it
+pub(crate) struct CommandScope<'a> {
+    /// Subcommand names from the binary down (`["remote", "add"]`)
+    pub path: Vec<String>,
+
+    /// The subcommand itself
+    pub subcommand: &'a Subcommand,
+
+    /// Whether this subcommand or one of its parents is destructive
+    pub destructive: bool,
+}
+
+impl CommandScope<'_> {
+    /// Path as an ID fragment (`remote-add`)
+    fn slug(&self) -> String {
+        self.path
+            .iter()
+            .map(|name| slugify(name))
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Test-name suffix naming the subcommand (` in subcommand remote add`)
+    fn label(&self) -> String {
+        format!(" in subcommand {}", self.path.join(" "))
+    }
+
+    /// Placeholder values for the required positional arguments
+    ///
+    /// Analysis files written before positional arguments were modelled only
+    /// have `required_args`; those get `test-value` each.
+    fn required_values(&self) -> Vec<String> {
+        if self.subcommand.positional_args.is_empty() {
+            return self
+                .subcommand
+                .required_args
+                .iter()
+                .map(|_| "test-value".to_string())
+                .collect();
+        }
+        self.subcommand
+            .positional_args
+            .iter()
+            .filter(|arg| arg.required)
+            .map(positional_value)
+            .collect()
+    }
+}
+
+/// Every subcommand of the analysis, parents before children
+///
+/// `help` subcommands are skipped since they only print help.
+pub(crate) fn subcommand_scopes(analysis: &CliAnalysis) -> Vec<CommandScope<'_>> {
+    fn walk<'a>(
+        path: &mut Vec<String>,
+        destructive: bool,
+        subcommands: &'a [Subcommand],
+        scopes: &mut Vec<CommandScope<'a>>,
+    ) {
+        for subcommand in subcommands {
+            if subcommand.name == "help" {
+                continue;
+            }
+            path.push(subcommand.name.clone());
+            let destructive = destructive || is_destructive(&subcommand.name);
+            scopes.push(CommandScope {
+                path: path.clone(),
+                subcommand,
+                destructive,
+            });
+            walk(path, destructive, &subcommand.subcommands, scopes);
+            path.pop();
+        }
+    }
+
+    let mut scopes = Vec::new();
+    walk(&mut Vec::new(), false, &analysis.subcommands, &mut scopes);
+    scopes
+}
+
+/// Numeric and enum validation tests for one command's options
+///
+/// Global options (`scope` is `None`) keep their numbered IDs (`input-001-invalid`).
+/// Subcommand IDs name the path and option (`input-remote-add-timeout-invalid`)
+/// so they stay stable when options are added elsewhere. Rejected values may
+/// end with any non-zero exit code. Subcommands run for real with placeholder
+/// positionals, so an accepted value there only has to get past the option
+/// parser ([`Assertion::ValueNotRejected`]).
+fn option_validation_tests(options: &[CliOption], scope: Option<&CommandScope>) -> Vec<TestCase> {
+    let mut tests = Vec::new();
+    let label = scope.map(CommandScope::label).unwrap_or_default();
+    let accept = |test: TestCase, flag: &str, value: &str| match scope {
+        Some(_) => test.with_assertion(Assertion::ValueNotRejected {
+            option: flag.to_string(),
+            value: value.to_string(),
+        }),
+        None => test,
+    };
+    let id = |group: &str, idx: usize, flag: &str, kind: &str| match scope {
+        Some(scope) => format!("{}-{}-{}-{}", group, scope.slug(), slugify(flag), kind),
+        None => format!("{}-{:03}-{}", group, idx + 1, kind),
+    };
+    let (prefix, suffix) = invocation_parts(scope);
+    let command = |option_args: String| format!("{} {}{}", prefix, option_args, suffix);
+
+    // Find numeric options
+    let numeric_options = options
+        .iter()
+        .filter(|opt| matches!(opt.option_type, OptionType::Numeric { .. }));
+
+    for (idx, option) in numeric_options.enumerate() {
+        let Some(flag) = option.long.as_ref().or(option.short.as_ref()) else {
+            continue;
+        };
+
+        let range = numeric_range(option);
+
+        // Test 1: Valid value, inside the range the boundary tests probe
+        let valid = range.map_or(10, |range| {
+            clamp_number(10, Some(range.min), Some(range.max))
+        });
+        tests.push(accept(
+            TestCase::new(
+                id("input", idx, flag, "valid"),
+                format!("Accept valid numeric value for {}{}", flag, label),
+                TestCategory::InputValidation,
+                command(format!("{} {}", flag, valid)),
+            )
+            .with_tag("numeric".to_string()),
+            flag,
+            &valid.to_string(),
+        ));
+
+        // Test 2: Non-numeric value
+        tests.push(
+            TestCase::new(
+                id("input", idx, flag, "invalid"),
+                format!("Reject non-numeric value for {}{}", flag, label),
+                TestCategory::InputValidation,
+                command(format!("{} 'not-a-number'", flag)),
+            )
+            .expect_nonzero_exit()
+            .with_tag("numeric".to_string())
+            .with_tag("validation".to_string()),
+        );
+
+        // Test 3: Negative value (if min >= 0)
+        if let OptionType::Numeric {
+            min: Some(min_val), ..
+        } = &option.option_type
+        {
+            if *min_val >= 0 {
+                tests.push(
+                    TestCase::new(
+                        id("input", idx, flag, "negative"),
+                        format!("Reject negative value for {}{}", flag, label),
+                        TestCategory::InputValidation,
+                        command(format!("{} -1", flag)),
+                    )
+                    .expect_nonzero_exit()
+                    .with_tag("numeric".to_string())
+                    .with_tag("validation".to_string()),
+                );
+            }
+        }
+
+        // Test 4: Boundary values from the parsed or configured range
+        let Some(range) = range else {
+            continue;
+        };
+        for boundary in boundary_values(range) {
+            let test = TestCase::new(
+                id("input", idx, flag, &format!("boundary-{}", boundary.kind)),
+                boundary.name(flag, &label),
+                TestCategory::InputValidation,
+                command(format!(
+                    "{} {}",
+                    flag,
+                    ShellDialect::Bash.quote(&boundary.value)
+                )),
+            )
+            .with_tag("numeric".to_string())
+            .with_tag("boundary".to_string());
+            tests.push(if boundary.accepted {
+                accept(test, flag, &boundary.value)
+            } else {
+                test.expect_nonzero_exit()
+                    .with_tag("validation".to_string())
+            });
+        }
+    }
+
+    // Find enum options
+    let enum_options = options
+        .iter()
+        .filter(|opt| matches!(opt.option_type, OptionType::Enum { .. }));
+
+    for (idx, option) in enum_options.enumerate() {
+        let Some(flag) = option.long.as_ref().or(option.short.as_ref()) else {
+            continue;
+        };
+
+        let option_tag = format!("{}{}", EnumMatrix::OPTION_TAG_PREFIX, flag);
+
+        if let (OptionType::Enum { values }, Some(rules)) =
+            (&option.option_type, enum_rules(option))
+        {
+            // Every declared value, case variants, prefixes, empty and padded values
+            for case in enum_values(values, rules) {
+                let test = TestCase::new(
+                    id("enum", idx, flag, case.kind.as_str()),
+                    case.name(flag, &label),
+                    TestCategory::InputValidation,
+                    command(format!(
+                        "{} {}",
+                        flag,
+                        ShellDialect::Bash.quote(&case.value)
+                    )),
+                )
+                .with_tag("enum".to_string())
+                .with_tag(option_tag.clone());
+                tests.push(if case.accepted {
+                    accept(test, flag, &case.value)
+                } else {
+                    test.expect_nonzero_exit()
+                        .with_tag("validation".to_string())
+                });
+            }
+
+            // Test invalid enum value
+            tests.push(
+                TestCase::new(
+                    id("enum", idx, flag, "invalid"),
+                    format!("Reject invalid enum value for {}{}", flag, label),
+                    TestCategory::InputValidation,
+                    command(format!("{} 'invalid-value-xyz'", flag)),
+                )
+                .expect_nonzero_exit()
+                .with_tag("enum".to_string())
+                .with_tag(option_tag)
+                .with_tag("validation".to_string()),
+            );
+        }
+    }
+
+    tests
+}
+
+/// Long flag of the first string or path option not listed in `skip_options`
+fn security_option<'a>(options: &'a [CliOption], skip_options: &[String]) -> Option<&'a str> {
+    options
+        .iter()
+        .find(|opt| {
+            matches!(opt.option_type, OptionType::String | OptionType::Path)
+                && opt.long.is_some()
+                && !skip_options.iter().any(|skip_name| {
+                    opt.long
+                        .as_ref()
+                        .is_some_and(|long| long.trim_start_matches("--") == skip_name)
+                })
+        })
+        .and_then(|opt| opt.long.as_deref())
+}
+
+/// Command injection, null byte and path traversal tests through `flag`
+///
+/// Global tests keep their numbered IDs (`security-001`); subcommand IDs name
+/// the path (`security-remote-add-injection`).
+fn injection_tests(flag: &str, scope: Option<&CommandScope>) -> Vec<TestCase> {
+    let label = scope.map(CommandScope::label).unwrap_or_default();
+    let id = |number: usize, kind: &str| match scope {
+        Some(scope) => format!("security-{}-{}", scope.slug(), kind),
+        None => format!("security-{:03}", number),
+    };
+    let (prefix, suffix) = invocation_parts(scope);
+
+    vec![
+        // Test 1: Command injection via option
+        // MUST reject malicious input (any non-zero exit code)
+        TestCase::new(
+            id(1, "injection"),
+            format!("Reject command injection in option value{}", label),
+            TestCategory::Security,
+            format!("{} {} 'test; rm -rf /'{}", prefix, flag, suffix),
+        )
+        .expect_nonzero_exit() // Accept exit code 1, 2, or any non-zero
+        .with_priority(TestPriority::SecurityCheck)
+        .with_tag("injection".to_string())
+        .with_tag("critical".to_string()),
+        // Test 2: Null byte injection
+        // MUST reject malicious input (any non-zero exit code)
+        TestCase::new(
+            id(2, "null-byte"),
+            format!("Reject null byte in option value{}", label),
+            TestCategory::Security,
+            format!(r#"{} {} $'/tmp/test\x00malicious'{}"#, prefix, flag, suffix),
+        )
+        .expect_nonzero_exit() // Accept exit code 1, 2, or any non-zero
+        .with_priority(TestPriority::SecurityCheck)
+        .with_tag("injection".to_string())
+        .with_tag("null-byte".to_string())
+        .with_tag("critical".to_string()),
+        // Test 3: Path traversal
+        // MUST reject path traversal attempt (any non-zero exit code)
+        TestCase::new(
+            id(3, "path-traversal"),
+            format!("Reject path traversal attempt{}", label),
+            TestCategory::Security,
+            format!("{} {} ../../../etc/passwd{}", prefix, flag, suffix),
+        )
+        .expect_nonzero_exit() // Accept exit code 1, 2, or any non-zero
+        .with_priority(TestPriority::SecurityCheck)
+        .with_tag("path-traversal".to_string())
+        .with_tag("critical".to_string()),
+    ]
+}
+
+/// Command text around option arguments: `"$CLI_BINARY" sub nested` and ` <required args>`
+fn invocation_parts(scope: Option<&CommandScope>) -> (String, String) {
+    let prefix = command_line(scope.map(|s| &s.path[..]).unwrap_or_default());
+    let suffix = scope
+        .map(CommandScope::required_values)
+        .unwrap_or_default()
+        .iter()
+        .map(|value| format!(" {}", ShellDialect::Bash.quote(value)))
+        .collect();
+    (prefix, suffix)
+}
+
+/// A value passed to a typed option and whether it must be accepted
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProbeValue {
+    /// ID fragment (`below-min`, `value-json`)
+    kind: String,
+
+    /// Test-name description (`value below minimum`)
+    what: &'static str,
+
+    /// Value passed to the option
+    value: String,
+
+    /// Whether the tool is expected to accept the value
+    accepted: bool,
+}
+
+impl ProbeValue {
+    /// Test name such as `Reject value above maximum (3601) for --timeout`
+    fn name(&self, flag: &str, label: &str) -> String {
+        let verb = if self.accepted { "Accept" } else { "Reject" };
+        format!(
+            "{} {} ({}) for {}{}",
+            verb, self.what, self.value, flag, label
+        )
+    }
+}
+
+/// Append a probe unless an earlier one already passes the same value
+fn push_probe(
+    probes: &mut Vec<ProbeValue>,
+    kind: impl Into<String>,
+    what: &'static str,
+    value: String,
+    accepted: bool,
+) {
+    if probes.iter().all(|probe| probe.value != value) {
+        probes.push(ProbeValue {
+            kind: kind.into(),
+            what,
+            value,
+            accepted,
+        });
+    }
+}
+
+/// Value one past `i64::MAX`, which wraps around in unchecked parsers
+const I64_OVERFLOW: &str = "9223372036854775808";
+
+/// Boundary values for a numeric range
+///
+/// min, min-1, max, max+1, zero, i64 overflow, a fractional value, hex and
+/// scientific notation. max+1 is skipped when the maximum only comes from
+/// `default_constraints`, which is a guess rather than a limit. Fractions and
+/// scientific notation are only valid for `float` ranges; hex is rejected for
+/// both. A value equal to an earlier one (zero when min is 0) is not repeated.
+fn boundary_values(range: NumericRange) -> Vec<ProbeValue> {
+    let NumericRange {
+        min,
+        max,
+        float,
+        explicit_max,
+    } = range;
+    let in_range = |value: i64| (min..=max).contains(&value);
+    // Integer base for the fractional value: `base.5` stays inside the range
+    let base = min.max(0).min(max.saturating_sub(1));
+
+    let candidates = [
+        Some(("min", "minimum", min.to_string(), true)),
+        min.checked_sub(1)
+            .map(|v| ("below-min", "value below minimum", v.to_string(), false)),
+        Some(("max", "maximum", max.to_string(), true)),
+        max.checked_add(1)
+            .filter(|_| explicit_max)
+            .map(|v| ("above-max", "value above maximum", v.to_string(), false)),
+        Some(("zero", "zero", "0".to_string(), in_range(0))),
+        Some(("overflow", "i64 overflow", I64_OVERFLOW.to_string(), false)),
+        if float && min < max {
+            Some((
+                "float",
+                "fractional value",
+                (base as f64 + 0.5).to_string(),
+                true,
+            ))
+        } else {
+            Some(("float", "fractional value", "1.5".to_string(), false))
+        },
+        Some(("hex", "hexadecimal value", "0x10".to_string(), false)),
+        if float {
+            Some((
+                "scientific",
+                "scientific notation",
+                format!("{}e0", min),
+                true,
+            ))
+        } else {
+            Some((
+                "scientific",
+                "scientific notation",
+                "1e3".to_string(),
+                false,
+            ))
+        },
+    ];
+
+    let mut probes = Vec::new();
+    for (kind, what, value, accepted) in candidates.into_iter().flatten() {
+        push_probe(&mut probes, kind, what, value, accepted);
+    }
+    probes
+}
+
+/// Values to try for an enum option
+///
+/// Every declared value must be accepted. Upper- and mixed-case variants of the
+/// first value with letters are accepted unless `rules.case_sensitive`; a
+/// prefix abbreviation only when `rules.allow_prefix` and it is unambiguous.
+/// Empty and whitespace-padded values are always rejected.
+fn enum_values(values: &[String], rules: EnumRules) -> Vec<ProbeValue> {
+    let mut probes = Vec::new();
+
+    let mut kinds = Vec::new();
+    for (idx, value) in values.iter().enumerate() {
+        let slug = slugify(value);
+        let kind = if slug.is_empty() || kinds.contains(&slug) {
+            format!("value-{}", idx + 1)
+        } else {
+            format!("value-{}", slug)
+        };
+        kinds.push(slug);
+        push_probe(&mut probes, kind, "declared value", value.clone(), true);
+    }
+
+    let declared = |candidate: &str| values.iter().any(|value| value == candidate);
+
+    if let Some(base) = values
+        .iter()
+        .find(|value| value.chars().any(|c| c.is_alphabetic()))
+    {
+        let upper = base.to_uppercase();
+        if upper != *base && !declared(&upper) {
+            push_probe(
+                &mut probes,
+                "uppercase",
+                "uppercase variant",
+                upper,
+                !rules.case_sensitive,
+            );
+        }
+
+        let mut chars = base.chars();
+        let capitalized: String = chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default();
+        if capitalized != *base && !declared(&capitalized) {
+            push_probe(
+                &mut probes,
+                "mixed-case",
+                "mixed-case variant",
+                capitalized,
+                !rules.case_sensitive,
+            );
+        }
+    }
+
+    if let Some(base) = values.iter().find(|value| value.chars().count() >= 3) {
+        let prefix: String = base
+            .chars()
+            .take(base.chars().count().div_ceil(2))
+            .collect();
+        if !declared(&prefix) {
+            let matches = values.iter().filter(|v| v.starts_with(&prefix)).count();
+            push_probe(
+                &mut probes,
+                "prefix",
+                "prefix abbreviation",
+                prefix,
+                rules.allow_prefix && matches == 1,
+            );
+        }
+    }
+
+    push_probe(&mut probes, "empty", "empty value", String::new(), false);
+    if let Some(base) = values.first() {
+        push_probe(
+            &mut probes,
+            "padded",
+            "whitespace-padded value",
+            format!(" {} ", base),
+            false,
+        );
+    }
+
+    probes
+}
+
+/// An invocation passing one value to a typed option
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ValueCase {
+    /// Stable test ID (`<group>-<path>-<option>-<kind>`)
+    pub id: String,
+
+    /// Human-readable test name
+    pub name: String,
+
+    /// Arguments after the binary, subcommand path included
+    pub args: Vec<String>,
+
+    /// Option the value is passed to
+    pub option: String,
+
+    /// Value under test
+    pub value: String,
+
+    /// Whether the value must be accepted
+    pub accepted: bool,
+
+    /// Whether acceptance only means getting past the option parser: subcommands
+    /// run with placeholder positionals and may still fail for other reasons
+    pub parser_check: bool,
+}
+
+/// Boundary-value invocations for the numeric options of every command
+pub(crate) fn boundary_cases(analysis: &CliAnalysis) -> Vec<ValueCase> {
+    value_cases(analysis, "boundary", |option| {
+        numeric_range(option)
+            .map(boundary_values)
+            .unwrap_or_default()
+    })
+}
+
+/// Declared values and their variants for the enum options of every command
+pub(crate) fn enum_cases(analysis: &CliAnalysis) -> Vec<ValueCase> {
+    value_cases(analysis, "enum", |option| {
+        match (&option.option_type, enum_rules(option)) {
+            (OptionType::Enum { values }, Some(rules)) => enum_values(values, rules),
+            _ => Vec::new(),
+        }
+    })
+}
+
+/// Invocations passing each probe value of every option, command by command
+///
+/// Destructive subcommands are skipped: an accepted value would carry out the
+/// operation.
+fn value_cases(
+    analysis: &CliAnalysis,
+    group: &str,
+    probes: impl Fn(&CliOption) -> Vec<ProbeValue>,
+) -> Vec<ValueCase> {
+    let mut cases = Vec::new();
+    let scopes = subcommand_scopes(analysis);
+    let commands = std::iter::once((None, &analysis.global_options)).chain(
+        scopes
+            .iter()
+            .filter(|scope| !scope.destructive)
+            .map(|scope| (Some(scope), &scope.subcommand.options)),
+    );
+
+    for (scope, options) in commands {
+        let label = scope.map(CommandScope::label).unwrap_or_default();
+        let slug = std::iter::once(group.to_string())
+            .chain(scope.map(CommandScope::slug))
+            .collect::<Vec<_>>()
+            .join("-");
+        let path = scope.map(|scope| scope.path.clone()).unwrap_or_default();
+        let required_values = scope.map(CommandScope::required_values).unwrap_or_default();
+
+        for option in options {
+            let Some(flag) = option.long.as_ref().or(option.short.as_ref()) else {
+                continue;
+            };
+            for probe in probes(option) {
+                let mut args = path.clone();
+                args.extend([flag.clone(), probe.value.clone()]);
+                args.extend(required_values.iter().cloned());
+                cases.push(ValueCase {
+                    id: format!("{}-{}-{}", slug, slugify(flag), probe.kind),
+                    name: probe.name(flag, &label),
+                    args,
+                    option: flag.clone(),
+                    accepted: probe.accepted,
+                    parser_check: probe.accepted && scope.is_some(),
+                    value: probe.value,
+                });
+            }
+        }
+    }
+    cases
+}
+
+/// Exit codes accepted when the analysis documents none: success, general and usage errors
+const DEFAULT_EXIT_CODES: &[i32] = &[0, 1, 2];
+
+/// Message Rust prints when a thread panics
+pub(crate) const PANIC_MARKER: &str = "panicked at";
+
+/// Upper bound on pairwise invocations per command
+const MAX_PAIRWISE_ROWS: usize = 50;
+
+/// Numeric options with at most this many accepted values are combined value by value
+const SMALL_NUMERIC_DOMAIN: i64 = 5;
+
+/// Exit codes a combination may end with: the documented exit statuses, else 0-2
+pub(crate) fn allowed_exit_codes(analysis: &CliAnalysis) -> Vec<i32> {
+    let mut codes: Vec<i32> = analysis
+        .exit_statuses
+        .iter()
+        .map(|status| status.code)
+        .collect();
+    if codes.is_empty() {
+        codes = DEFAULT_EXIT_CODES.to_vec();
+    }
+    codes.sort_unstable();
+    codes.dedup();
+    codes
+}
+
+/// One invocation of a pairwise covering array
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CombinationCase {
+    /// Stable test ID (`combinatorial-<path>-<row>`)
+    pub id: String,
+
+    /// Human-readable test name
+    pub name: String,
+
+    /// Arguments after the binary, subcommand path included
+    pub args: Vec<String>,
+}
+
+/// Pairwise combinations of the flags, enums and small numeric options of every command
+///
+/// Destructive subcommands are skipped. Commands with fewer than two such
+/// options produce no cases.
+pub(crate) fn combination_cases(analysis: &CliAnalysis) -> Vec<CombinationCase> {
+    let mut cases = Vec::new();
+    add_combination_cases(None, &analysis.global_options, &mut cases);
+    for scope in subcommand_scopes(analysis) {
+        if !scope.destructive {
+            add_combination_cases(Some(&scope), &scope.subcommand.options, &mut cases);
+        }
+    }
+    cases
+}
+
+fn add_combination_cases(
+    scope: Option<&CommandScope>,
+    options: &[CliOption],
+    cases: &mut Vec<CombinationCase>,
+) {
+    let parameters: Vec<(&str, Vec<Vec<String>>)> =
+        options.iter().filter_map(pairwise_levels).collect();
+    if parameters.len() < 2 {
+        return;
+    }
+
+    let label = scope.map(CommandScope::label).unwrap_or_default();
+    let slug = std::iter::once("combinatorial".to_string())
+        .chain(scope.map(CommandScope::slug))
+        .collect::<Vec<_>>()
+        .join("-");
+    let path = scope.map(|scope| scope.path.clone()).unwrap_or_default();
+    let required_values = scope.map(CommandScope::required_values).unwrap_or_default();
+
+    let domains: Vec<usize> = parameters.iter().map(|(_, levels)| levels.len()).collect();
+    let rows = pairwise_rows(&domains, MAX_PAIRWISE_ROWS);
+    let mut number = 0;
+    for row in rows {
+        let given: Vec<&str> = parameters
+            .iter()
+            .zip(&row)
+            .filter(|(_, &level)| level > 0)
+            .map(|((flag, _), _)| *flag)
+            .collect();
+        // The bare command is not a combination
+        if given.is_empty() {
+            continue;
+        }
+
+        let mut args = path.clone();
+        for ((_, levels), &level) in parameters.iter().zip(&row) {
+            args.extend(levels[level].iter().cloned());
+        }
+        args.extend(required_values.iter().cloned());
+
+        number += 1;
+        let options = match given.as_slice() {
+            [only] => only.to_string(),
+            [first, second] => format!("{} and {}", first, second),
+            [first, second, rest @ ..] => {
+                format!("{}, {} and {} more options", first, second, rest.len())
+            }
+            [] => unreachable!(),
+        };
+        cases.push(CombinationCase {
+            id: format!("{}-{:03}", slug, number),
+            name: format!("Combine {} without crashing{}", options, label),
+            args,
+        });
+    }
+}
+
+/// Flag and argument lists for each setting of an option; level 0 leaves it out
+///
+/// Flags are given or not, enums take each value, and numeric options with a
+/// small range each number in it. Help and version options end the command
+/// before other options matter, and string or path values are not enumerable.
+fn pairwise_levels(option: &CliOption) -> Option<(&str, Vec<Vec<String>>)> {
+    let flag = option.long.as_deref().or(option.short.as_deref())?;
+    if matches!(flag, "--help" | "--version" | "-h" | "-V") {
+        return None;
+    }
+
+    let values: Vec<String> = match &option.option_type {
+        OptionType::Flag => return Some((flag, vec![vec![], vec![flag.to_string()]])),
+        OptionType::Enum { values } => values.clone(),
+        OptionType::Numeric { .. } => {
+            let range = numeric_range(option)?;
+            // An overflowing span is far larger than the small domain
+            let span = range.max.checked_sub(range.min).unwrap_or(i64::MAX);
+            if span >= SMALL_NUMERIC_DOMAIN {
+                return None;
+            }
+            (range.min..=range.max).map(|n| n.to_string()).collect()
+        }
+        OptionType::String | OptionType::Path => return None,
+    };
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut levels = vec![vec![]];
+    levels.extend(
+        values
+            .into_iter()
+            .map(|value| vec![flag.to_string(), value]),
+    );
+    Some((flag, levels))
+}
+
+/// Rows of a pairwise covering array over parameters with `domains[i]` levels each
+///
+/// Every pair of levels of two parameters appears in at least one row (unless
+/// `max_rows` cuts the array short). Greedy: each row starts from the first
+/// uncovered pair and gives every other parameter the level that covers the
+/// most uncovered pairs with the parameters already set.
+fn pairwise_rows(domains: &[usize], max_rows: usize) -> Vec<Vec<usize>> {
+    let mut uncovered = std::collections::BTreeSet::new();
+    for i in 0..domains.len() {
+        for j in i + 1..domains.len() {
+            for a in 0..domains[i] {
+                for b in 0..domains[j] {
+                    uncovered.insert((i, a, j, b));
+                }
+            }
+        }
+    }
+    // Pair key with the lower parameter index first
+    let pair = |(k, v): (usize, usize), (l, w): (usize, usize)| {
+        if k < l {
+            (k, v, l, w)
+        } else {
+            (l, w, k, v)
+        }
+    };
+
+    let mut rows = Vec::new();
+    while let Some(&(i, a, j, b)) = uncovered.iter().next() {
+        if rows.len() == max_rows {
+            log::warn!(
+                "Pairwise coverage stopped at {} rows with {} pairs uncovered",
+                max_rows,
+                uncovered.len()
+            );
+            break;
+        }
+
+        let mut row: Vec<Option<usize>> = vec![None; domains.len()];
+        row[i] = Some(a);
+        row[j] = Some(b);
+        for k in 0..domains.len() {
+            if row[k].is_some() {
+                continue;
+            }
+            let gain = |v: usize| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(|(l, level)| level.map(|w| pair((k, v), (l, w))))
+                    .filter(|key| uncovered.contains(key))
+                    .count()
+            };
+            let best = (0..domains[k])
+                .max_by_key(|&v| (gain(v), std::cmp::Reverse(v)))
+                .unwrap_or(0);
+            row[k] = Some(best);
+        }
+
+        let row: Vec<usize> = row.into_iter().map(|level| level.unwrap_or(0)).collect();
+        for k in 0..row.len() {
+            for l in k + 1..row.len() {
+                uncovered.remove(&(k, row[k], l, row[l]));
+            }
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+/// Shell command running `$CLI_BINARY` with `args` (quoted as needed)
+pub(crate) fn command_line(args: &[String]) -> String {
+    let mut command = "\"$CLI_BINARY\"".to_string();
+    for arg in args {
+        command.push(' ');
+        command.push_str(&ShellDialect::Bash.quote(arg));
+    }
+    command
+}
+
+/// An invocation with a wrong set of positional arguments that must be rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PositionalCase {
+    /// Stable test ID (`positional-<path>-<kind>`)
+    pub id: String,
+
+    /// Human-readable test name
+    pub name: String,
+
+    /// Arguments after the binary, subcommand path included
+    pub args: Vec<String>,
+}
+
+/// Missing, too-many and wrong-type positional argument cases for every command
+///
+/// Destructive subcommands only get the missing-argument case, which cannot
+/// reach the operation itself.
+pub(crate) fn positional_cases(analysis: &CliAnalysis) -> Vec<PositionalCase> {
+    let mut cases = Vec::new();
+    add_positional_cases(&[], &analysis.positional_args, false, &mut cases);
+    for scope in subcommand_scopes(analysis) {
+        add_positional_cases(
+            &scope.path,
+            &scope.subcommand.positional_args,
+            scope.destructive,
+            &mut cases,
+        );
+    }
+    cases
+}
+
+fn add_positional_cases(
+    path: &[String],
+    positionals: &[PositionalArg],
+    destructive: bool,
+    cases: &mut Vec<PositionalCase>,
+) {
+    if positionals.is_empty() {
+        return;
+    }
+
+    let slug = std::iter::once("positional".to_string())
+        .chain(path.iter().map(|name| slugify(name)))
+        .collect::<Vec<_>>()
+        .join("-");
+    let command = if path.is_empty() {
+        "the command".to_string()
+    } else {
+        format!("subcommand {}", path.join(" "))
+    };
+    let with_values =
+        |values: Vec<String>| -> Vec<String> { path.iter().cloned().chain(values).collect() };
+
+    if positionals.iter().any(|arg| arg.required) {
+        cases.push(PositionalCase {
+            id: format!("{}-missing", slug),
+            name: format!("Reject {} without required arguments", command),
+            args: path.to_vec(),
+        });
+    }
+
+    if destructive {
+        return;
+    }
+
+    let valid: Vec<String> = positionals.iter().map(positional_value).collect();
+
+    if !positionals.iter().any(|arg| arg.variadic) {
+        let mut values = valid.clone();
+        values.push("unexpected-extra-arg".to_string());
+        cases.push(PositionalCase {
+            id: format!("{}-too-many", slug),
+            name: format!("Reject {} with too many arguments", command),
+            args: with_values(values),
+        });
+    }
+
+    for (index, arg) in positionals.iter().enumerate() {
+        let invalid = match &arg.arg_type {
+            OptionType::Numeric { .. } => "not-a-number",
+            OptionType::Enum { values } if !values.is_empty() => "invalid-value-xyz",
+            _ => continue,
+        };
+        // The other positionals keep valid values so only this one can fail
+        let mut values = valid.clone();
+        values[index] = invalid.to_string();
+        cases.push(PositionalCase {
+            id: format!("{}-{}-type", slug, slugify(&arg.name)),
+            name: format!("Reject invalid {} for {}", arg.name, command),
+            args: with_values(values),
+        });
+    }
+}
+
+/// An invocation combining options in a way their relations forbid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RelationCase {
+    /// Stable test ID (`relation-<path>-<option>-<kind>-<other>`)
+    pub id: String,
+
+    /// Human-readable test name
+    pub name: String,
+
+    /// Arguments after the binary, subcommand path included
+    pub args: Vec<String>,
+
+    /// Missing required option the error message should name
+    pub requires: Option<String>,
+}
+
+/// Conflict, exclusive-group and requirement violations for every command
+///
+/// Destructive subcommands are skipped: a CLI that ignores the relation would
+/// carry out the operation.
+pub(crate) fn relation_cases(analysis: &CliAnalysis) -> Vec<RelationCase> {
+    let mut cases = Vec::new();
+    add_relation_cases(
+        &[],
+        &analysis.global_options,
+        &analysis.option_relations,
+        &analysis.positional_args,
+        &mut cases,
+    );
+    for scope in subcommand_scopes(analysis) {
+        if scope.destructive {
+            continue;
+        }
+        add_relation_cases(
+            &scope.path,
+            &scope.subcommand.options,
+            &scope.subcommand.option_relations,
+            &scope.subcommand.positional_args,
+            &mut cases,
+        );
+    }
+    cases
+}
+
+fn add_relation_cases(
+    path: &[String],
+    options: &[CliOption],
+    relations: &[OptionRelation],
+    positionals: &[PositionalArg],
+    cases: &mut Vec<RelationCase>,
+) {
+    let slug = std::iter::once("relation".to_string())
+        .chain(path.iter().map(|name| slugify(name)))
+        .collect::<Vec<_>>()
+        .join("-");
+    let suffix = if path.is_empty() {
+        String::new()
+    } else {
+        format!(" in subcommand {}", path.join(" "))
+    };
+    // Required positionals get valid values so only the relation can fail
+    let positional_values: Vec<String> = positionals
+        .iter()
+        .filter(|arg| arg.required)
+        .map(positional_value)
+        .collect();
+    let invocation = |flags: &[&str]| -> Option<Vec<String>> {
+        let mut args = path.to_vec();
+        for flag in flags {
+            args.extend(option_args(options, flag)?);
+        }
+        args.extend(positional_values.iter().cloned());
+        Some(args)
+    };
+
+    let push_conflict = |cases: &mut Vec<RelationCase>, a: &str, b: &str, kind: &str| {
+        if let Some(args) = invocation(&[a, b]) {
+            cases.push(RelationCase {
+                id: format!("{}-{}-{}-{}", slug, slugify(a), kind, slugify(b)),
+                name: format!("Reject {} together with {}{}", a, b, suffix),
+                args,
+                requires: None,
+            });
+        }
+    };
+
+    for relation in relations {
+        match relation {
+            OptionRelation::Conflicts { option, with } => {
+                push_conflict(cases, option, with, "conflicts");
+            }
+            OptionRelation::ExclusiveGroup { options: members } => {
+                for (index, a) in members.iter().enumerate() {
+                    for b in &members[index + 1..] {
+                        push_conflict(cases, a, b, "excludes");
+                    }
+                }
+            }
+            OptionRelation::Requires { option, requires } => {
+                if let Some(args) = invocation(&[option]) {
+                    cases.push(RelationCase {
+                        id: format!(
+                            "{}-{}-requires-{}",
+                            slug,
+                            slugify(option),
+                            slugify(requires)
+                        ),
+                        name: format!("Reject {} without {}{}", option, requires, suffix),
+                        args,
+                        requires: Some(requires.clone()),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// The flag (and a valid value unless it is a flag) for the option named `name`
+fn option_args(options: &[CliOption], name: &str) -> Option<Vec<String>> {
+    let option = options
+        .iter()
+        .find(|o| o.long.as_deref() == Some(name) || o.short.as_deref() == Some(name))?;
+    let mut args = vec![name.to_string()];
+    if option.option_type != OptionType::Flag {
+        args.push(valid_value(option)?);
+    }
+    Some(args)
+}
+
+/// A value the option accepts, preferring its default
+fn valid_value(option: &CliOption) -> Option<String> {
+    match &option.option_type {
+        OptionType::Flag => None,
+        OptionType::Numeric { min, max } => Some(
+            option
+                .default_value
+                .clone()
+                .filter(|v| v.parse::<i64>().is_ok())
+                .unwrap_or_else(|| clamp_number(10, *min, *max).to_string()),
+        ),
+        OptionType::Enum { values } => option
+            .default_value
+            .clone()
+            .filter(|v| values.contains(v))
+            .or_else(|| values.first().cloned()),
+        OptionType::Path | OptionType::String => Some(option.default_value.clone().unwrap_or_else(
+            || match option.option_type {
+                OptionType::Path => ".".to_string(),
+                _ => "test-value".to_string(),
+            },
+        )),
+    }
+}
+
+/// A value a positional argument should accept
+fn positional_value(arg: &PositionalArg) -> String {
+    match &arg.arg_type {
+        OptionType::Enum { values } if !values.is_empty() => values[0].clone(),
+        OptionType::Numeric { min, max } => clamp_number(10, *min, *max).to_string(),
+        OptionType::Path => ".".to_string(),
+        _ => "test-value".to_string(),
+    }
+}
+
+/// Lowercase a name and replace anything but ASCII alphanumerics with `-`
+pub(crate) fn slugify(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::analysis::OptionSource;
+    use crate::types::ExitStatus;
+    use std::path::PathBuf;
+
+    fn create_test_analysis() -> CliAnalysis {
+        let mut analysis = CliAnalysis::new(
+            PathBuf::from("/usr/bin/test-cli"),
+            "test-cli".to_string(),
+            "Test CLI help output".to_string(),
+        );
+
+        analysis.version = Some("1.0.0".to_string());
+
+        // Add a numeric option
+        analysis.global_options.push(CliOption {
+            short: Some("-t".to_string()),
+            long: Some("--timeout".to_string()),
+            description: Some("Timeout in seconds".to_string()),
+            option_type: OptionType::Numeric {
+                min: Some(0),
+                max: Some(3600),
+            },
+            required: false,
+            default_value: Some("30".to_string()),
+            env_var: None,
+            source: OptionSource::Help,
+        });
+
+        // Add a path option
+        analysis.global_options.push(CliOption {
+            short: Some("-f".to_string()),
+            long: Some("--file".to_string()),
+            description: Some("Input file".to_string()),
+            option_type: OptionType::Path,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        });
+
+        // Add an enum option
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--format".to_string()),
+            description: Some("Output format".to_string()),
+            option_type: OptionType::Enum {
+                values: vec!["json".to_string(), "yaml".to_string(), "text".to_string()],
+            },
+            required: false,
+            default_value: Some("text".to_string()),
+            env_var: None,
+            source: OptionSource::Help,
+        });
+
+        // Add a subcommand
+        analysis.subcommands.push(Subcommand {
+            name: "delete".to_string(),
+            description: Some("Delete resources".to_string()),
+            options: vec![CliOption {
+                short: None,
+                long: Some("--yes".to_string()),
+                description: Some("Skip confirmation".to_string()),
+                option_type: OptionType::Flag,
+                required: false,
+                default_value: None,
+                env_var: None,
+                source: OptionSource::Help,
+            }],
+            required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
+            subcommands: vec![],
+            depth: 0,
+        });
+
+        analysis
+    }
+
+    #[test]
+    fn test_generator_creation() {
+        let analysis = create_test_analysis();
+        let categories = vec![TestCategory::Basic];
+        let generator = TestGenerator::new(analysis, categories);
+
+        assert_eq!(generator.categories.len(), 1);
+    }
+
+    #[test]
+    fn test_generate_basic_tests() {
+        let analysis = create_test_analysis();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_basic_tests().unwrap();
+
+        assert!(!tests.is_empty());
+        assert!(tests.iter().any(|t| t.command.contains("--help")));
+        assert!(tests.iter().any(|t| t.command.contains("--version")));
+    }
+
+    #[test]
+    fn test_generate_security_tests() {
+        let analysis = create_test_analysis();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_security_tests().unwrap();
+
+        assert!(!tests.is_empty());
+        assert!(tests
+            .iter()
+            .any(|t| t.tags.contains(&"injection".to_string())));
+    }
+
+    #[test]
+    fn test_generate_input_validation_tests() {
+        let analysis = create_test_analysis();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_input_validation_tests().unwrap();
+
+        assert!(!tests.is_empty());
+        // Should have tests for numeric, path, and enum options
+        assert!(tests
+            .iter()
+            .any(|t| t.tags.contains(&"numeric".to_string())));
+        assert!(tests.iter().any(|t| t.tags.contains(&"enum".to_string())));
+    }
+
+    /// `remote add <NAME>` with a numeric, an enum and a string option
+    fn add_nested_subcommand(analysis: &mut CliAnalysis) {
+        let add = Subcommand {
+            name: "add".to_string(),
+            description: None,
+            options: analysis.global_options.clone(),
+            required_args: vec!["NAME".to_string()],
+            positional_args: vec![positional("NAME", true, false, OptionType::String)],
+            option_relations: vec![],
+            subcommands: vec![],
+            depth: 1,
+        };
+        analysis.subcommands.push(Subcommand {
+            name: "remote".to_string(),
+            description: None,
+            options: vec![],
+            required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
+            subcommands: vec![add],
+            depth: 0,
+        });
+    }
+
+    #[test]
+    fn test_generate_input_validation_tests_for_subcommands() {
+        let mut analysis = create_test_analysis();
+        add_nested_subcommand(&mut analysis);
+        // Typed options of destructive subcommands are not exercised
+        analysis.subcommands[0].options = analysis.global_options.clone();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_input_validation_tests().unwrap();
+        let find = |id: &str| tests.iter().find(|t| t.id == id);
+
+        // Global tests keep their numbered IDs
+        assert_eq!(
+            find("input-001-invalid").unwrap().command,
+            "\"$CLI_BINARY\" --timeout 'not-a-number'"
+        );
+
+        let invalid = find("input-remote-add-timeout-invalid").unwrap();
+        assert_eq!(
+            invalid.command,
+            "\"$CLI_BINARY\" remote add --timeout 'not-a-number' test-value"
+        );
+        assert_eq!(
+            invalid.name,
+            "Reject non-numeric value for --timeout in subcommand remote add"
+        );
+        assert_eq!(invalid.expected_exit, None);
+        assert!(find("input-remote-add-timeout-negative").is_some());
+
+        // Subcommand accept probes only check that the parser took the value
+        let valid = find("input-remote-add-timeout-valid").unwrap();
+        assert!(!valid.checks_expected_exit());
+        assert!(matches!(
+            valid.assertions.as_slice(),
+            [Assertion::ValueNotRejected { option, value }] if option == "--timeout" && value == "10"
+        ));
+        assert!(find("input-001-valid").unwrap().checks_expected_exit());
+        assert_eq!(
+            find("enum-remote-add-format-invalid").unwrap().command,
+            "\"$CLI_BINARY\" remote add --format 'invalid-value-xyz' test-value"
+        );
+        assert!(!tests.iter().any(|t| t.id.contains("delete")));
+    }
+
+    #[test]
+    fn test_generate_numeric_boundary_tests() {
+        let analysis = create_test_analysis();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_input_validation_tests().unwrap();
+        let boundaries: Vec<(&str, &str, Option<i32>)> = tests
+            .iter()
+            .filter(|t| t.tags.contains(&"boundary".to_string()))
+            .map(|t| {
+                (
+                    t.id.trim_start_matches("input-001-boundary-"),
+                    t.command.trim_start_matches("\"$CLI_BINARY\" --timeout "),
+                    t.expected_exit,
+                )
+            })
+            .collect();
+
+        // --timeout is 0..3600; zero duplicates the minimum
+        assert_eq!(
+            boundaries,
+            vec![
+                ("min", "0", Some(0)),
+                ("below-min", "-1", None),
+                ("max", "3600", Some(0)),
+                ("above-max", "3601", None),
+                ("overflow", "9223372036854775808", None),
+                ("float", "1.5", None),
+                ("hex", "0x10", None),
+                ("scientific", "1e3", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_valid_numeric_value_stays_in_range() {
+        let mut analysis = create_test_analysis();
+        // Click-style `[1<=x<=5]`: 10 would be above the maximum
+        analysis.global_options[0].option_type = OptionType::Numeric {
+            min: Some(1),
+            max: Some(5),
+        };
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_input_validation_tests().unwrap();
+        let find = |id: &str| tests.iter().find(|t| t.id == id).unwrap();
+
+        assert_eq!(
+            find("input-001-valid").command,
+            "\"$CLI_BINARY\" --timeout 5"
+        );
+        assert_eq!(
+            find("input-001-boundary-above-max").command,
+            "\"$CLI_BINARY\" --timeout 6"
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_value_tests() {
+        let analysis = create_test_analysis();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_input_validation_tests().unwrap();
+        let enum_tests: Vec<(&str, &str, Option<i32>)> = tests
+            .iter()
+            .filter(|t| t.tags.contains(&"option:--format".to_string()))
+            .map(|t| {
+                (
+                    t.id.trim_start_matches("enum-001-"),
+                    t.command.trim_start_matches("\"$CLI_BINARY\" --format "),
+                    t.expected_exit,
+                )
+            })
+            .collect();
+
+        // --format matches the case-insensitive `format` definition
+        assert_eq!(
+            enum_tests,
+            vec![
+                ("value-json", "json", Some(0)),
+                ("value-yaml", "yaml", Some(0)),
+                ("value-text", "text", Some(0)),
+                ("uppercase", "JSON", Some(0)),
+                ("mixed-case", "Json", Some(0)),
+                ("prefix", "js", None),
+                ("empty", "''", None),
+                ("padded", "' json '", None),
+                ("invalid", "'invalid-value-xyz'", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_values_case_sensitive_prefix() {
+        let values = vec!["debug".to_string(), "info".to_string()];
+        let probes = enum_values(
+            &values,
+            EnumRules {
+                case_sensitive: true,
+                allow_prefix: true,
+            },
+        );
+        let find = |kind: &str| probes.iter().find(|p| p.kind == kind).unwrap();
+
+        assert!(!find("uppercase").accepted);
+        assert!(!find("mixed-case").accepted);
+        assert_eq!(find("prefix").value, "deb");
+        assert!(find("prefix").accepted);
+        assert_eq!(find("padded").value, " debug ");
+    }
+
+    #[test]
+    fn test_pairwise_rows_cover_every_pair() {
+        let domains = [2, 2, 2, 2, 2, 2, 3, 4];
+        let rows = pairwise_rows(&domains, 100);
+
+        for i in 0..domains.len() {
+            for j in i + 1..domains.len() {
+                for a in 0..domains[i] {
+                    for b in 0..domains[j] {
+                        assert!(
+                            rows.iter().any(|row| row[i] == a && row[j] == b),
+                            "pair ({}={}, {}={}) not covered",
+                            i,
+                            a,
+                            j,
+                            b
+                        );
+                    }
+                }
+            }
+        }
+        // Far fewer rows than the 768 exhaustive combinations
+        assert!(rows.len() <= 16, "{} rows", rows.len());
+        assert_eq!(pairwise_rows(&domains, 3).len(), 3);
+    }
+
+    #[test]
+    fn test_pairwise_levels_skip_full_i64_range() {
+        let option = CliOption {
+            short: None,
+            long: Some("--offset".to_string()),
+            description: None,
+            option_type: OptionType::Numeric {
+                min: Some(i64::MIN),
+                max: Some(i64::MAX),
+            },
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        };
+
+        assert!(pairwise_levels(&option).is_none());
+    }
+
+    #[test]
+    fn test_generate_combinatorial_tests() {
+        let mut analysis = create_test_analysis();
+        let flag = |long: &str| CliOption {
+            short: None,
+            long: Some(long.to_string()),
+            description: None,
+            option_type: OptionType::Flag,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        };
+        analysis.global_options.push(flag("--verbose"));
+        analysis.global_options.push(flag("--help"));
+        analysis.global_options.push(CliOption {
+            long: Some("--level".to_string()),
+            option_type: OptionType::Numeric {
+                min: Some(1),
+                max: Some(3),
+            },
+            ..flag("--level")
+        });
+        add_nested_subcommand(&mut analysis);
+        let generator = TestGenerator::new(analysis, vec![TestCategory::Combinatorial]);
+
+        let tests = generator.generate().unwrap();
+
+        let global: Vec<&TestCase> = tests
+            .iter()
+            .filter(|t| t.id.starts_with("combinatorial-0"))
+            .collect();
+        assert_eq!(global[0].id, "combinatorial-001");
+        // --format (3 values), --verbose and --level (3 values); not --timeout or --help
+        for format in ["json", "yaml", "text"] {
+            for level in ["1", "2", "3"] {
+                assert!(global
+                    .iter()
+                    .any(|t| t.command.contains(&format!("--format {}", format))
+                        && t.command.contains(&format!("--level {}", level))));
+            }
+        }
+        assert!(!tests
+            .iter()
+            .any(|t| t.command.contains("--help") || t.command.contains("--timeout")));
+
+        let test = global[0];
+        assert_eq!(test.category, TestCategory::Combinatorial);
+        assert_eq!(test.allowed_exit_codes(), Some(&[0, 1, 2][..]));
+        assert!(test.tags.contains(&"pairwise".to_string()));
+        assert!(test.name.starts_with("Combine "));
+
+        // Subcommands are combined through their path, destructive ones skipped
+        let nested = tests
+            .iter()
+            .find(|t| t.id == "combinatorial-remote-add-001")
+            .unwrap();
+        assert!(nested.command.starts_with("\"$CLI_BINARY\" remote add "));
+        assert!(nested.command.ends_with(" test-value"));
+        assert!(nested.name.ends_with(" in subcommand remote add"));
+        assert!(!tests.iter().any(|t| t.id.contains("delete")));
+    }
+
+    #[test]
+    fn test_allowed_exit_codes_from_analysis() {
+        let mut analysis = create_test_analysis();
+        assert_eq!(allowed_exit_codes(&analysis), vec![0, 1, 2]);
+
+        analysis.exit_statuses = vec![
+            ExitStatus {
+                code: 2,
+                description: "if serious trouble".to_string(),
+            },
+            ExitStatus {
+                code: 0,
+                description: "if OK".to_string(),
+            },
+        ];
+        assert_eq!(allowed_exit_codes(&analysis), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_boundary_values_for_float_range() {
+        let values = boundary_values(NumericRange {
+            min: -5,
+            max: 100,
+            float: true,
+            explicit_max: true,
+        });
+        let find = |kind: &str| values.iter().find(|v| v.kind == kind).unwrap();
+
+        assert!(find("zero").accepted);
+        assert_eq!(find("float").value, "0.5");
+        assert!(find("float").accepted);
+        assert_eq!(find("scientific").value, "-5e0");
+        assert!(find("scientific").accepted);
+        assert!(!find("hex").accepted);
+        assert!(!find("below-min").accepted);
+    }
+
+    #[test]
+    fn test_default_constraint_range_has_no_above_max() {
+        let mut analysis = create_test_analysis();
+        // No parsed bounds and no matching constraint: only `default_constraints` apply
+        analysis.global_options[0].long = Some("--level".to_string());
+        analysis.global_options[0].option_type = OptionType::Numeric {
+            min: None,
+            max: None,
+        };
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_input_validation_tests().unwrap();
+        let ids: Vec<&str> = tests.iter().map(|t| t.id.as_str()).collect();
+
+        assert!(ids.contains(&"input-001-boundary-max"));
+        assert!(ids.contains(&"input-001-boundary-overflow"));
+        assert!(!ids.contains(&"input-001-boundary-above-max"));
+    }
+
+    #[test]
+    fn test_generate_security_tests_for_subcommands() {
+        let mut analysis = create_test_analysis();
+        add_nested_subcommand(&mut analysis);
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_security_tests().unwrap();
+        let ids: Vec<&str> = tests.iter().map(|t| t.id.as_str()).collect();
+
+        // `remote` has no string option; `delete` is destructive
+        assert_eq!(
+            ids,
+            vec![
+                "security-001",
+                "security-002",
+                "security-003",
+                "security-remote-add-injection",
+                "security-remote-add-null-byte",
+                "security-remote-add-path-traversal",
+            ]
+        );
+        assert_eq!(tests[0].command, "\"$CLI_BINARY\" --file 'test; rm -rf /'");
+        assert_eq!(
+            tests[3].command,
+            "\"$CLI_BINARY\" remote add --file 'test; rm -rf /' test-value"
+        );
+        assert_eq!(tests[3].expected_exit, None);
+    }
+
+    fn positional(
+        name: &str,
+        required: bool,
+        variadic: bool,
+        arg_type: OptionType,
+    ) -> PositionalArg {
+        PositionalArg {
+            name: name.to_string(),
+            index: 0,
+            required,
+            variadic,
+            arg_type,
+        }
+    }
+
+    #[test]
+    fn test_positional_cases() {
+        let mut analysis = create_test_analysis();
+        analysis.positional_args = vec![positional("FILE", false, true, OptionType::Path)];
+        analysis.subcommands[0].positional_args =
+            vec![positional("ID", true, false, OptionType::String)];
+        analysis.subcommands.push(Subcommand {
+            name: "scale".to_string(),
+            description: None,
+            options: vec![],
+            required_args: vec!["SERVICE".to_string(), "REPLICAS".to_string()],
+            positional_args: vec![
+                positional("SERVICE", true, false, OptionType::String),
+                positional(
+                    "REPLICAS",
+                    true,
+                    false,
+                    OptionType::Numeric {
+                        min: Some(1),
+                        max: Some(5),
+                    },
+                ),
+            ],
+            option_relations: vec![],
+            subcommands: vec![],
+            depth: 0,
+        });
+
+        let cases = positional_cases(&analysis);
+        let ids: Vec<&str> = cases.iter().map(|c| c.id.as_str()).collect();
+
+        // Root FILE... is optional and variadic: nothing to reject
+        // delete is destructive: only the missing-argument case
+        assert_eq!(
+            ids,
+            vec![
+                "positional-delete-missing",
+                "positional-scale-missing",
+                "positional-scale-too-many",
+                "positional-scale-replicas-type",
+            ]
+        );
+        assert_eq!(cases[0].args, vec!["delete"]);
+        assert_eq!(
+            cases[2].args,
+            vec!["scale", "test-value", "5", "unexpected-extra-arg"]
+        );
+        assert_eq!(cases[3].args, vec!["scale", "test-value", "not-a-number"]);
+
+        let generator = TestGenerator::new(analysis, vec![]);
+        let tests = generator.generate_input_validation_tests().unwrap();
+        let test = tests
+            .iter()
+            .find(|t| t.id == "positional-scale-replicas-type")
+            .unwrap();
+        assert_eq!(
+            test.command,
+            "\"$CLI_BINARY\" scale test-value not-a-number"
+        );
+        assert_eq!(test.expected_exit, None);
+        assert!(test.tags.contains(&"positional".to_string()));
+    }
+
+    #[test]
+    fn test_positional_type_case_keeps_later_arguments() {
+        let mut analysis = create_test_analysis();
+        analysis.positional_args = vec![
+            positional(
+                "COUNT",
+                true,
+                false,
+                OptionType::Numeric {
+                    min: None,
+                    max: None,
+                },
+            ),
+            positional("FILE", true, false, OptionType::Path),
+        ];
+
+        let cases = positional_cases(&analysis);
+        let case = cases
+            .iter()
+            .find(|c| c.id == "positional-count-type")
+            .unwrap();
+
+        // FILE is still given, so only the bad COUNT can be rejected
+        assert_eq!(case.args, vec!["not-a-number", "."]);
+    }
+
+    #[test]
+    fn test_generate_option_relation_tests() {
+        let mut analysis = create_test_analysis();
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--quiet".to_string()),
+            description: None,
+            option_type: OptionType::Flag,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        });
+        analysis.option_relations = vec![
+            OptionRelation::ExclusiveGroup {
+                options: vec!["--format".to_string(), "--quiet".to_string()],
+            },
+            OptionRelation::Requires {
+                option: "--timeout".to_string(),
+                requires: "--file".to_string(),
+            },
+            // Unknown options cannot be invoked
+            OptionRelation::Conflicts {
+                option: "--quiet".to_string(),
+                with: "--missing".to_string(),
+            },
+        ];
+        // Relations of destructive subcommands are not exercised
+        analysis.subcommands[0].option_relations = vec![OptionRelation::Requires {
+            option: "--yes".to_string(),
+            requires: "--force".to_string(),
+        }];
+        analysis.positional_args = vec![positional("NAME", true, false, OptionType::String)];
+
+        let generator = TestGenerator::new(analysis, vec![TestCategory::OptionRelations]);
+        let tests = generator.generate().unwrap();
+
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[0].id, "relation-format-excludes-quiet");
+        assert_eq!(
+            tests[0].command,
+            "\"$CLI_BINARY\" --format text --quiet test-value"
+        );
+        assert_eq!(tests[0].expected_exit, None);
+        assert!(tests[0].tags.contains(&"conflict".to_string()));
+
+        assert_eq!(tests[1].id, "relation-timeout-requires-file");
+        assert_eq!(tests[1].command, "\"$CLI_BINARY\" --timeout 30 test-value");
+        assert!(matches!(
+            &tests[1].assertions[..],
+            [Assertion::OutputContains(text)] if text == "--file"
+        ));
+    }
+
+    #[test]
+    fn test_generate_destructive_ops_tests() {
+        let analysis = create_test_analysis();
+        let generator = TestGenerator::new(analysis, vec![]);
+
+        let tests = generator.generate_destructive_ops_tests().unwrap();
+
+        assert!(!tests.is_empty());
+        assert!(tests.iter().any(|t| t.command.contains("delete")));
+    }
+
+    #[test]
     fn test_generate_all_categories() {
         let analysis = create_test_analysis();
         let categories = vec![
@@ -1149,4 +3085,294 @@ mod tests {
 
         assert!(!tests.is_empty());
     }
+
+    #[test]
+    fn test_timeout_overrides_from_config() {
+        use crate::types::config::{CiSettings, GlobalSettings, TestAdjustments};
+
+        let mut global = GlobalSettings::default();
+        global.test_timeouts.insert("basic-001".to_string(), 120);
+
+        let mut generator = TestGenerator::new(create_test_analysis(), vec![TestCategory::Basic]);
+        generator.config = Some(CliTestConfig {
+            version: "1.0".to_string(),
+            tool_name: "test-cli".to_string(),
+            tool_version: None,
+            test_adjustments: TestAdjustments::default(),
+            global,
+            ci: CiSettings::default(),
+        });
+
+        for tests in [
+            generator.generate().unwrap(),
+            generator.generate_parallel().unwrap(),
+        ] {
+            let overridden = tests.iter().find(|t| t.id == "basic-001").unwrap();
+            assert_eq!(overridden.timeout, Some(120));
+            assert!(tests
+                .iter()
+                .filter(|t| t.id != "basic-001")
+                .all(|t| t.timeout.is_none()));
+        }
+    }
+
+    #[test]
+    fn test_multi_shell_default_shells() {
+        let generator = TestGenerator::new(create_test_analysis(), vec![TestCategory::MultiShell]);
+        let tests = generator.generate().unwrap();
+
+        let ids: Vec<&str> = tests.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["multi-shell-bash", "multi-shell-zsh", "multi-shell-sh"]
+        );
+        assert_eq!(tests[0].command, "bash -c '\"$CLI_BINARY\" --help'");
+        assert_eq!(tests[0].requires, vec!["bash"]);
+    }
+
+    #[test]
+    fn test_multi_shell_from_config() {
+        use crate::types::config::{
+            CiSettings, GlobalSettings, MultiShellAdjustments, TestAdjustments,
+        };
+
+        let mut multi_shell = MultiShellAdjustments {
+            shells: vec![
+                "bash".to_string(),
+                "fish".to_string(),
+                "busybox".to_string(),
+                "tcsh".to_string(),
+            ],
+            ..Default::default()
+        };
+        multi_shell
+            .bash_env
+            .insert("APP_SHELL".to_string(), "bash".to_string());
+        multi_shell.shell_env.insert(
+            "fish".to_string(),
+            [("APP_SHELL".to_string(), "fish".to_string())].into(),
+        );
+
+        let mut generator =
+            TestGenerator::new(create_test_analysis(), vec![TestCategory::MultiShell]);
+        generator.config = Some(CliTestConfig {
+            version: "1.0".to_string(),
+            tool_name: "test-cli".to_string(),
+            tool_version: None,
+            test_adjustments: TestAdjustments {
+                multi_shell: Some(multi_shell),
+                ..Default::default()
+            },
+            global: GlobalSettings::default(),
+            ci: CiSettings::default(),
+        });
+
+        let tests = generator.generate().unwrap();
+
+        // Unsupported shells (tcsh) are dropped
+        assert_eq!(tests.len(), 3);
+        assert_eq!(
+            tests[0].command,
+            "env APP_SHELL='bash' bash -c '\"$CLI_BINARY\" --help'"
+        );
+        assert_eq!(
+            tests[1].command,
+            "env APP_SHELL='fish' fish -c '$CLI_BINARY --help'"
+        );
+        assert_eq!(tests[2].command, "busybox sh -c '\"$CLI_BINARY\" --help'");
+        assert_eq!(tests[2].requires, vec!["busybox"]);
+    }
+
+    #[test]
+    fn test_performance_budgets_from_config() {
+        use crate::types::config::{
+            CiSettings, GlobalSettings, PerformanceAdjustments, TestAdjustments,
+        };
+
+        let mut generator =
+            TestGenerator::new(create_test_analysis(), vec![TestCategory::Performance]);
+
+        // Defaults without config
+        let tests = generator.generate().unwrap();
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[0].budget.as_ref().unwrap().max_startup_ms, Some(100));
+        assert_eq!(tests[1].budget.as_ref().unwrap().max_memory_mb, Some(100));
+
+        generator.config = Some(CliTestConfig {
+            version: "1.0".to_string(),
+            tool_name: "test-cli".to_string(),
+            tool_version: None,
+            test_adjustments: TestAdjustments {
+                performance: Some(PerformanceAdjustments {
+                    max_startup_time: Some(250),
+                    max_memory_mb: Some(64),
+                    skip_in_ci: true,
+                }),
+                ..Default::default()
+            },
+            global: GlobalSettings::default(),
+            ci: CiSettings::default(),
+        });
+
+        let tests = generator.generate().unwrap();
+        assert_eq!(tests[0].name, "Startup time for --help < 250ms");
+        assert_eq!(
+            tests[0].budget,
+            Some(PerformanceBudget {
+                iterations: BENCHMARK_ITERATIONS,
+                max_startup_ms: Some(250),
+                max_memory_mb: None,
+                skip_in_ci: true,
+            })
+        );
+        assert_eq!(tests[1].name, "Memory usage for --help < 64MB");
+        assert_eq!(tests[1].budget.as_ref().unwrap().max_memory_mb, Some(64));
+    }
+
+    #[test]
+    fn test_environment_tests() {
+        let mut analysis = create_test_analysis();
+        analysis.global_options[0].env_var = Some("TEST_CLI_TIMEOUT".to_string());
+        analysis.global_options.push(CliOption {
+            short: None,
+            long: Some("--no-color".to_string()),
+            description: Some("Disable colors".to_string()),
+            option_type: OptionType::Flag,
+            required: false,
+            default_value: None,
+            env_var: Some("NO_COLOR".to_string()),
+            source: OptionSource::Help,
+        });
+
+        let generator = TestGenerator::new(analysis, vec![TestCategory::Environment]);
+        let tests = generator.generate().unwrap();
+
+        let ids: Vec<&str> = tests.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "env-empty",
+                "env-001-flag",
+                "env-001-invalid",
+                "env-002-flag"
+            ]
+        );
+        assert_eq!(tests[0].command, "env -i \"$CLI_BINARY\" --help");
+
+        // Numeric option: a non-default value (default 30) through the variable vs. the flag
+        assert_eq!(tests[1].command, "env TEST_CLI_TIMEOUT=10 \"$CLI_BINARY\"");
+        assert!(matches!(
+            &tests[1].assertions[..],
+            [Assertion::SameOutcomeAs(cmd)] if cmd == "\"$CLI_BINARY\" --timeout 10"
+        ));
+        // The flag's exit code is the expectation, not a fixed 0
+        assert!(!tests[1].checks_expected_exit());
+
+        // Invalid numeric value is rejected
+        assert_eq!(tests[2].expected_exit, None);
+        assert!(tests[2].assertions.is_empty());
+        assert_eq!(
+            tests[2].command,
+            "env TEST_CLI_TIMEOUT=not-a-number \"$CLI_BINARY\""
+        );
+
+        // Flag: the variable is set to 1
+        assert_eq!(tests[3].command, "env NO_COLOR=1 \"$CLI_BINARY\"");
+        assert!(matches!(
+            &tests[3].assertions[..],
+            [Assertion::SameOutcomeAs(cmd)] if cmd == "\"$CLI_BINARY\" --no-color"
+        ));
+    }
+
+    #[test]
+    fn test_environment_tests_for_subcommand_options() {
+        let mut analysis = create_test_analysis();
+        add_nested_subcommand(&mut analysis);
+        let remote = analysis
+            .subcommands
+            .iter_mut()
+            .find(|s| s.name == "remote")
+            .unwrap();
+        remote.subcommands[0].options.push(CliOption {
+            short: None,
+            long: Some("--protocol".to_string()),
+            description: None,
+            option_type: OptionType::Enum {
+                values: vec!["https".to_string(), "ssh".to_string()],
+            },
+            required: false,
+            default_value: Some("https".to_string()),
+            env_var: Some("REMOTE_PROTOCOL".to_string()),
+            source: OptionSource::Help,
+        });
+
+        let generator = TestGenerator::new(analysis, vec![TestCategory::Environment]);
+        let tests = generator.generate().unwrap();
+
+        // Run through the owning subcommand, with a non-default value
+        let flag = tests
+            .iter()
+            .find(|t| t.id == "env-remote-add-remote-protocol-flag")
+            .unwrap();
+        assert_eq!(
+            flag.command,
+            "env REMOTE_PROTOCOL=ssh \"$CLI_BINARY\" remote add test-value"
+        );
+        assert!(matches!(
+            &flag.assertions[..],
+            [Assertion::SameOutcomeAs(cmd)]
+                if cmd == "\"$CLI_BINARY\" remote add --protocol ssh test-value"
+        ));
+        assert_eq!(
+            flag.name,
+            "REMOTE_PROTOCOL has the same effect as --protocol in subcommand remote add"
+        );
+        assert!(tests
+            .iter()
+            .any(|t| t.id == "env-remote-add-remote-protocol-invalid"));
+    }
+
+    #[test]
+    fn test_env_var_values_avoid_default() {
+        let option = |option_type: OptionType, default: Option<&str>| CliOption {
+            short: None,
+            long: Some("--value".to_string()),
+            description: None,
+            option_type,
+            required: false,
+            default_value: default.map(str::to_string),
+            env_var: None,
+            source: OptionSource::Help,
+        };
+        let numeric = |min, max| OptionType::Numeric { min, max };
+
+        assert_eq!(
+            env_var_values(&option(numeric(Some(0), Some(100)), Some("10"))).0,
+            Some("11".to_string())
+        );
+        assert_eq!(
+            env_var_values(&option(numeric(Some(1), Some(5)), Some("5"))).0,
+            Some("4".to_string())
+        );
+        // The default is the only valid value
+        assert_eq!(
+            env_var_values(&option(numeric(Some(3), Some(3)), Some("3"))).0,
+            None
+        );
+        let choice = OptionType::Enum {
+            values: vec!["auto".to_string(), "never".to_string()],
+        };
+        assert_eq!(
+            env_var_values(&option(choice, Some("auto"))).0,
+            Some("never".to_string())
+        );
+        assert_eq!(
+            env_var_values(&option(OptionType::Path, Some("."))).0,
+            Some("/tmp".to_string())
+        );
+        assert_eq!(
+            env_var_values(&option(OptionType::String, Some("test-value"))).0,
+            Some("other-value".to_string())
+        );
+    }
 }
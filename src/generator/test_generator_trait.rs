@@ -1,13 +1,31 @@
-use crate::error::Result;
+use crate::error::{CliTestError, Result};
 use crate::types::analysis::CliAnalysis;
 use crate::types::test_case::TestCategory;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Tests generated for one category by [`TestGenerator::generate_categories`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedTests {
+    /// Category the tests belong to
+    pub category: TestCategory,
+
+    /// Generated test code
+    pub code: String,
+
+    /// Number of tests in `code` (`None` if the generator does not count them)
+    pub test_count: Option<usize>,
+}
 
 /// Common interface for all test generators
 ///
 /// This trait provides a unified interface for generating tests in different formats:
 /// - BatsGenerator: Generates BATS shell test scripts
 /// - AssertCmdGenerator: Generates Rust tests using assert_cmd crate
-/// - SnapboxGenerator: Generates snapshot tests (future implementation)
+/// - SnapboxGenerator: Generates Rust snapshot tests using snapbox crate
+///
+/// Additional formats can be plugged in at runtime with [`register_generator`].
 ///
 /// Design rationale:
 /// - Maintainability: Single source of truth for test generation logic
@@ -28,7 +46,7 @@ pub trait TestGenerator {
     /// # Example
     ///
     /// ```ignore
-    /// let generator = AssertCmdGenerator::new()?;
+    /// let generator = AssertCmdGenerator::new(&analysis)?;
     /// let test_code = generator.generate(&analysis, TestCategory::Basic)?;
     /// ```
     fn generate(&self, analysis: &CliAnalysis, category: TestCategory) -> Result<String>;
@@ -56,6 +74,34 @@ pub trait TestGenerator {
         Ok(results)
     }
 
+    /// Generate tests for several categories in one pass
+    ///
+    /// Categories that produce no tests are left out. The default calls
+    /// [`generate`](Self::generate) per category; generators that build all test
+    /// cases up front override it to share that work across categories.
+    fn generate_categories(
+        &self,
+        analysis: &CliAnalysis,
+        categories: &[TestCategory],
+    ) -> Result<Vec<GeneratedTests>> {
+        let mut results = Vec::new();
+
+        for &category in categories {
+            let code = self.generate(analysis, category)?;
+            if code.trim().is_empty() {
+                log::debug!("No tests generated for category: {}", category.as_str());
+                continue;
+            }
+            results.push(GeneratedTests {
+                category,
+                code,
+                test_count: None,
+            });
+        }
+
+        Ok(results)
+    }
+
     /// Get the file extension for generated test files
     ///
     /// # Returns
@@ -71,11 +117,94 @@ pub trait TestGenerator {
     fn name(&self) -> &str;
 }
 
+/// Constructor for a registered test generator
+pub type GeneratorFactory = Arc<dyn Fn() -> Result<Box<dyn TestGenerator>> + Send + Sync>;
+
+lazy_static! {
+    /// Generator registry (format name -> factory), seeded with the built-in formats
+    static ref GENERATOR_REGISTRY: RwLock<HashMap<String, GeneratorFactory>> = {
+        let mut registry: HashMap<String, GeneratorFactory> = HashMap::new();
+
+        registry.insert(
+            "bats".to_string(),
+            Arc::new(|| {
+                Ok(Box::new(crate::generator::bats_generator::BatsGenerator::new())
+                    as Box<dyn TestGenerator>)
+            }),
+        );
+        registry.insert(
+            "assert_cmd".to_string(),
+            Arc::new(|| {
+                Ok(Box::new(crate::generator::assert_cmd_generator::AssertCmdGenerator::from_analyses()?)
+                    as Box<dyn TestGenerator>)
+            }),
+        );
+        registry.insert(
+            "snapbox".to_string(),
+            Arc::new(|| {
+                Ok(Box::new(crate::generator::snapbox_generator::SnapboxGenerator::new()?)
+                    as Box<dyn TestGenerator>)
+            }),
+        );
+
+        RwLock::new(registry)
+    };
+}
+
+/// Normalize a format name for registry lookup ("Assert-Cmd" -> "assert_cmd")
+fn normalize_format(format: &str) -> String {
+    format.trim().to_lowercase().replace('-', "_")
+}
+
+/// Register a test generator under a format name
+///
+/// Registering an existing name replaces the previous factory, which allows
+/// embedding tools to override built-in formats.
+///
+/// # Arguments
+///
+/// * `format` - Format name used with [`create_generator`] (case-insensitive, `-` and `_` are equivalent)
+/// * `factory` - Closure constructing a new generator instance
+///
+/// # Example
+///
+/// ```ignore
+/// register_generator("pytest", || Ok(Box::new(PytestGenerator::new())));
+/// let generator = create_generator("pytest")?;
+/// ```
+pub fn register_generator<F>(format: &str, factory: F)
+where
+    F: Fn() -> Result<Box<dyn TestGenerator>> + Send + Sync + 'static,
+{
+    let name = normalize_format(format);
+    log::debug!("Registering test generator: {}", name);
+
+    GENERATOR_REGISTRY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name, Arc::new(factory));
+}
+
+/// List registered generator format names (sorted)
+pub fn registered_generators() -> Vec<String> {
+    let mut names: Vec<String> = GENERATOR_REGISTRY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .keys()
+        .cloned()
+        .collect();
+    names.sort();
+    names
+}
+
 /// Factory function to create a test generator by name
 ///
+/// Looks up the generator registry, which contains the built-in formats
+/// ("bats", "assert_cmd", "snapbox") and any format added via [`register_generator`].
+///
 /// # Arguments
 ///
-/// * `format` - Generator format name ("bats", "assert_cmd", "snapbox")
+/// * `format` - Generator format name ("bats", "assert_cmd", "snapbox", or a registered name)
 ///
 /// # Returns
 ///
@@ -88,28 +217,21 @@ pub trait TestGenerator {
 /// let test_code = generator.generate(&analysis, TestCategory::Security)?;
 /// ```
 pub fn create_generator(format: &str) -> Result<Box<dyn TestGenerator>> {
-    match format.to_lowercase().as_str() {
-        "bats" => {
-            // Use existing BatsGenerator (to be refactored to implement TestGenerator)
-            Err(crate::error::CliTestError::InvalidFormat(
-                "BATS generator not yet refactored to use TestGenerator trait".to_string(),
-            ))
-        }
-        "assert_cmd" | "assert-cmd" => {
-            // Create AssertCmdGenerator
-            Err(crate::error::CliTestError::InvalidFormat(
-                "AssertCmdGenerator not yet implemented".to_string(),
-            ))
-        }
-        "snapbox" => {
-            // Future implementation
-            Err(crate::error::CliTestError::InvalidFormat(
-                "Snapbox generator not yet implemented".to_string(),
-            ))
-        }
-        _ => Err(crate::error::CliTestError::InvalidFormat(format!(
-            "Unknown generator format: {}",
-            format
+    let name = normalize_format(format);
+
+    // Clone the factory out of the lock so factories may use the registry themselves
+    let factory = GENERATOR_REGISTRY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(&name)
+        .cloned();
+
+    match factory {
+        Some(factory) => factory(),
+        None => Err(CliTestError::InvalidFormat(format!(
+            "Unknown generator format: {} (available: {})",
+            format,
+            registered_generators().join(", ")
         ))),
     }
 }
@@ -118,6 +240,22 @@ pub fn create_generator(format: &str) -> Result<Box<dyn TestGenerator>> {
 mod tests {
     use super::*;
 
+    struct EchoGenerator;
+
+    impl TestGenerator for EchoGenerator {
+        fn generate(&self, analysis: &CliAnalysis, category: TestCategory) -> Result<String> {
+            Ok(format!("{} {}", analysis.binary_name, category.as_str()))
+        }
+
+        fn file_extension(&self) -> &str {
+            "txt"
+        }
+
+        fn name(&self) -> &str {
+            "echo"
+        }
+    }
+
     #[test]
     fn test_create_generator_invalid_format() {
         let result = create_generator("invalid");
@@ -125,10 +263,61 @@ mod tests {
     }
 
     #[test]
-    fn test_create_generator_not_implemented() {
-        // These should fail until implemented
-        assert!(create_generator("bats").is_err());
-        assert!(create_generator("assert_cmd").is_err());
-        assert!(create_generator("snapbox").is_err());
+    fn test_create_generator_builtin_formats() {
+        assert_eq!(create_generator("bats").unwrap().name(), "bats");
+        assert_eq!(create_generator("assert_cmd").unwrap().name(), "assert_cmd");
+        assert_eq!(create_generator("assert-cmd").unwrap().name(), "assert_cmd");
+        assert_eq!(create_generator("BATS").unwrap().file_extension(), "bats");
+    }
+
+    #[test]
+    fn test_create_generator_snapbox() {
+        let generator = create_generator("snapbox").unwrap();
+        assert_eq!(generator.name(), "snapbox");
+        assert_eq!(generator.file_extension(), "rs");
+    }
+
+    #[test]
+    fn test_register_custom_generator() {
+        register_generator("Echo-Format", || Ok(Box::new(EchoGenerator)));
+
+        assert!(registered_generators().contains(&"echo_format".to_string()));
+
+        let generator = create_generator("echo_format").unwrap();
+        let analysis = CliAnalysis::new(
+            std::path::PathBuf::from("/usr/bin/tool"),
+            "tool".to_string(),
+            String::new(),
+        );
+        assert_eq!(generator.name(), "echo");
+        assert_eq!(
+            generator.generate(&analysis, TestCategory::Basic).unwrap(),
+            "tool basic"
+        );
+    }
+
+    #[test]
+    fn test_generate_categories_default() {
+        let analysis = CliAnalysis::new(
+            std::path::PathBuf::from("/usr/bin/tool"),
+            "tool".to_string(),
+            String::new(),
+        );
+
+        let generated = EchoGenerator
+            .generate_categories(&analysis, &[TestCategory::Basic, TestCategory::Help])
+            .unwrap();
+        assert_eq!(generated.len(), 2);
+        assert_eq!(generated[1].category, TestCategory::Help);
+        assert_eq!(generated[1].code, "tool help");
+        assert_eq!(generated[1].test_count, None);
+    }
+
+    #[test]
+    fn test_registered_generators_includes_builtins() {
+        let names = registered_generators();
+        assert!(names.contains(&"bats".to_string()));
+        assert!(names.contains(&"assert_cmd".to_string()));
+        assert!(names.contains(&"snapbox".to_string()));
     }
 }
@@ -1,19 +1,21 @@
 use clap::{CommandFactory, Parser};
 use clap_complete::{generate, Shell};
-use cli_testing_specialist::analyzer::CliParser;
-use cli_testing_specialist::cli::{Cli, Commands, ReportFormat, TestFormat};
+use cli_testing_specialist::analyzer::{validate_analysis, CliParser};
+use cli_testing_specialist::cli::{Cli, Commands, ReportFormat, Runner, TestFormat};
+use cli_testing_specialist::config::load_config;
 use cli_testing_specialist::error::Result;
-use cli_testing_specialist::generator::{
-    AssertCmdGenerator, BatsWriter, TestGenerator, TestGeneratorTrait,
-};
+use cli_testing_specialist::generator::{create_generator, TestGenerator};
 use cli_testing_specialist::reporter::{
-    HtmlReporter, JsonReporter, JunitReporter, MarkdownReporter,
+    HtmlReporter, JsonReporter, JunitReporter, MarkdownReporter, SarifReporter,
+};
+use cli_testing_specialist::runner::{
+    compare_with_history, load_corpus, BatsExecutor, Fuzzer, NativeExecutor, PerformanceHistory,
 };
-use cli_testing_specialist::runner::BatsExecutor;
-use cli_testing_specialist::types::{CliAnalysis, TestCategory};
-use cli_testing_specialist::utils::validate_binary_path;
+use cli_testing_specialist::types::{CliAnalysis, OptionSource, Subcommand, TestCategory};
+use cli_testing_specialist::utils::{validate_binary_path, ResourceLimits};
 use std::fs;
 use std::io;
+use std::time::Duration;
 
 fn main() -> Result<()> {
     // Parse command-line arguments
@@ -34,17 +36,33 @@ fn main() -> Result<()> {
     match cli.command {
         Commands::Analyze {
             binary,
+            from_help_dir,
             output,
             depth: _,
             parallel: _,
+            completions,
         } => {
-            // 1. Validate binary path
-            let binary_path = validate_binary_path(&binary)?;
-            log::info!("Analyzing binary: {}", binary_path.display());
+            let parser = CliParser::new().with_completions(completions);
+            let analysis = match from_help_dir {
+                // 1-2. Build analysis from captured help text (the binary is not executed,
+                //      but generated tests run it, so its path is recorded)
+                Some(help_dir) => {
+                    let mut analysis = parser.analyze_help_dir(&help_dir)?;
+                    if let Some(name) = binary.file_name().and_then(|n| n.to_str()) {
+                        analysis.binary_name = name.to_string();
+                    }
+                    analysis.binary_path = binary;
+                    analysis
+                }
+                None => {
+                    // 1. Validate binary path
+                    let binary_path = validate_binary_path(&binary)?;
+                    log::info!("Analyzing binary: {}", binary_path.display());
 
-            // 2. Execute analysis with CliParser
-            let parser = CliParser::new();
-            let analysis = parser.analyze(&binary_path)?;
+                    // 2. Execute analysis with CliParser
+                    parser.analyze(&binary_path)?
+                }
+            };
 
             log::info!(
                 "Analysis complete: {} global options, {} subcommands",
@@ -63,7 +81,29 @@ fn main() -> Result<()> {
             if let Some(version) = &analysis.version {
                 println!("  Version: {}", version);
             }
+            println!("  Framework: {}", analysis.framework);
             println!("  Global options: {}", analysis.global_options.len());
+            let man_page_options = count_options_from(&analysis, OptionSource::ManPage);
+            if man_page_options > 0 {
+                println!("  Options from man page: {}", man_page_options);
+            }
+            let undocumented = count_options_from(&analysis, OptionSource::Completion);
+            if undocumented > 0 {
+                println!("  Undocumented options (completion only): {}", undocumented);
+            }
+            if !analysis.environment_variables.is_empty() {
+                println!(
+                    "  Environment variables: {}",
+                    analysis.environment_variables.len()
+                );
+            }
+            if !analysis.exit_statuses.is_empty() {
+                println!("  Exit statuses: {}", analysis.exit_statuses.len());
+            }
+            let relations = count_option_relations(&analysis);
+            if relations > 0 {
+                println!("  Option relations: {}", relations);
+            }
             println!("  Subcommands: {}", analysis.subcommands.len());
             println!(
                 "  Analysis time: {}ms",
@@ -105,145 +145,177 @@ fn main() -> Result<()> {
                 );
             }
 
-            match format {
-                TestFormat::Bats => {
-                    // 3. Generate test cases (BATS) with config support and automatic strategy selection
-                    let generator = TestGenerator::with_config(
-                        cli_analysis.clone(),
-                        selected_categories,
-                        None, // Auto-detect .cli-test-config.yml
-                    )?;
-
-                    // Use automatic strategy selection based on workload
-                    let test_cases = generator.generate_with_strategy()?;
-
-                    log::info!("Generated {} test cases", test_cases.len());
-
-                    // 4. Write BATS files
-                    let writer = BatsWriter::new(
-                        output.clone(),
-                        cli_analysis.binary_name.clone(),
-                        cli_analysis.binary_path.clone(),
-                    )?;
-
-                    let output_files = writer.write_tests(&test_cases)?;
-
-                    // 5. Validate generated files
-                    for file in &output_files {
-                        writer.validate_bats_file(file)?;
-                        log::debug!("Validated: {}", file.display());
-                    }
-
-                    // 6. Success message
-                    println!("✓ Test generation complete: {} files", output_files.len());
-                    println!("  Output directory: {}", output.display());
-                    println!("  Total test cases: {}", test_cases.len());
-                    println!("\nGenerated files:");
-                    for file in &output_files {
-                        let file_name = file.file_name().unwrap().to_string_lossy();
-                        let test_count = test_cases
-                            .iter()
-                            .filter(|t| file_name.starts_with(t.category.as_str()))
-                            .count();
-                        println!("  - {} ({} tests)", file_name, test_count);
-                    }
-
-                    println!("\nRun tests with: bats {}", output.display());
-                }
-
-                TestFormat::AssertCmd => {
-                    // 3. Generate assert_cmd tests
-                    log::info!("Generating assert_cmd Rust tests");
-                    let generator = AssertCmdGenerator::new(&cli_analysis)?;
-
-                    // Create output directory
-                    fs::create_dir_all(&output)?;
+            // 3. Generate tests through the generator registry (single path for all formats)
+            let generator = create_generator(format.as_str())?;
+            log::info!("Generating {} tests", generator.name());
 
-                    // Generate tests for each category
-                    let mut output_files = Vec::new();
-                    for category in &selected_categories {
-                        let test_code = generator.generate(&cli_analysis, *category)?;
-                        let file_name = format!("{}.rs", category.as_str());
-                        let file_path = output.join(&file_name);
+            fs::create_dir_all(&output)?;
 
-                        fs::write(&file_path, test_code)?;
-                        output_files.push(file_path);
-                        log::info!("Generated: {}", file_name);
-                    }
+            // All categories in one pass (BATS: config loaded once, parallel strategy)
+            let generated = generator.generate_categories(&cli_analysis, &selected_categories)?;
 
-                    // 4. Success message
-                    println!(
-                        "✓ assert_cmd test generation complete: {} files",
-                        output_files.len()
-                    );
-                    println!("  Output directory: {}", output.display());
-                    println!("  Format: Rust (assert_cmd)");
-                    println!("\nGenerated files:");
-                    for file in &output_files {
-                        let file_name = file.file_name().unwrap().to_string_lossy();
-                        println!("  - {}", file_name);
-                    }
+            let mut output_files = Vec::new();
+            for tests in &generated {
+                let file_name =
+                    format!("{}.{}", tests.category.as_str(), generator.file_extension());
+                let file_path = output.join(&file_name);
 
-                    println!("\nNext steps:");
-                    println!("  1. Add to your Cargo.toml:");
-                    println!("     [dev-dependencies]");
-                    println!("     assert_cmd = \"2.0\"");
-                    println!("     predicates = \"3.0\"");
-                    println!("     tempfile = \"3.0\"");
-                    println!("\n  2. Copy generated tests to tests/ directory:");
-                    println!("     cp {}/*.rs tests/", output.display());
-                    println!("\n  3. Run tests:");
-                    println!("     cargo test");
-                }
+                fs::write(&file_path, &tests.code)?;
+                output_files.push((file_path, tests.test_count));
+                log::info!("Generated: {}", file_name);
+            }
 
-                TestFormat::Snapbox => {
-                    return Err(cli_testing_specialist::error::Error::InvalidFormat(
-                        "snapbox format not yet implemented".to_string(),
-                    ));
+            // 4. Success message
+            println!(
+                "✓ {} test generation complete: {} files",
+                generator.name(),
+                output_files.len()
+            );
+            println!("  Output directory: {}", output.display());
+            let counts: Option<Vec<usize>> = generated.iter().map(|t| t.test_count).collect();
+            if let Some(counts) = counts {
+                println!("  Total test cases: {}", counts.iter().sum::<usize>());
+            }
+            println!("\nGenerated files:");
+            for (file, test_count) in &output_files {
+                let file_name = file.file_name().unwrap().to_string_lossy();
+                match test_count {
+                    Some(count) => println!("  - {} ({} tests)", file_name, count),
+                    None => println!("  - {}", file_name),
                 }
             }
 
+            print_next_steps(format, &output);
+
             Ok(())
         }
 
         Commands::Run {
             test_dir,
+            analysis,
+            categories,
+            include_intensive,
             format,
             output,
             timeout,
+            test_timeout,
             skip,
+            runner,
+            jobs,
+            history_dir,
+            baseline,
+            fail_on_regression,
         } => {
-            log::info!("Running tests from: {}", test_dir.display());
-
-            // 1. Determine binary name from test directory or use default
-            let binary_name = test_dir
-                .file_name()
-                .and_then(|s| s.to_str())
-                .unwrap_or("unknown")
-                .to_string();
-
-            // 2. Create BATS executor with custom timeout
-            log::info!("Using timeout: {}s per test suite", timeout);
-            let mut executor = BatsExecutor::with_timeout(binary_name.clone(), None, timeout);
-
-            // 3. Apply skip categories if specified
-            if let Some(skip_categories) = skip {
-                let skip_list: Vec<String> = skip_categories
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-
-                if !skip_list.is_empty() {
-                    log::info!("Skipping test categories: {}", skip_list.join(", "));
-                    println!("Skipping categories: {}", skip_list.join(", "));
-                    executor = executor.with_skip_categories(skip_list);
-                }
+            // 1. Parse skip categories
+            let skip_list: Vec<String> = skip
+                .map(|skip_categories| {
+                    skip_categories
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if !skip_list.is_empty() {
+                log::info!("Skipping test categories: {}", skip_list.join(", "));
+                println!("Skipping categories: {}", skip_list.join(", "));
             }
 
-            // 3. Run tests and collect results
-            println!("Running BATS tests from: {}", test_dir.display());
-            let report = executor.run_tests(&test_dir)?;
+            // Global settings from .cli-test-config.yml (timeout, retry_count)
+            let config = load_config(None)?;
+            let requested_test_timeout =
+                test_timeout.or(config.as_ref().map(|config| config.global.timeout));
+            let global = config.map(|config| config.global).unwrap_or_default();
+
+            // Per-test timeout: CLI flag, then global.timeout from config
+            let test_timeout = requested_test_timeout.unwrap_or(global.timeout);
+            if global.retry_count > 0 {
+                log::info!("Retrying failed tests up to {} time(s)", global.retry_count);
+            }
+
+            let mut report = match (runner, test_dir, analysis) {
+                (Runner::Bats, Some(test_dir), _) => {
+                    log::info!("Running tests from: {}", test_dir.display());
+
+                    // 2. Determine the binary under test from the generated suites,
+                    //    falling back to the test directory name
+                    let (binary_name, binary_version) =
+                        match BatsExecutor::find_target_binary(&test_dir) {
+                            Some((name, path)) => (name, CliParser::new().try_get_version(&path)),
+                            None => (
+                                test_dir
+                                    .file_name()
+                                    .and_then(|s| s.to_str())
+                                    .unwrap_or("unknown")
+                                    .to_string(),
+                                None,
+                            ),
+                        };
+
+                    // 3. Create BATS executor with custom timeouts (BATS_TEST_TIMEOUT
+                    //    only when a per-test timeout was requested)
+                    log::info!("Using timeout: {}s per test suite", timeout);
+                    let mut executor =
+                        BatsExecutor::with_timeout(binary_name, binary_version, timeout)
+                            .with_retry_count(global.retry_count)
+                            .with_jobs(jobs as usize);
+                    if let Some(test_timeout) = requested_test_timeout {
+                        log::info!("Using timeout: {}s per test", test_timeout);
+                        executor = executor.with_test_timeout(test_timeout);
+                    }
+                    if !skip_list.is_empty() {
+                        executor = executor.with_skip_categories(skip_list);
+                    }
+
+                    // 4. Run tests and collect results
+                    println!("Running BATS tests from: {}", test_dir.display());
+                    executor.run_tests(&test_dir)?
+                }
+
+                (Runner::Native, _, Some(analysis)) => {
+                    log::info!("Running tests from: {}", analysis.display());
+
+                    // 2. Load analysis JSON and generate the selected categories in memory
+                    let analysis_json =
+                        cli_testing_specialist::utils::read_json_string_optimized(&analysis)?;
+                    let cli_analysis: CliAnalysis =
+                        cli_testing_specialist::utils::deserialize_json_safe(&analysis_json)?;
+
+                    let selected_categories = parse_categories(&categories, include_intensive)?;
+                    let generator = TestGenerator::with_config(
+                        cli_analysis.clone(),
+                        selected_categories,
+                        None, // Auto-detect .cli-test-config.yml
+                    )?;
+                    let test_cases = generator.generate_with_strategy()?;
+
+                    // 3. Create native executor with per-test timeout
+                    log::info!("Using timeout: {}s per test", test_timeout);
+                    let mut executor = NativeExecutor::new(
+                        cli_analysis.binary_path.clone(),
+                        cli_analysis.binary_name.clone(),
+                        cli_analysis.version.clone(),
+                    )
+                    .with_timeout(test_timeout)
+                    .with_retry_count(global.retry_count)
+                    .with_jobs(jobs as usize);
+                    if !skip_list.is_empty() {
+                        executor = executor.with_skip_categories(skip_list);
+                    }
+
+                    // 4. Run tests and collect results
+                    println!(
+                        "Running {} tests natively for: {}",
+                        test_cases.len(),
+                        cli_analysis.binary_name
+                    );
+                    executor.run_tests(&test_cases)?
+                }
+
+                // clap requires TEST_DIR for BATS and --analysis for the native runner
+                _ => unreachable!(),
+            };
 
             // 4. Display summary with priority-based breakdown
             println!("\n=== Test Results ===");
@@ -261,32 +333,69 @@ fn main() -> Result<()> {
             // Security Checks
             let security_total = report.security_check_tests().len();
             let security_passed = report.passed_security_checks();
-            let security_failed = report.failed_security_checks();
             if security_total > 0 {
                 println!(
                     "Security Checks: {}/{} passed ({} vulnerabilities detected)",
-                    security_passed, security_total, security_failed
+                    security_passed,
+                    security_total,
+                    report.vulnerability_count()
                 );
 
                 // Display vulnerability warnings
-                if security_failed > 0 {
+                if !report.security_findings.is_empty() {
                     println!("\n⚠️  Security Vulnerabilities Found:");
-                    for test in report.security_check_tests() {
-                        if test.status.is_failure() {
-                            // Extract vulnerability type from test name or tags
-                            let vuln_type = if test.tags.contains(&"injection".to_string()) {
-                                "Command Injection"
-                            } else if test.tags.contains(&"path-traversal".to_string()) {
-                                "Path Traversal"
-                            } else {
-                                "Security Issue"
-                            };
-                            println!("  • {} ({})", vuln_type, test.name);
+                    for finding in &report.security_findings {
+                        println!(
+                            "  • [{}] {} ({})",
+                            finding.severity.as_str(),
+                            finding.category,
+                            finding.test_name
+                        );
+                        if !finding.command.is_empty() {
+                            println!("      $ {}", finding.command);
                         }
                     }
                 }
             }
 
+            // Flaky tests (passed only after retry)
+            let flaky_tests = report.flaky_tests();
+            if !flaky_tests.is_empty() {
+                println!("\n⚠️  Flaky Tests (passed on retry):");
+                for test in flaky_tests {
+                    println!("  • {} ({} attempts)", test.name, test.attempts.len());
+                }
+            }
+
+            // Performance trends against the baseline run, then record this run
+            if let Some(history_dir) = &history_dir {
+                let history = PerformanceHistory::new(history_dir, &report.binary_name);
+                let entries = history.load()?;
+                report.performance_trends =
+                    compare_with_history(&report, &entries, baseline.as_deref());
+                if history.record(&report)? {
+                    log::info!("Recorded performance history: {}", history.path().display());
+                }
+            }
+
+            if !report.performance_trends.is_empty() {
+                println!("\nPerformance Trends:");
+                for trend in &report.performance_trends {
+                    println!(
+                        "  • {}: {:.1}ms → {:.1}ms ({:+.1}%){}",
+                        trend.test_name,
+                        trend.baseline_mean_ms,
+                        trend.current_mean_ms,
+                        trend.change_percent,
+                        if trend.is_significant() {
+                            " ⚠️  significant"
+                        } else {
+                            ""
+                        }
+                    );
+                }
+            }
+
             // Overall summary
             println!(
                 "\nOverall: {}/{} tests executed in {:.2}s",
@@ -305,10 +414,13 @@ fn main() -> Result<()> {
                     ReportFormat::Json,
                     ReportFormat::Html,
                     ReportFormat::Junit,
+                    ReportFormat::Sarif,
                 ],
                 _ => vec![format],
             };
 
+            let binary_name = report.binary_name.clone();
+
             println!("\nGenerating reports:");
             for fmt in formats {
                 match fmt {
@@ -332,6 +444,11 @@ fn main() -> Result<()> {
                         JunitReporter::generate(&report, &path)?;
                         println!("  ✓ JUnit XML: {}", path.display());
                     }
+                    ReportFormat::Sarif => {
+                        let path = output.join(std::format!("{}-report.sarif", binary_name));
+                        SarifReporter::generate(&report, &path)?;
+                        println!("  ✓ SARIF: {}", path.display());
+                    }
                     ReportFormat::All => {
                         // Already expanded above
                         unreachable!()
@@ -343,7 +460,119 @@ fn main() -> Result<()> {
             println!("  Reports directory: {}", output.display());
 
             // 7. Exit with appropriate code
-            if report.all_passed() {
+            let regressions: Vec<_> = match fail_on_regression {
+                Some(percent) => report
+                    .performance_trends
+                    .iter()
+                    .filter(|trend| trend.is_regression(percent))
+                    .collect(),
+                None => Vec::new(),
+            };
+            if !regressions.is_empty() {
+                eprintln!(
+                    "\n✗ Performance regression beyond {}%:",
+                    fail_on_regression.unwrap_or_default()
+                );
+                for trend in &regressions {
+                    match trend.p_value {
+                        Some(p) => eprintln!(
+                            "  - {}: {:+.1}% (p = {:.4})",
+                            trend.test_name, trend.change_percent, p
+                        ),
+                        None => eprintln!(
+                            "  - {}: {:+.1}% (baseline has no samples, means compared)",
+                            trend.test_name, trend.change_percent
+                        ),
+                    }
+                }
+            }
+
+            if report.all_passed() && regressions.is_empty() {
+                Ok(())
+            } else {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Fuzz {
+            analysis,
+            duration,
+            corpus,
+            seed,
+            timeout,
+            max_memory,
+            max_runs,
+        } => {
+            log::info!("Fuzzing from analysis: {}", analysis.display());
+
+            // 1. Load analysis JSON
+            let analysis_json =
+                cli_testing_specialist::utils::read_json_string_optimized(&analysis)?;
+            let cli_analysis: CliAnalysis =
+                cli_testing_specialist::utils::deserialize_json_safe(&analysis_json)?;
+            validate_binary_path(&cli_analysis.binary_path)?;
+
+            // 2. Resource limits for every execution (0 MB lifts the memory limit)
+            let defaults = ResourceLimits::default();
+            let max_memory_bytes = match max_memory {
+                0 => u64::MAX,
+                mb => mb.saturating_mul(1024 * 1024),
+            };
+            let limits = ResourceLimits::new(
+                max_memory_bytes,
+                defaults.max_file_descriptors,
+                defaults.max_processes,
+                Duration::from_secs(timeout),
+            );
+            let mut fuzzer = Fuzzer::new(cli_analysis.clone(), corpus.clone()).with_limits(limits);
+            if let Some(seed) = seed {
+                fuzzer = fuzzer.with_seed(seed);
+            }
+            if let Some(max_runs) = max_runs {
+                fuzzer = fuzzer.with_max_runs(max_runs);
+            }
+
+            // 3. Replay the crashes already in the corpus under the same limits
+            let known = load_corpus(&corpus)?;
+            if !known.is_empty() {
+                let reproduced = fuzzer.replay(&known);
+                println!(
+                    "Corpus: {} of {} saved crash(es) still reproduce",
+                    reproduced.len(),
+                    known.len()
+                );
+                for test in &reproduced {
+                    println!("  - {}: {}", test.id, test.command);
+                }
+            }
+
+            println!(
+                "Fuzzing {} for {}s (seed {})",
+                cli_analysis.binary_name,
+                duration.as_secs(),
+                fuzzer.seed()
+            );
+            let report = fuzzer.run(duration)?;
+
+            // 4. Display summary
+            println!("\n=== Fuzzing Results ===");
+            println!(
+                "Executions: {} in {:.1}s",
+                report.executions,
+                report.elapsed.as_secs_f64()
+            );
+            println!(
+                "Crashes: {} ({} new in corpus)",
+                report.crashes.len(),
+                report.saved.len()
+            );
+            for test in &report.crashes {
+                println!("  - {}: {}", test.id, test.command);
+            }
+            println!("Corpus: {}", corpus.display());
+            println!("Repeat this run with: --seed {}", report.seed);
+
+            if report.crashes.is_empty() {
                 Ok(())
             } else {
                 std::process::exit(1);
@@ -351,9 +580,34 @@ fn main() -> Result<()> {
         }
 
         Commands::Validate { file } => {
-            log::info!("Validating: {:?}", file);
-            println!("Validate command - Implementation planned for Phase 2");
-            Ok(())
+            log::info!("Validating: {}", file.display());
+
+            // 1. Load analysis JSON (optimized buffered I/O + safe deserialization)
+            let analysis_json = cli_testing_specialist::utils::read_json_string_optimized(&file)?;
+            let cli_analysis: CliAnalysis =
+                cli_testing_specialist::utils::deserialize_json_safe(&analysis_json)?;
+
+            // 2. Run structural checks
+            let issues = validate_analysis(&cli_analysis);
+
+            // 3. Report results
+            if issues.is_empty() {
+                println!("✓ Analysis file is valid: {}", file.display());
+                println!("  Binary: {}", cli_analysis.binary_name);
+                println!("  Subcommands: {}", cli_analysis.metadata.total_subcommands);
+                println!("  Options: {}", cli_analysis.metadata.total_options);
+                Ok(())
+            } else {
+                eprintln!(
+                    "✗ Analysis file has {} problem(s): {}",
+                    issues.len(),
+                    file.display()
+                );
+                for issue in &issues {
+                    eprintln!("  - {}", issue);
+                }
+                std::process::exit(1);
+            }
         }
 
         Commands::Completion { shell } => {
@@ -395,12 +649,76 @@ fn main() -> Result<()> {
     }
 }
 
+/// Count options (global and per subcommand) discovered from `source`
+fn count_options_from(analysis: &CliAnalysis, source: OptionSource) -> usize {
+    fn count(subcommands: &[Subcommand], source: OptionSource) -> usize {
+        subcommands
+            .iter()
+            .map(|s| {
+                s.options.iter().filter(|o| o.source == source).count()
+                    + count(&s.subcommands, source)
+            })
+            .sum()
+    }
+
+    analysis
+        .global_options
+        .iter()
+        .filter(|o| o.source == source)
+        .count()
+        + count(&analysis.subcommands, source)
+}
+
+/// Count option relations (global and per subcommand)
+fn count_option_relations(analysis: &CliAnalysis) -> usize {
+    fn count(subcommands: &[Subcommand]) -> usize {
+        subcommands
+            .iter()
+            .map(|s| s.option_relations.len() + count(&s.subcommands))
+            .sum()
+    }
+
+    analysis.option_relations.len() + count(&analysis.subcommands)
+}
+
+/// Print format-specific instructions for running generated tests
+fn print_next_steps(format: TestFormat, output: &std::path::Path) {
+    match format {
+        TestFormat::Bats => {
+            println!("\nRun tests with: bats {}", output.display());
+        }
+        TestFormat::AssertCmd => {
+            println!("\nNext steps:");
+            println!("  1. Add to your Cargo.toml:");
+            println!("     [dev-dependencies]");
+            println!("     assert_cmd = \"2.0\"");
+            println!("     predicates = \"3.0\"");
+            println!("     tempfile = \"3.0\"");
+            println!("\n  2. Copy generated tests to tests/ directory:");
+            println!("     cp {}/*.rs tests/", output.display());
+            println!("\n  3. Run tests:");
+            println!("     cargo test");
+        }
+        TestFormat::Snapbox => {
+            println!("\nNext steps:");
+            println!("  1. Add to your Cargo.toml:");
+            println!("     [dev-dependencies]");
+            println!("     snapbox = {{ version = \"0.6\", features = [\"cmd\"] }}");
+            println!("\n  2. Copy generated tests to tests/ directory:");
+            println!("     cp {}/*.rs tests/", output.display());
+            println!("\n  3. Record snapshots, then run tests:");
+            println!("     SNAPSHOTS=overwrite cargo test");
+            println!("     cargo test");
+        }
+    }
+}
+
 /// Parse test categories from comma-separated string or "all"
 fn parse_categories(categories_str: &str, include_intensive: bool) -> Result<Vec<TestCategory>> {
     if categories_str.trim().to_lowercase() == "all" {
-        // "all" respects the include_intensive flag
+        // "all" respects the include_intensive flag; opt-in categories must be named
         return if include_intensive {
-            Ok(TestCategory::all())
+            Ok(TestCategory::default_categories())
         } else {
             Ok(TestCategory::standard_categories())
         };
@@ -33,6 +33,11 @@ impl HtmlReporter {
         {}
         {}
         {}
+        {}
+        {}
+        {}
+        {}
+        {}
     </div>
     {}
 </body>
@@ -43,6 +48,11 @@ impl HtmlReporter {
             Self::render_header(report),
             Self::render_summary(report),
             Self::render_suite_overview(report),
+            Self::render_security_findings(report),
+            Self::render_enum_matrices(report),
+            Self::render_flaky_tests(report),
+            Self::render_performance(report),
+            Self::render_performance_trends(report),
             Self::render_detailed_results(report),
             Self::render_environment(report),
             Self::embedded_javascript(),
@@ -218,6 +228,340 @@ impl HtmlReporter {
         )
     }
 
+    /// Render security findings from failed security checks
+    fn render_security_findings(report: &TestReport) -> String {
+        if report.security_findings.is_empty() {
+            return String::new();
+        }
+
+        let mut findings_html = String::new();
+        for finding in &report.security_findings {
+            let command = if finding.command.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    r#"<p class="mb-1"><strong>Command:</strong> <code>{}</code></p>"#,
+                    Self::html_escape(&finding.command)
+                )
+            };
+            let evidence = if finding.evidence.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    r#"<pre class="mb-0"><code>{}</code></pre>"#,
+                    Self::html_escape(&finding.evidence)
+                )
+            };
+
+            findings_html.push_str(&format!(
+                r#"
+            <div class="card border-danger mb-3">
+                <div class="card-header d-flex justify-content-between align-items-center">
+                    <h5 class="mb-0">🛡️ {}</h5>
+                    <span><span class="badge bg-{}">{}</span> <span class="badge bg-dark">{}</span></span>
+                </div>
+                <div class="card-body">
+                    <p class="mb-1">{}</p>
+                    <p class="mb-1"><strong>Test ID:</strong> <code>{}</code></p>
+                    {}
+                    {}
+                </div>
+            </div>"#,
+                Self::html_escape(&finding.test_name),
+                finding.severity.badge_color(),
+                finding.severity.as_str().to_uppercase(),
+                Self::html_escape(&finding.category),
+                Self::html_escape(&finding.description),
+                Self::html_escape(&finding.test_id),
+                command,
+                evidence,
+            ));
+        }
+
+        format!(
+            r#"<section class="mb-5">
+            <h2>Security Findings</h2>
+            {}
+        </section>"#,
+            findings_html
+        )
+    }
+
+    /// Render which values each enum option accepted, against the expectation
+    fn render_enum_matrices(report: &TestReport) -> String {
+        if report.enum_matrices.is_empty() {
+            return String::new();
+        }
+
+        let mut matrices_html = String::new();
+        for matrix in &report.enum_matrices {
+            let mut rows_html = String::new();
+            for cell in &matrix.cells {
+                let observed = match cell.accepted {
+                    Some(true) => "accepted",
+                    Some(false) => "rejected",
+                    None => "-",
+                };
+                let verdict = match cell.accepted {
+                    None => r#"<span class="badge bg-secondary">Not run</span>"#,
+                    Some(_) if cell.matches() => r#"<span class="badge bg-success">Pass</span>"#,
+                    Some(_) => r#"<span class="badge bg-danger">Fail</span>"#,
+                };
+
+                rows_html.push_str(&format!(
+                    r#"
+                        <tr>
+                            <td><code>{}</code></td>
+                            <td>{}</td>
+                            <td>{}</td>
+                            <td>{}</td>
+                            <td><small>{}</small></td>
+                        </tr>"#,
+                    Self::html_escape(&format!("{:?}", cell.value)),
+                    if cell.expected_accepted {
+                        "accept"
+                    } else {
+                        "reject"
+                    },
+                    observed,
+                    verdict,
+                    Self::html_escape(&cell.test_id),
+                ));
+            }
+
+            matrices_html.push_str(&format!(
+                r#"
+            <h5><code>{}</code> <small class="text-muted">({} of {} as expected)</small></h5>
+            <div class="table-responsive">
+                <table class="table table-sm table-striped">
+                    <thead class="table-dark">
+                        <tr>
+                            <th>Value</th>
+                            <th>Expected</th>
+                            <th>Observed</th>
+                            <th>Result</th>
+                            <th>Test ID</th>
+                        </tr>
+                    </thead>
+                    <tbody>{}
+                    </tbody>
+                </table>
+            </div>"#,
+                Self::html_escape(&matrix.option),
+                matrix.cells.len() - matrix.mismatches(),
+                matrix.cells.len(),
+                rows_html
+            ));
+        }
+
+        format!(
+            r#"<section class="mb-5">
+            <h2>Enum Value Matrix</h2>
+            {}
+        </section>"#,
+            matrices_html
+        )
+    }
+
+    /// Render flaky tests (passed on retry) with every attempt
+    fn render_flaky_tests(report: &TestReport) -> String {
+        let flaky_tests = report.flaky_tests();
+        if flaky_tests.is_empty() {
+            return String::new();
+        }
+
+        let mut flaky_html = String::new();
+        for test in flaky_tests {
+            let mut attempts_html = String::new();
+            for (idx, attempt) in test.attempts.iter().enumerate() {
+                let output = if attempt.output.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        r#"<pre class="mb-0"><code>{}</code></pre>"#,
+                        Self::html_escape(attempt.output.trim_end())
+                    )
+                };
+                attempts_html.push_str(&format!(
+                    r#"<li class="list-group-item"><strong>Attempt {}:</strong> {} in {:.0}ms {}{}</li>"#,
+                    idx + 1,
+                    attempt.status.as_str(),
+                    attempt.duration.as_millis(),
+                    attempt
+                        .error_message
+                        .as_deref()
+                        .map(|e| format!(r#"<small class="text-danger">{}</small>"#, Self::html_escape(e)))
+                        .unwrap_or_default(),
+                    output,
+                ));
+            }
+
+            flaky_html.push_str(&format!(
+                r#"
+            <div class="card border-warning mb-3">
+                <div class="card-header">
+                    <h5 class="mb-0">⚠️ {} <span class="badge bg-warning text-dark">passed after {} attempts</span></h5>
+                </div>
+                <ul class="list-group list-group-flush">{}</ul>
+            </div>"#,
+                Self::html_escape(&test.name),
+                test.attempts.len(),
+                attempts_html,
+            ));
+        }
+
+        format!(
+            r#"<section class="mb-5">
+            <h2>Flaky Tests</h2>
+            <p class="text-muted">Tests that failed at least once and passed on retry.</p>
+            {}
+        </section>"#,
+            flaky_html
+        )
+    }
+
+    /// Render benchmark measurements of performance tests
+    fn render_performance(report: &TestReport) -> String {
+        let performance_results = report.performance_results();
+        if performance_results.is_empty() {
+            return String::new();
+        }
+
+        let mut rows_html = String::new();
+        for test in performance_results {
+            let Some(metrics) = &test.performance else {
+                continue;
+            };
+            rows_html.push_str(&format!(
+                r#"
+                        <tr>
+                            <td>{}</td>
+                            <td><span class="badge bg-{}">{}</span></td>
+                            <td>{}</td>
+                            <td>{:.1}ms</td>
+                            <td>{:.1}ms</td>
+                            <td>{:.1}ms</td>
+                            <td>{}</td>
+                        </tr>"#,
+                Self::html_escape(&test.name),
+                if test.status.is_success() {
+                    "success"
+                } else {
+                    "danger"
+                },
+                test.status.as_str(),
+                metrics.iterations,
+                metrics.mean_ms,
+                metrics.p95_ms,
+                metrics.max_ms,
+                metrics
+                    .max_rss_kb
+                    .map(|kb| format!("{:.1}MB", kb as f64 / 1024.0))
+                    .unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+
+        format!(
+            r#"<section class="mb-5">
+            <h2>Performance</h2>
+            <div class="table-responsive">
+                <table class="table table-striped">
+                    <thead class="table-dark">
+                        <tr>
+                            <th>Test</th>
+                            <th>Status</th>
+                            <th>Runs</th>
+                            <th>Mean</th>
+                            <th>p95</th>
+                            <th>Max</th>
+                            <th>Peak RSS</th>
+                        </tr>
+                    </thead>
+                    <tbody>{}
+                    </tbody>
+                </table>
+            </div>
+        </section>"#,
+            rows_html
+        )
+    }
+
+    /// Render performance trends against the baseline run
+    fn render_performance_trends(report: &TestReport) -> String {
+        if report.performance_trends.is_empty() {
+            return String::new();
+        }
+
+        let mut rows_html = String::new();
+        for trend in &report.performance_trends {
+            let baseline = trend.baseline_version.clone().unwrap_or_else(|| {
+                trend
+                    .baseline_recorded_at
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            });
+            let history = trend
+                .history_ms
+                .iter()
+                .map(|ms| format!("{:.1}", ms))
+                .collect::<Vec<_>>()
+                .join(" → ");
+            let verdict = if trend.is_significant() {
+                r#"<span class="badge bg-danger">Regression</span>"#
+            } else {
+                r#"<span class="badge bg-success">No significant change</span>"#
+            };
+
+            rows_html.push_str(&format!(
+                r#"
+                        <tr>
+                            <td>{}</td>
+                            <td>{:.1}ms <small class="text-muted">({})</small></td>
+                            <td>{:.1}ms</td>
+                            <td>{:+.1}%</td>
+                            <td>{}</td>
+                            <td><small>{}</small></td>
+                            <td>{}</td>
+                        </tr>"#,
+                Self::html_escape(&trend.test_name),
+                trend.baseline_mean_ms,
+                Self::html_escape(&baseline),
+                trend.current_mean_ms,
+                trend.change_percent,
+                trend
+                    .p_value
+                    .map(|p| format!("{:.4}", p))
+                    .unwrap_or_else(|| "-".to_string()),
+                history,
+                verdict,
+            ));
+        }
+
+        format!(
+            r#"<section class="mb-5">
+            <h2>Performance Trends</h2>
+            <div class="table-responsive">
+                <table class="table table-striped">
+                    <thead class="table-dark">
+                        <tr>
+                            <th>Test</th>
+                            <th>Baseline</th>
+                            <th>Current</th>
+                            <th>Change</th>
+                            <th>p-value</th>
+                            <th>Trend (mean ms)</th>
+                            <th>Verdict</th>
+                        </tr>
+                    </thead>
+                    <tbody>{}
+                    </tbody>
+                </table>
+            </div>
+        </section>"#,
+            rows_html
+        )
+    }
+
     /// Render detailed results
     fn render_detailed_results(report: &TestReport) -> String {
         let mut details_html = String::new();
@@ -478,7 +822,10 @@ impl HtmlReporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{EnvironmentInfo, TestResult, TestSuite};
+    use crate::types::report::{EnumMatrix, EnumMatrixCell, SecurityFinding, SecuritySeverity};
+    use crate::types::{
+        EnvironmentInfo, PerformanceMetrics, PerformanceTrend, TestResult, TestSuite,
+    };
     use chrono::Utc;
     use std::time::Duration;
     use tempfile::NamedTempFile;
@@ -498,6 +845,13 @@ mod tests {
                     line_number: Some(5),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "failed test".to_string(),
@@ -509,6 +863,13 @@ mod tests {
                     line_number: Some(10),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
             ],
             duration: Duration::from_millis(350),
@@ -525,6 +886,8 @@ mod tests {
             finished_at: Utc::now(),
             environment: EnvironmentInfo::default(),
             security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
         }
     }
 
@@ -579,4 +942,126 @@ mod tests {
             "&quot;quoted&quot;"
         );
     }
+
+    #[test]
+    fn test_html_flaky_section() {
+        let mut report = create_test_report();
+        assert!(HtmlReporter::render_flaky_tests(&report).is_empty());
+
+        let first = report.suites[0].tests[1].clone();
+        let retry = report.suites[0].tests[0].clone();
+        report.suites[0].tests[1] = first.with_retry(retry);
+
+        let html = HtmlReporter::render_flaky_tests(&report);
+        assert!(html.contains("Flaky Tests"));
+        assert!(html.contains("failed test"));
+        assert!(html.contains("passed after 2 attempts"));
+        assert!(html.contains("<strong>Attempt 1:</strong> failed"));
+    }
+
+    #[test]
+    fn test_html_performance() {
+        let mut report = create_test_report();
+        assert!(HtmlReporter::render_performance(&report).is_empty());
+
+        report.suites[0].tests[0].performance = Some(PerformanceMetrics {
+            iterations: 20,
+            mean_ms: 12.34,
+            p95_ms: 15.0,
+            max_ms: 18.25,
+            max_rss_kb: None,
+            samples_ms: vec![],
+        });
+
+        let html = HtmlReporter::render_performance(&report);
+        assert!(html.contains("<h2>Performance</h2>"));
+        assert!(html.contains("<td>15.0ms</td>"));
+        assert!(html.contains("<td>-</td>"));
+    }
+
+    #[test]
+    fn test_html_performance_trends() {
+        let mut report = create_test_report();
+        assert!(HtmlReporter::render_performance_trends(&report).is_empty());
+
+        report.performance_trends = vec![PerformanceTrend {
+            test_id: "perf-001".to_string(),
+            test_name: "Startup time".to_string(),
+            baseline_version: None,
+            baseline_recorded_at: Utc::now(),
+            baseline_mean_ms: 10.0,
+            current_mean_ms: 10.1,
+            change_percent: 1.0,
+            p_value: Some(0.41),
+            history_ms: vec![10.0, 10.1],
+        }];
+
+        let html = HtmlReporter::render_performance_trends(&report);
+        assert!(html.contains("<h2>Performance Trends</h2>"));
+        assert!(html.contains("<td>+1.0%</td>"));
+        assert!(html.contains("<td>0.4100</td>"));
+        assert!(html.contains("10.0 → 10.1"));
+        assert!(html.contains("No significant change"));
+    }
+
+    #[test]
+    fn test_html_security_findings() {
+        let mut report = create_test_report();
+        assert!(HtmlReporter::render_security_findings(&report).is_empty());
+
+        report.security_findings = vec![SecurityFinding {
+            test_name: "Reject path traversal".to_string(),
+            category: "path-traversal".to_string(),
+            severity: SecuritySeverity::High,
+            description: "Path traversal sequence was not rejected".to_string(),
+            evidence: "root:x:0:0".to_string(),
+            test_file: "security.bats".to_string(),
+            test_id: "security-003".to_string(),
+            command: "test-cli --file ../../../etc/passwd".to_string(),
+        }];
+
+        let html = HtmlReporter::render_security_findings(&report);
+        assert!(html.contains("Security Findings"));
+        assert!(html.contains("HIGH"));
+        assert!(html.contains("security-003"));
+        assert!(html.contains("<code>test-cli --file ../../../etc/passwd</code>"));
+    }
+
+    #[test]
+    fn test_html_enum_matrix() {
+        let mut report = create_test_report();
+        assert!(HtmlReporter::render_enum_matrices(&report).is_empty());
+
+        report.enum_matrices = vec![EnumMatrix {
+            option: "remote add --format".to_string(),
+            cells: vec![
+                EnumMatrixCell {
+                    value: "json".to_string(),
+                    expected_accepted: true,
+                    accepted: Some(true),
+                    test_id: "enum-001-value-json".to_string(),
+                },
+                EnumMatrixCell {
+                    value: "JSON".to_string(),
+                    expected_accepted: true,
+                    accepted: Some(false),
+                    test_id: "enum-001-uppercase".to_string(),
+                },
+                EnumMatrixCell {
+                    value: String::new(),
+                    expected_accepted: false,
+                    accepted: None,
+                    test_id: "enum-001-empty".to_string(),
+                },
+            ],
+        }];
+
+        let html = HtmlReporter::render_enum_matrices(&report);
+        assert!(html.contains("Enum Value Matrix"));
+        assert!(html.contains("<code>remote add --format</code>"));
+        assert!(html.contains("(1 of 3 as expected)"));
+        assert!(html.contains("<code>&quot;JSON&quot;</code>"));
+        assert!(html.contains("bg-danger\">Fail"));
+        assert!(html.contains("Not run"));
+    }
 }
@@ -1,8 +1,47 @@
 use crate::error::Result;
 use crate::types::TestReport;
 use crate::utils::{write_json_compact_optimized, write_json_optimized};
+use serde::Serialize;
 use std::path::Path;
 
+/// Serialized report: the full `TestReport` plus a top-level flaky test summary
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    #[serde(flatten)]
+    report: &'a TestReport,
+
+    /// Tests that passed only after retry (details are in each test's `attempts`)
+    flaky_tests: Vec<FlakyTest<'a>>,
+}
+
+#[derive(Serialize)]
+struct FlakyTest<'a> {
+    name: &'a str,
+    suite: &'a str,
+    attempts: usize,
+}
+
+impl<'a> JsonReport<'a> {
+    fn new(report: &'a TestReport) -> Self {
+        let flaky_tests = report
+            .suites
+            .iter()
+            .flat_map(|suite| suite.tests.iter().map(move |test| (suite, test)))
+            .filter(|(_, test)| test.flaky)
+            .map(|(suite, test)| FlakyTest {
+                name: &test.name,
+                suite: &suite.name,
+                attempts: test.attempts.len(),
+            })
+            .collect();
+
+        Self {
+            report,
+            flaky_tests,
+        }
+    }
+}
+
 /// JSON report generator
 pub struct JsonReporter;
 
@@ -12,7 +51,7 @@ impl JsonReporter {
     /// Uses optimized buffered I/O for improved performance on large reports.
     pub fn generate(report: &TestReport, output_path: &Path) -> Result<()> {
         // Write using optimized buffered I/O (64KB buffer)
-        write_json_optimized(report, output_path)?;
+        write_json_optimized(&JsonReport::new(report), output_path)?;
 
         Ok(())
     }
@@ -22,7 +61,7 @@ impl JsonReporter {
     /// Uses optimized buffered I/O for improved performance on large reports.
     pub fn generate_compact(report: &TestReport, output_path: &Path) -> Result<()> {
         // Write using optimized buffered I/O (64KB buffer)
-        write_json_compact_optimized(report, output_path)?;
+        write_json_compact_optimized(&JsonReport::new(report), output_path)?;
 
         Ok(())
     }
@@ -52,6 +91,13 @@ mod tests {
                     line_number: Some(5),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "failed test".to_string(),
@@ -63,6 +109,13 @@ mod tests {
                     line_number: Some(10),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
             ],
             duration: Duration::from_millis(350),
@@ -79,6 +132,8 @@ mod tests {
             finished_at: Utc::now(),
             environment: EnvironmentInfo::default(),
             security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
         }
     }
 
@@ -157,4 +212,33 @@ mod tests {
             original.suites[0].tests.len()
         );
     }
+
+    #[test]
+    fn test_json_flaky_tests_section() {
+        let mut report = create_test_report();
+        let temp_file = NamedTempFile::new().unwrap();
+
+        JsonReporter::generate(&report, temp_file.path()).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp_file.path()).unwrap()).unwrap();
+        assert_eq!(parsed["flaky_tests"].as_array().unwrap().len(), 0);
+
+        let first = report.suites[0].tests[1].clone();
+        let retry = report.suites[0].tests[0].clone();
+        report.suites[0].tests[1] = first.with_retry(retry);
+
+        JsonReporter::generate(&report, temp_file.path()).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp_file.path()).unwrap()).unwrap();
+
+        let flaky = &parsed["flaky_tests"][0];
+        assert_eq!(flaky["name"], "failed test");
+        assert_eq!(flaky["suite"], "test_suite");
+        assert_eq!(flaky["attempts"], 2);
+
+        let test = &parsed["suites"][0]["tests"][1];
+        assert_eq!(test["flaky"], true);
+        assert_eq!(test["attempts"][0]["status"], "failed");
+        assert_eq!(test["attempts"][0]["output"], "error output");
+    }
 }
@@ -63,6 +63,33 @@ impl JunitReporter {
             ));
             xml.push('\n');
         }
+        for finding in &report.security_findings {
+            xml.push_str(&format!(
+                r#"    <property name="security_finding" value="{}"/>"#,
+                Self::xml_escape(&format!(
+                    "[{}] {}: {} ({})",
+                    finding.severity.as_str(),
+                    finding.category,
+                    finding.test_name,
+                    finding.command
+                ))
+            ));
+            xml.push('\n');
+        }
+        let flaky_tests = report.flaky_tests();
+        if !flaky_tests.is_empty() {
+            xml.push_str(&format!(
+                r#"    <property name="flaky_tests" value="{}"/>"#,
+                Self::xml_escape(
+                    &flaky_tests
+                        .iter()
+                        .map(|t| t.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            ));
+            xml.push('\n');
+        }
         xml.push_str("  </properties>\n");
 
         // Add each test suite
@@ -111,6 +138,34 @@ impl JunitReporter {
         ));
 
         match test.status {
+            TestStatus::Passed if test.flaky => {
+                // Surefire-style <flakyFailure> per failed attempt (understood by most CI tools)
+                xml.push_str(">\n");
+                for attempt in test.attempts.iter().filter(|a| a.status.is_failure()) {
+                    let error_msg = attempt
+                        .error_message
+                        .as_deref()
+                        .unwrap_or("Attempt failed without error message");
+                    xml.push_str(&format!(
+                        r#"      <flakyFailure message="{}" type="{}">"#,
+                        Self::xml_escape(error_msg),
+                        if attempt.status == TestStatus::Timeout {
+                            "TimeoutError"
+                        } else {
+                            "AssertionError"
+                        }
+                    ));
+                    xml.push('\n');
+                    if !attempt.output.is_empty() {
+                        xml.push_str(&format!(
+                            "        <system-out>{}</system-out>\n",
+                            Self::xml_escape(&attempt.output)
+                        ));
+                    }
+                    xml.push_str("      </flakyFailure>\n");
+                }
+                xml.push_str("    </testcase>\n");
+            }
             TestStatus::Passed => {
                 xml.push_str("/>\n");
             }
@@ -162,6 +217,7 @@ impl JunitReporter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::report::{SecurityFinding, SecuritySeverity};
     use crate::types::{EnvironmentInfo, TestResult, TestSuite};
     use chrono::Utc;
     use std::time::Duration;
@@ -182,6 +238,13 @@ mod tests {
                     line_number: Some(5),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "failed test".to_string(),
@@ -193,6 +256,13 @@ mod tests {
                     line_number: Some(10),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "skipped test".to_string(),
@@ -204,6 +274,13 @@ mod tests {
                     line_number: Some(15),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
             ],
             duration: Duration::from_millis(350),
@@ -220,6 +297,8 @@ mod tests {
             finished_at: Utc::now(),
             environment: EnvironmentInfo::default(),
             security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
         }
     }
 
@@ -293,4 +372,39 @@ mod tests {
         let property_count = content.matches("<property").count();
         assert!(property_count >= 4); // At least os, shell, bats_version, hostname
     }
+
+    #[test]
+    fn test_flaky_test_rendering() {
+        let mut report = create_test_report();
+        let first = report.suites[0].tests[1].clone();
+        let retry = report.suites[0].tests[0].clone();
+        report.suites[0].tests[1] = first.with_retry(retry);
+
+        let xml = JunitReporter::render_xml(&report);
+
+        assert!(xml.contains(r#"<property name="flaky_tests" value="failed test"/>"#));
+        assert!(xml.contains(r#"<flakyFailure message="assertion failed" type="AssertionError">"#));
+        assert!(xml.contains("<system-out>error output</system-out>"));
+        assert!(!xml.contains("<failure"));
+    }
+
+    #[test]
+    fn test_security_findings_as_properties() {
+        let mut report = create_test_report();
+        report.security_findings = vec![SecurityFinding {
+            test_name: "Reject null byte".to_string(),
+            category: "null-byte".to_string(),
+            severity: SecuritySeverity::Critical,
+            description: "Null byte in argument was not rejected".to_string(),
+            evidence: String::new(),
+            test_file: "security.bats".to_string(),
+            test_id: "security-002".to_string(),
+            command: "test-cli --name $'a\\x00b'".to_string(),
+        }];
+
+        let xml = JunitReporter::render_xml(&report);
+        assert!(xml.contains(
+            r#"<property name="security_finding" value="[critical] null-byte: Reject null byte (test-cli --name $&apos;a\x00b&apos;)"/>"#
+        ));
+    }
 }
@@ -46,6 +46,10 @@ impl MarkdownReporter {
         content.push_str(&format!("| Passed | ✅ {} |\n", report.total_passed()));
         content.push_str(&format!("| Failed | ❌ {} |\n", report.total_failed()));
         content.push_str(&format!("| Skipped | ⏭️ {} |\n", report.total_skipped()));
+        let flaky_count = report.flaky_tests().len();
+        if flaky_count > 0 {
+            content.push_str(&format!("| Flaky | ⚠️ {} |\n", flaky_count));
+        }
         content.push_str(&format!(
             "| Duration | {:.2}s |\n",
             report.total_duration.as_secs_f64()
@@ -108,6 +112,175 @@ impl MarkdownReporter {
             }
         }
 
+        // Security findings section
+        if !report.security_findings.is_empty() {
+            content.push_str("## Security Findings\n\n");
+            content.push_str("| Severity | Category | Test | Test ID |\n");
+            content.push_str("|----------|----------|------|---------|\n");
+            for finding in &report.security_findings {
+                content.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    finding.severity.as_str().to_uppercase(),
+                    finding.category,
+                    finding.test_name,
+                    finding.test_id
+                ));
+            }
+            content.push('\n');
+
+            for finding in &report.security_findings {
+                content.push_str(&format!(
+                    "- 🛡️ **{}** ({})\n",
+                    finding.test_name,
+                    finding.severity.as_str()
+                ));
+                content.push_str(&format!("  - Description: {}\n", finding.description));
+                if !finding.command.is_empty() {
+                    content.push_str("  - Command:\n");
+                    content.push_str(&list_item_code_block(&finding.command));
+                }
+                if !finding.evidence.is_empty() {
+                    content.push_str("  - Evidence:\n");
+                    content.push_str(&list_item_code_block(&finding.evidence));
+                }
+            }
+            content.push('\n');
+        }
+
+        // Enum value matrix section
+        for (idx, matrix) in report.enum_matrices.iter().enumerate() {
+            if idx == 0 {
+                content.push_str("## Enum Value Matrix\n\n");
+            }
+            content.push_str(&format!(
+                "### `{}` ({} of {} as expected)\n\n",
+                matrix.option,
+                matrix.cells.len() - matrix.mismatches(),
+                matrix.cells.len()
+            ));
+            content.push_str("| Value | Expected | Observed | Result | Test ID |\n");
+            content.push_str("|-------|----------|----------|--------|---------|\n");
+            for cell in &matrix.cells {
+                content.push_str(&format!(
+                    "| `{}` | {} | {} | {} | {} |\n",
+                    format!("{:?}", cell.value).replace('|', "\\|"),
+                    if cell.expected_accepted {
+                        "accept"
+                    } else {
+                        "reject"
+                    },
+                    match cell.accepted {
+                        Some(true) => "accepted",
+                        Some(false) => "rejected",
+                        None => "-",
+                    },
+                    match cell.accepted {
+                        None => "⏭️",
+                        Some(_) if cell.matches() => "✅",
+                        Some(_) => "❌",
+                    },
+                    cell.test_id
+                ));
+            }
+            content.push('\n');
+        }
+
+        // Flaky tests section
+        let flaky_tests = report.flaky_tests();
+        if !flaky_tests.is_empty() {
+            content.push_str("## Flaky Tests\n\n");
+            content.push_str("Tests that failed at least once and passed on retry.\n\n");
+
+            for test in flaky_tests {
+                content.push_str(&format!(
+                    "- ⚠️ **{}** (passed after {} attempts)\n",
+                    test.name,
+                    test.attempts.len()
+                ));
+                for (idx, attempt) in test.attempts.iter().enumerate() {
+                    content.push_str(&format!(
+                        "  - Attempt {}: {} in {}ms",
+                        idx + 1,
+                        attempt.status.as_str(),
+                        attempt.duration.as_millis()
+                    ));
+                    if let Some(error) = &attempt.error_message {
+                        content.push_str(&format!(" - `{}`", error));
+                    }
+                    content.push('\n');
+                    if !attempt.output.is_empty() {
+                        content.push_str(&list_item_code_block(&attempt.output));
+                    }
+                }
+            }
+            content.push('\n');
+        }
+
+        // Performance section
+        let performance_results = report.performance_results();
+        if !performance_results.is_empty() {
+            content.push_str("## Performance\n\n");
+            content.push_str("| Test | Status | Runs | Mean | p95 | Max | Peak RSS |\n");
+            content.push_str("|------|--------|------|------|-----|-----|----------|\n");
+            for test in performance_results {
+                let Some(metrics) = &test.performance else {
+                    continue;
+                };
+                content.push_str(&format!(
+                    "| {} | {} | {} | {:.1}ms | {:.1}ms | {:.1}ms | {} |\n",
+                    test.name,
+                    test.status.as_str(),
+                    metrics.iterations,
+                    metrics.mean_ms,
+                    metrics.p95_ms,
+                    metrics.max_ms,
+                    metrics
+                        .max_rss_kb
+                        .map(|kb| format!("{:.1}MB", kb as f64 / 1024.0))
+                        .unwrap_or_else(|| "-".to_string())
+                ));
+            }
+            content.push('\n');
+        }
+
+        // Performance trends section
+        if !report.performance_trends.is_empty() {
+            content.push_str("## Performance Trends\n\n");
+            content
+                .push_str("| Test | Baseline | Current | Change | p-value | Trend | Verdict |\n");
+            content
+                .push_str("|------|----------|---------|--------|---------|-------|---------|\n");
+            for trend in &report.performance_trends {
+                content.push_str(&format!(
+                    "| {} | {:.1}ms ({}) | {:.1}ms | {:+.1}% | {} | {} | {} |\n",
+                    trend.test_name,
+                    trend.baseline_mean_ms,
+                    trend.baseline_version.clone().unwrap_or_else(|| trend
+                        .baseline_recorded_at
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()),
+                    trend.current_mean_ms,
+                    trend.change_percent,
+                    trend
+                        .p_value
+                        .map(|p| format!("{:.4}", p))
+                        .unwrap_or_else(|| "-".to_string()),
+                    trend
+                        .history_ms
+                        .iter()
+                        .map(|ms| format!("{:.1}", ms))
+                        .collect::<Vec<_>>()
+                        .join(" → "),
+                    if trend.is_significant() {
+                        "⚠️ Regression"
+                    } else {
+                        "No significant change"
+                    }
+                ));
+            }
+            content.push('\n');
+        }
+
         // Environment section
         content.push_str("## Environment\n\n");
         content.push_str("| Property | Value |\n");
@@ -159,10 +332,29 @@ impl MarkdownReporter {
     }
 }
 
+/// Fenced code block nested in a list item
+///
+/// Every line is indented to stay inside the list item, and the fence is
+/// longer than any backtick run in `text` so payloads cannot close it.
+fn list_item_code_block(text: &str) -> String {
+    let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+
+    let mut block = format!("    {}\n", fence);
+    for line in text.trim_end().lines() {
+        block.push_str(&format!("    {}\n", line));
+    }
+    block.push_str(&format!("    {}\n", fence));
+    block
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{EnvironmentInfo, TestResult, TestSuite};
+    use crate::types::report::{EnumMatrix, EnumMatrixCell, SecurityFinding, SecuritySeverity};
+    use crate::types::{
+        EnvironmentInfo, PerformanceMetrics, PerformanceTrend, TestResult, TestSuite,
+    };
     use chrono::Utc;
     use std::time::Duration;
     use tempfile::NamedTempFile;
@@ -182,6 +374,13 @@ mod tests {
                     line_number: Some(5),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "failed test".to_string(),
@@ -193,6 +392,13 @@ mod tests {
                     line_number: Some(10),
                     tags: vec![],
                     priority: crate::types::TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
             ],
             duration: Duration::from_millis(350),
@@ -209,6 +415,8 @@ mod tests {
             finished_at: Utc::now(),
             environment: EnvironmentInfo::default(),
             security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
         }
     }
 
@@ -261,6 +469,13 @@ mod tests {
                 line_number: None,
                 tags: vec![],
                 priority: crate::types::TestPriority::Important,
+                exit_code: None,
+                stderr: String::new(),
+                flaky: false,
+                attempts: vec![],
+                id: None,
+                command: None,
+                performance: None,
             }],
             duration: Duration::from_millis(100),
             started_at: Utc::now(),
@@ -276,6 +491,8 @@ mod tests {
             finished_at: Utc::now(),
             environment: EnvironmentInfo::default(),
             security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
         };
 
         let temp_file = NamedTempFile::new().unwrap();
@@ -284,4 +501,147 @@ mod tests {
         let content = fs::read_to_string(temp_file.path()).unwrap();
         assert!(content.contains("✅ 100% passed"));
     }
+
+    #[test]
+    fn test_markdown_flaky_section() {
+        let mut report = create_test_report();
+        let first = report.suites[0].tests[1].clone();
+        let mut retry = report.suites[0].tests[0].clone();
+        retry.output = "second attempt\nsecond line".to_string();
+        report.suites[0].tests[1] = first.with_retry(retry);
+
+        let temp_file = NamedTempFile::new().unwrap();
+        MarkdownReporter::generate(&report, temp_file.path()).unwrap();
+
+        let content = fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("| Flaky | ⚠️ 1 |"));
+        assert!(content.contains("## Flaky Tests"));
+        assert!(content.contains("**failed test** (passed after 2 attempts)"));
+        assert!(content.contains("Attempt 1: failed in 200ms - `assertion failed`"));
+        assert!(content.contains("Attempt 2: passed in 150ms"));
+        assert!(content.contains("    ```\n    second attempt\n    second line\n    ```\n"));
+    }
+
+    #[test]
+    fn test_markdown_no_flaky_section_without_retries() {
+        let report = create_test_report();
+        let temp_file = NamedTempFile::new().unwrap();
+        MarkdownReporter::generate(&report, temp_file.path()).unwrap();
+
+        let content = fs::read_to_string(temp_file.path()).unwrap();
+        assert!(!content.contains("Flaky"));
+    }
+
+    #[test]
+    fn test_markdown_performance_section() {
+        let mut report = create_test_report();
+        report.suites[0].tests[0].performance = Some(PerformanceMetrics {
+            iterations: 20,
+            mean_ms: 12.34,
+            p95_ms: 15.0,
+            max_ms: 18.25,
+            max_rss_kb: Some(5120),
+            samples_ms: vec![],
+        });
+
+        let temp_file = NamedTempFile::new().unwrap();
+        MarkdownReporter::generate(&report, temp_file.path()).unwrap();
+
+        let content = fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("## Performance"));
+        assert!(content.contains("| 20 | 12.3ms | 15.0ms | 18.2ms | 5.0MB |"));
+    }
+
+    #[test]
+    fn test_markdown_performance_trends() {
+        let mut report = create_test_report();
+        report.performance_trends = vec![PerformanceTrend {
+            test_id: "perf-001".to_string(),
+            test_name: "Startup time".to_string(),
+            baseline_version: Some("1.2.0".to_string()),
+            baseline_recorded_at: Utc::now(),
+            baseline_mean_ms: 10.0,
+            current_mean_ms: 12.5,
+            change_percent: 25.0,
+            p_value: Some(0.0012),
+            history_ms: vec![10.0, 10.2, 12.5],
+        }];
+
+        let temp_file = NamedTempFile::new().unwrap();
+        MarkdownReporter::generate(&report, temp_file.path()).unwrap();
+
+        let content = fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("## Performance Trends"));
+        assert!(content.contains(
+            "| Startup time | 10.0ms (1.2.0) | 12.5ms | +25.0% | 0.0012 | 10.0 → 10.2 → 12.5 | ⚠️ Regression |"
+        ));
+    }
+
+    #[test]
+    fn test_markdown_security_findings() {
+        let mut report = create_test_report();
+        report.security_findings = vec![SecurityFinding {
+            test_name: "Reject command injection".to_string(),
+            category: "injection".to_string(),
+            severity: SecuritySeverity::Critical,
+            description: "Command injection payload was not rejected".to_string(),
+            evidence: "exit status: 0\nstderr:\nuid=0(root)".to_string(),
+            test_file: "security.bats".to_string(),
+            test_id: "security-001".to_string(),
+            command: "test-cli --name '```id```'".to_string(),
+        }];
+
+        let temp_file = NamedTempFile::new().unwrap();
+        MarkdownReporter::generate(&report, temp_file.path()).unwrap();
+
+        let content = fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("## Security Findings"));
+        assert!(
+            content.contains("| CRITICAL | injection | Reject command injection | security-001 |")
+        );
+        assert!(
+            content.contains("  - Command:\n    ````\n    test-cli --name '```id```'\n    ````\n")
+        );
+        assert!(content.contains(
+            "  - Evidence:\n    ```\n    exit status: 0\n    stderr:\n    uid=0(root)\n    ```\n"
+        ));
+    }
+
+    #[test]
+    fn test_markdown_enum_matrix() {
+        let mut report = create_test_report();
+        report.enum_matrices = vec![EnumMatrix {
+            option: "--format".to_string(),
+            cells: vec![
+                EnumMatrixCell {
+                    value: "json".to_string(),
+                    expected_accepted: true,
+                    accepted: Some(true),
+                    test_id: "enum-001-value-json".to_string(),
+                },
+                EnumMatrixCell {
+                    value: "JSON".to_string(),
+                    expected_accepted: true,
+                    accepted: Some(false),
+                    test_id: "enum-001-uppercase".to_string(),
+                },
+                EnumMatrixCell {
+                    value: String::new(),
+                    expected_accepted: false,
+                    accepted: None,
+                    test_id: "enum-001-empty".to_string(),
+                },
+            ],
+        }];
+
+        let temp_file = NamedTempFile::new().unwrap();
+        MarkdownReporter::generate(&report, temp_file.path()).unwrap();
+
+        let content = fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("## Enum Value Matrix"));
+        assert!(content.contains("### `--format` (1 of 3 as expected)"));
+        assert!(content.contains("| `\"json\"` | accept | accepted | ✅ | enum-001-value-json |"));
+        assert!(content.contains("| `\"JSON\"` | accept | rejected | ❌ | enum-001-uppercase |"));
+        assert!(content.contains("| `\"\"` | reject | - | ⏭️ | enum-001-empty |"));
+    }
 }
@@ -8,6 +8,7 @@
 //! - **JSON**: Machine-readable structured data
 //! - **HTML**: Interactive web-based reports with filtering
 //! - **JUnit**: CI/CD compatible XML format
+//! - **SARIF**: SARIF 2.1.0 log of security findings for code scanning
 //!
 //! ## Example Usage
 //!
@@ -27,6 +28,8 @@
 //!     finished_at: Utc::now(),
 //!     environment: Default::default(),
 //!     security_findings: vec![],
+//!     performance_trends: vec![],
+//!     enum_matrices: vec![],
 //! };
 //!
 //! MarkdownReporter::generate(&report, Path::new("report.md"))?;
@@ -50,6 +53,8 @@
 //! #     finished_at: Utc::now(),
 //! #     environment: Default::default(),
 //! #     security_findings: vec![],
+//! #     performance_trends: vec![],
+//! #     enum_matrices: vec![],
 //! # };
 //!
 //! // Generate all formats
@@ -63,9 +68,11 @@ pub mod html;
 pub mod json;
 pub mod junit;
 pub mod markdown;
+pub mod sarif;
 
 // Re-export reporters
 pub use html::HtmlReporter;
 pub use json::JsonReporter;
 pub use junit::JunitReporter;
 pub use markdown::MarkdownReporter;
+pub use sarif::SarifReporter;
@@ -0,0 +1,407 @@
+use crate::error::Result;
+use crate::runner::security_findings::vulnerability_class;
+use crate::types::report::{SecurityFinding, SecuritySeverity};
+use crate::types::TestReport;
+use crate::utils::write_json_optimized;
+use serde::Serialize;
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "cli-testing-specialist";
+const TOOL_INFORMATION_URI: &str = "https://github.com/sanae-abe/cli-testing-specialist";
+/// URI base that relative artifact locations are resolved against
+const SRCROOT: &str = "%SRCROOT%";
+
+/// SARIF log (top-level object)
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    name: String,
+    short_description: SarifMessage,
+    full_description: SarifMessage,
+    help: SarifMessage,
+    default_configuration: SarifConfiguration,
+    properties: SarifRuleProperties,
+}
+
+#[derive(Serialize)]
+struct SarifConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifRuleProperties {
+    tags: Vec<String>,
+
+    /// Numeric severity (0.0-10.0) used by code scanning dashboards
+    #[serde(rename = "security-severity")]
+    security_severity: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    physical_location: Option<SarifPhysicalLocation>,
+    logical_locations: Vec<SarifLogicalLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLogicalLocation {
+    name: String,
+    kind: &'static str,
+}
+
+/// SARIF 2.1.0 report generator for security findings
+pub struct SarifReporter;
+
+impl SarifReporter {
+    /// Generate SARIF log from the report's security findings
+    ///
+    /// Each finding becomes one `result`; its rule id is the generated test id
+    /// (e.g. `security-001`) and its rule metadata describes the vulnerability class.
+    pub fn generate(report: &TestReport, output_path: &Path) -> Result<()> {
+        write_json_optimized(&Self::build_log(report), output_path)?;
+
+        Ok(())
+    }
+
+    fn build_log(report: &TestReport) -> SarifLog {
+        let mut rules: Vec<SarifRule> = Vec::new();
+        let mut results = Vec::new();
+
+        for finding in &report.security_findings {
+            let rule_id = Self::rule_id(finding);
+            let rule_index = match rules.iter().position(|rule| rule.id == rule_id) {
+                Some(index) => index,
+                None => {
+                    rules.push(Self::build_rule(finding, rule_id.clone()));
+                    rules.len() - 1
+                }
+            };
+
+            results.push(SarifResult {
+                rule_id,
+                rule_index,
+                level: Self::level(finding.severity),
+                message: SarifMessage {
+                    text: Self::message(finding),
+                },
+                locations: vec![Self::location(finding)],
+            });
+        }
+
+        SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: TOOL_NAME,
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: TOOL_INFORMATION_URI,
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+
+    /// Rule id from the test id, falling back to the category for older reports
+    fn rule_id(finding: &SecurityFinding) -> String {
+        if finding.test_id.is_empty() {
+            finding.category.clone()
+        } else {
+            finding.test_id.clone()
+        }
+    }
+
+    fn build_rule(finding: &SecurityFinding, id: String) -> SarifRule {
+        let class = vulnerability_class(&finding.category);
+
+        let mut tags = vec!["security".to_string(), finding.category.clone()];
+        if let Some(class) = class {
+            tags.push(class.cwe.to_string());
+        }
+
+        SarifRule {
+            id,
+            name: class
+                .map(|class| class.name.to_string())
+                .unwrap_or_else(|| finding.test_name.clone()),
+            short_description: SarifMessage {
+                text: class
+                    .map(|class| class.summary.to_string())
+                    .unwrap_or_else(|| finding.test_name.clone()),
+            },
+            full_description: SarifMessage {
+                text: class
+                    .map(|class| class.full_description.to_string())
+                    .unwrap_or_else(|| format!("Security check failed: {}", finding.test_name)),
+            },
+            help: SarifMessage {
+                text: class
+                    .map(|class| class.help.to_string())
+                    .unwrap_or_else(|| "Review the failing security check.".to_string()),
+            },
+            default_configuration: SarifConfiguration {
+                level: Self::level(finding.severity),
+            },
+            properties: SarifRuleProperties {
+                tags,
+                security_severity: Self::security_severity(finding.severity),
+            },
+        }
+    }
+
+    /// Map severity to a SARIF result level
+    fn level(severity: SecuritySeverity) -> &'static str {
+        match severity {
+            SecuritySeverity::Critical | SecuritySeverity::High => "error",
+            SecuritySeverity::Medium => "warning",
+            SecuritySeverity::Low | SecuritySeverity::Info => "note",
+        }
+    }
+
+    /// Map severity to a CVSS-style score understood by GitHub code scanning
+    fn security_severity(severity: SecuritySeverity) -> &'static str {
+        match severity {
+            SecuritySeverity::Critical => "9.5",
+            SecuritySeverity::High => "8.0",
+            SecuritySeverity::Medium => "5.5",
+            SecuritySeverity::Low => "3.0",
+            SecuritySeverity::Info => "0.0",
+        }
+    }
+
+    fn message(finding: &SecurityFinding) -> String {
+        let mut text = format!("{}: {}", finding.test_name, finding.description);
+        if !finding.command.is_empty() {
+            text.push_str(&format!("\nCommand: {}", finding.command));
+        }
+        text
+    }
+
+    fn location(finding: &SecurityFinding) -> SarifLocation {
+        SarifLocation {
+            physical_location: (!finding.test_file.is_empty()).then(|| SarifPhysicalLocation {
+                artifact_location: Self::artifact_location(&finding.test_file),
+            }),
+            logical_locations: vec![SarifLogicalLocation {
+                name: Self::rule_id(finding),
+                kind: "test",
+            }],
+        }
+    }
+
+    /// Percent-encoded artifact URI for a test file path
+    ///
+    /// Absolute paths become `file://` URIs; relative paths stay relative to
+    /// the `%SRCROOT%` base.
+    fn artifact_location(test_file: &str) -> SarifArtifactLocation {
+        let path = test_file.replace('\\', "/");
+        let encoded = percent_encode_path(&path);
+
+        if Path::new(test_file).is_absolute() || path.starts_with('/') {
+            let separator = if encoded.starts_with('/') { "" } else { "/" };
+            SarifArtifactLocation {
+                uri: format!("file://{}{}", separator, encoded),
+                uri_base_id: None,
+            }
+        } else {
+            SarifArtifactLocation {
+                uri: encoded,
+                uri_base_id: Some(SRCROOT),
+            }
+        }
+    }
+}
+
+/// Percent-encode every byte of a `/`-separated path that is not unreserved
+fn percent_encode_path(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EnvironmentInfo;
+    use chrono::Utc;
+    use std::fs;
+    use std::time::Duration;
+    use tempfile::NamedTempFile;
+
+    fn finding(test_id: &str, category: &str, severity: SecuritySeverity) -> SecurityFinding {
+        SecurityFinding {
+            test_name: format!("{} test", category),
+            category: category.to_string(),
+            severity,
+            description: "Command injection payload was not rejected".to_string(),
+            evidence: "exit status: 0".to_string(),
+            test_file: "security.bats".to_string(),
+            test_id: test_id.to_string(),
+            command: "test-cli --name 'test; rm -rf /'".to_string(),
+        }
+    }
+
+    fn create_test_report(security_findings: Vec<SecurityFinding>) -> TestReport {
+        TestReport {
+            binary_name: "test-cli".to_string(),
+            binary_version: Some("1.0.0".to_string()),
+            suites: vec![],
+            total_duration: Duration::from_secs(1),
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+            environment: EnvironmentInfo::default(),
+            security_findings,
+            performance_trends: vec![],
+            enum_matrices: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sarif_generation() {
+        let report = create_test_report(vec![
+            finding("security-001", "injection", SecuritySeverity::Critical),
+            finding("security-003", "path-traversal", SecuritySeverity::Medium),
+        ]);
+        let temp_file = NamedTempFile::new().unwrap();
+
+        SarifReporter::generate(&report, temp_file.path()).unwrap();
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp_file.path()).unwrap()).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(sarif["$schema"], SARIF_SCHEMA);
+
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "cli-testing-specialist");
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["id"], "security-001");
+        assert_eq!(rules[0]["name"], "CommandInjection");
+        assert_eq!(rules[0]["properties"]["security-severity"], "9.5");
+        assert!(rules[0]["properties"]["tags"]
+            .as_array()
+            .unwrap()
+            .contains(&"CWE-78".into()));
+        assert_eq!(rules[1]["name"], "PathTraversal");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "security-001");
+        assert_eq!(results[0]["level"], "error");
+        assert!(results[0]["message"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Command: test-cli --name 'test; rm -rf /'"));
+        let artifact = &results[0]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(artifact["uri"], "security.bats");
+        assert_eq!(artifact["uriBaseId"], "%SRCROOT%");
+        assert_eq!(results[1]["ruleIndex"], 1);
+        assert_eq!(results[1]["level"], "warning");
+    }
+
+    #[test]
+    fn test_sarif_artifact_uris_are_encoded() {
+        let absolute = SarifReporter::artifact_location("/tmp/my tests/security#1.bats");
+        assert_eq!(absolute.uri, "file:///tmp/my%20tests/security%231.bats");
+        assert_eq!(absolute.uri_base_id, None);
+
+        let relative = SarifReporter::artifact_location("out dir/security.bats");
+        assert_eq!(relative.uri, "out%20dir/security.bats");
+        assert_eq!(relative.uri_base_id, Some("%SRCROOT%"));
+    }
+
+    #[test]
+    fn test_sarif_without_findings() {
+        let log = SarifReporter::build_log(&create_test_report(vec![]));
+
+        assert!(log.runs[0].results.is_empty());
+        assert!(log.runs[0].tool.driver.rules.is_empty());
+    }
+
+    #[test]
+    fn test_sarif_rule_fallbacks() {
+        let mut unknown = finding("", "secrets-leak", SecuritySeverity::Low);
+        unknown.test_file = String::new();
+
+        let log = SarifReporter::build_log(&create_test_report(vec![unknown]));
+        let result = &log.runs[0].results[0];
+
+        assert_eq!(result.rule_id, "secrets-leak");
+        assert_eq!(result.level, "note");
+        assert!(result.locations[0].physical_location.is_none());
+        assert_eq!(log.runs[0].tool.driver.rules[0].name, "secrets-leak test");
+    }
+}
@@ -1,19 +1,46 @@
 use crate::error::{Error, Result};
-use crate::types::{EnvironmentInfo, TestReport, TestResult, TestStatus, TestSuite};
+use crate::runner::enum_matrix::extract_enum_matrices;
+use crate::runner::native_executor::{kill_process_tree, spawn_reader};
+use crate::runner::security_findings::extract_security_findings;
+use crate::types::{
+    EnvironmentInfo, PerformanceMetrics, TestPriority, TestReport, TestResult, TestStatus,
+    TestSuite,
+};
 use chrono::Utc;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use log::{debug, info, warn};
+use rayon::prelude::*;
 use regex::Regex;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
+use wait_timeout::ChildExt;
+
+/// Interval between "still running" progress messages while a suite executes
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Captured output of one `bats` invocation
+struct BatsRun {
+    stdout: String,
+    stderr: String,
+    timed_out: bool,
+}
 
 /// BATS test executor with TAP (Test Anything Protocol) parser
 pub struct BatsExecutor {
     /// Timeout per test suite in seconds
     timeout: u64,
 
+    /// Default timeout per test case in seconds (passed to BATS as `BATS_TEST_TIMEOUT`)
+    test_timeout: Option<u64>,
+
+    /// Number of times a failed test is retried
+    retry_count: u32,
+
+    /// Number of suites executed concurrently
+    jobs: usize,
+
     /// Binary name being tested
     binary_name: String,
 
@@ -29,6 +56,9 @@ impl BatsExecutor {
     pub fn new(binary_name: String, binary_version: Option<String>) -> Self {
         Self {
             timeout: 300,
+            test_timeout: None,
+            retry_count: 0,
+            jobs: 1,
             binary_name,
             binary_version,
             skip_categories: None,
@@ -39,6 +69,9 @@ impl BatsExecutor {
     pub fn with_timeout(binary_name: String, binary_version: Option<String>, timeout: u64) -> Self {
         Self {
             timeout,
+            test_timeout: None,
+            retry_count: 0,
+            jobs: 1,
             binary_name,
             binary_version,
             skip_categories: None,
@@ -51,6 +84,29 @@ impl BatsExecutor {
         self
     }
 
+    /// Set how many suites run concurrently (default: 1)
+    ///
+    /// Results are merged in file order regardless of completion order.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs.max(1);
+        self
+    }
+
+    /// Set how many times a failed test is retried (each retry runs only that test)
+    pub fn with_retry_count(mut self, retry_count: u32) -> Self {
+        self.retry_count = retry_count;
+        self
+    }
+
+    /// Set the default timeout for each test case in seconds
+    ///
+    /// Tests that exceed it are reported as [`TestStatus::Timeout`] while the
+    /// remaining tests in the suite keep running (requires bats-core 1.7+).
+    pub fn with_test_timeout(mut self, seconds: u64) -> Self {
+        self.test_timeout = Some(seconds);
+        self
+    }
+
     /// Verify BATS is installed and available
     ///
     /// # Examples
@@ -131,6 +187,35 @@ impl BatsExecutor {
         Ok(bats_files)
     }
 
+    /// Binary name and path the generated suites in `test_dir` run against
+    ///
+    /// Read from the `CLI_BINARY` and `BINARY_BASENAME` assignments that the
+    /// generator writes into each file's `setup()`.
+    pub fn find_target_binary(test_dir: &Path) -> Option<(String, PathBuf)> {
+        let path_re = Regex::new(r#"^\s*CLI_BINARY="([^"]+)""#).unwrap();
+        let name_re = Regex::new(r#"^\s*BINARY_BASENAME="([^"]+)""#).unwrap();
+
+        for bats_file in Self::find_bats_files(test_dir).ok()? {
+            let Ok(content) = fs::read_to_string(&bats_file) else {
+                continue;
+            };
+            let capture = |re: &Regex| {
+                content
+                    .lines()
+                    .find_map(|line| re.captures(line).map(|caps| caps[1].to_string()))
+            };
+            let Some(path) = capture(&path_re).map(PathBuf::from) else {
+                continue;
+            };
+            let name = capture(&name_re)
+                .or_else(|| path.file_name().map(|n| n.to_string_lossy().into_owned()))
+                .unwrap_or_else(|| path.display().to_string());
+            return Some((name, path));
+        }
+
+        None
+    }
+
     /// Execute all BATS files and generate report
     pub fn run_tests(&self, test_dir: &Path) -> Result<TestReport> {
         let start_time = Instant::now();
@@ -163,15 +248,16 @@ impl BatsExecutor {
             }
         }
 
-        info!("Executing {} test suites", bats_files.len());
-
-        // Create single tokio runtime for all test executions
-        let runtime = tokio::runtime::Runtime::new().map_err(|e| {
-            Error::BatsExecutionFailed(format!("Failed to create async runtime: {}", e))
-        })?;
+        let jobs = self.jobs.clamp(1, bats_files.len().max(1));
+        info!(
+            "Executing {} test suites with {} worker(s)",
+            bats_files.len(),
+            jobs
+        );
 
-        // Create progress bar
-        let pb = ProgressBar::new(bats_files.len() as u64);
+        // Overall progress bar plus one spinner per running suite
+        let multi = MultiProgress::new();
+        let pb = multi.add(ProgressBar::new(bats_files.len() as u64));
         pb.set_style(
             ProgressStyle::default_bar()
                 .template(
@@ -181,67 +267,26 @@ impl BatsExecutor {
                 .progress_chars("#>-"),
         );
 
-        // Execute each BATS file
-        let mut suites = Vec::new();
-        for bats_file in bats_files.iter() {
-            let suite_name = bats_file
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("unknown");
-
-            let suite_start_time = Instant::now();
-            pb.set_message(format!(
-                "Running {} (timeout: {}s)",
-                suite_name, self.timeout
-            ));
-
-            match self.execute_suite(bats_file, &runtime) {
-                Ok(suite) => {
-                    let passed = suite.passed_count();
-                    let total = suite.total_count();
-                    let elapsed = suite_start_time.elapsed();
-
-                    info!(
-                        "Suite '{}': {}/{} tests passed in {:.1}s",
-                        suite.name,
-                        passed,
-                        total,
-                        elapsed.as_secs_f64()
-                    );
-
-                    pb.set_message(format!(
-                        "{} ✓ ({}/{}) {:.1}s",
-                        suite_name,
-                        passed,
-                        total,
-                        elapsed.as_secs_f64()
-                    ));
-                    suites.push(suite);
-                }
-                Err(e) => {
-                    let elapsed = suite_start_time.elapsed();
-                    warn!(
-                        "Failed to execute suite '{}' after {:.1}s: {}",
-                        suite_name,
-                        elapsed.as_secs_f64(),
-                        e
-                    );
-                    pb.set_message(format!(
-                        "{} ✗ (timeout after {:.0}s)",
-                        suite_name,
-                        elapsed.as_secs_f64()
-                    ));
-
-                    // Print user-friendly error message
-                    eprintln!("\n⚠️  Warning: {}", e);
-                    eprintln!("    Continuing with remaining test suites...\n");
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .map_err(|e| {
+                Error::BatsExecutionFailed(format!("Failed to create worker pool: {}", e))
+            })?;
 
-                    // Continue with other suites
-                }
-            }
+        // Execute BATS files on the bounded pool; collect() keeps file order
+        let results: Vec<Result<TestSuite>> = pool.install(|| {
+            bats_files
+                .par_iter()
+                .map(|bats_file| {
+                    let result = self.run_suite_with_progress(bats_file, &multi);
+                    pb.inc(1);
+                    result
+                })
+                .collect()
+        });
 
-            pb.inc(1);
-        }
+        let suites: Vec<TestSuite> = results.into_iter().filter_map(|r| r.ok()).collect();
 
         pb.finish_with_message("All test suites completed");
 
@@ -251,6 +296,9 @@ impl BatsExecutor {
         // Gather environment information
         let environment = self.gather_environment_info(bats_version);
 
+        let security_findings = extract_security_findings(&suites);
+        let enum_matrices = extract_enum_matrices(&suites);
+
         Ok(TestReport {
             binary_name: self.binary_name.clone(),
             binary_version: self.binary_version.clone(),
@@ -259,106 +307,166 @@ impl BatsExecutor {
             started_at,
             finished_at,
             environment,
-            security_findings: vec![], // TODO: Extract from SecurityCheck tests
+            security_findings,
+            performance_trends: vec![],
+            enum_matrices,
         })
     }
 
-    /// Execute a single BATS suite with timeout
-    fn execute_suite(
+    /// Execute one suite with its own spinner, logging the outcome
+    ///
+    /// Errors are reported here so the remaining suites keep running.
+    fn run_suite_with_progress(
         &self,
         bats_file: &Path,
-        runtime: &tokio::runtime::Runtime,
+        multi: &MultiProgress,
     ) -> Result<TestSuite> {
+        let suite_name = bats_file
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let spinner = multi.add(ProgressBar::new_spinner());
+        spinner.set_style(
+            ProgressStyle::default_spinner()
+                .template("  {spinner:.cyan} {msg}")
+                .unwrap(),
+        );
+        spinner.enable_steady_tick(Duration::from_millis(120));
+        spinner.set_message(format!(
+            "Running {} (timeout: {}s)",
+            suite_name, self.timeout
+        ));
+
+        let suite_start_time = Instant::now();
+        let result = self.execute_suite(bats_file, &spinner);
+        let elapsed = suite_start_time.elapsed();
+
+        match &result {
+            Ok(suite) => {
+                let passed = suite.passed_count();
+                let total = suite.total_count();
+                let timed_out = suite
+                    .tests
+                    .iter()
+                    .filter(|t| t.status == TestStatus::Timeout)
+                    .count();
+
+                info!(
+                    "Suite '{}': {}/{} tests passed in {:.1}s",
+                    suite.name,
+                    passed,
+                    total,
+                    elapsed.as_secs_f64()
+                );
+
+                if timed_out > 0 {
+                    multi.suspend(|| {
+                        eprintln!(
+                            "  {} ⏱ ({}/{}, {} timed out) {:.1}s",
+                            suite_name,
+                            passed,
+                            total,
+                            timed_out,
+                            elapsed.as_secs_f64()
+                        )
+                    });
+                }
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to execute suite '{}' after {:.1}s: {}",
+                    suite_name,
+                    elapsed.as_secs_f64(),
+                    e
+                );
+
+                // Print user-friendly error message
+                multi.suspend(|| {
+                    eprintln!("\n⚠️  Warning: {}", e);
+                    eprintln!("    Continuing with remaining test suites...\n");
+                });
+            }
+        }
+
+        spinner.finish_and_clear();
+        multi.remove(&spinner);
+        result
+    }
+
+    /// Execute a single BATS suite with timeout
+    ///
+    /// If the suite timeout fires, BATS is killed and the results reported so far
+    /// are kept: the test that was running is recorded as [`TestStatus::Timeout`]
+    /// with its partial output, and tests that never started are recorded as skipped.
+    fn execute_suite(&self, bats_file: &Path, progress: &ProgressBar) -> Result<TestSuite> {
         let suite_start = Instant::now();
         let started_at = Utc::now();
 
         let suite_name = bats_file
             .file_stem()
             .and_then(|s| s.to_str())
-            .unwrap_or("unknown");
+            .unwrap_or("unknown")
+            .to_string();
 
         debug!("Executing BATS file: {}", bats_file.display());
 
-        // Execute BATS with TAP output and timeout with periodic progress updates
-        let timeout_duration = std::time::Duration::from_secs(self.timeout);
-        let bats_file_path = bats_file.to_path_buf();
-        let suite_name_clone = suite_name.to_string();
-
-        let output = runtime
-            .block_on(async move {
-                // Wrap execution in timeout
-                tokio::time::timeout(timeout_duration, async move {
-                    let mut execution = tokio::task::spawn_blocking(move || {
-                        Command::new("bats")
-                            .arg("--formatter")
-                            .arg("tap")
-                            .arg("--verbose-run")
-                            .arg(&bats_file_path)
-                            .stdout(Stdio::piped())
-                            .stderr(Stdio::piped())
-                            .output()
-                    });
+        let BatsRun {
+            stdout,
+            stderr,
+            timed_out,
+        } = self.run_bats(bats_file, None, &suite_name, progress)?;
 
-                    // Progress ticker that prints every 30 seconds
-                    let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
-                    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-
-                    let mut elapsed_secs = 0u64;
-                    let timeout_secs = timeout_duration.as_secs();
-
-                    loop {
-                        tokio::select! {
-                            result = &mut execution => {
-                                // result is Result<Result<Output, io::Error>, JoinError>
-                                return result.map_err(|e| std::io::Error::other(
-                                    format!("Task join error: {}", e)
-                                ))?;
-                            }
-                            _ = interval.tick() => {
-                                elapsed_secs += 30;
-                                if elapsed_secs < timeout_secs {
-                                    eprintln!("  ⏳ Still running '{}' ({}/{}s elapsed)...",
-                                        suite_name_clone, elapsed_secs, timeout_secs);
-                                }
-                            }
-                        }
-                    }
-                })
-                .await
-            })
-            .map_err(|_| {
-                // Timeout error from tokio::time::timeout
-                Error::BatsExecutionFailed(format!(
-                    "Test suite '{}' timed out after {} seconds. \
+        // Parse TAP output
+        let mut tests = self.parse_tap_output(&stdout, bats_file)?;
+
+        // Test ID, tags, priority and command come from the generated BATS file
+        let metadata = fs::read_to_string(bats_file)
+            .map(|content| extract_test_metadata(&content))
+            .unwrap_or_default();
+
+        if timed_out {
+            warn!(
+                "Test suite '{}' timed out after {} seconds; keeping {} reported result(s)",
+                suite_name,
+                self.timeout,
+                tests.len()
+            );
+            progress.suspend(|| {
+                eprintln!(
+                    "\n⚠️  Warning: Test suite '{}' timed out after {} seconds. \
                      This may indicate a hanging test (e.g., waiting for user input). \
                      Check the test file: {}",
                     suite_name,
                     self.timeout,
                     bats_file.display()
-                ))
-            })?
-            .map_err(|e| Error::BatsExecutionFailed(format!("Failed to execute BATS: {}", e)))?;
+                )
+            });
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let test_names: Vec<String> = metadata.iter().map(|m| m.name.clone()).collect();
+            let partial_output = trailing_output(&stdout);
+            self.record_unfinished_tests(
+                &mut tests,
+                &test_names,
+                bats_file,
+                partial_output,
+                stderr,
+            );
+        }
 
-        debug!("BATS stdout:\n{}", stdout);
-        if !stderr.is_empty() {
-            debug!("BATS stderr:\n{}", stderr);
+        if self.retry_count > 0 {
+            self.retry_failed_tests(&mut tests, bats_file, &suite_name, progress);
         }
 
-        // Parse TAP output
-        let tests = self.parse_tap_output(&stdout, bats_file)?;
+        for test in tests.iter_mut() {
+            if let Some(meta) = metadata.iter().find(|m| m.name == test.name) {
+                meta.apply_to(test);
+            }
+        }
 
         let duration = suite_start.elapsed();
         let finished_at = Utc::now();
 
-        let suite_name = bats_file
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown")
-            .to_string();
-
         Ok(TestSuite {
             name: suite_name,
             file_path: bats_file.to_string_lossy().to_string(),
@@ -369,9 +477,226 @@ impl BatsExecutor {
         })
     }
 
+    /// Run `bats` on a file (optionally filtered to matching test names) under the suite timeout
+    fn run_bats(
+        &self,
+        bats_file: &Path,
+        filter: Option<&str>,
+        label: &str,
+        progress: &ProgressBar,
+    ) -> Result<BatsRun> {
+        let start = Instant::now();
+        let mut command = Command::new("bats");
+
+        // Own process group so a suite timeout also kills the commands under test
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        if let Some(test_timeout) = self.test_timeout {
+            command.env("BATS_TEST_TIMEOUT", test_timeout.to_string());
+        }
+
+        if let Some(filter) = filter {
+            command.arg("--filter").arg(filter);
+        }
+
+        let mut child = command
+            .arg("--formatter")
+            .arg("tap")
+            .arg("--verbose-run")
+            .arg(bats_file)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::BatsExecutionFailed(format!("Failed to execute BATS: {}", e)))?;
+
+        let stdout_reader = spawn_reader(child.stdout.take());
+        let stderr_reader = spawn_reader(child.stderr.take());
+
+        // Wait in slices so long-running suites print periodic progress
+        let timeout_duration = Duration::from_secs(self.timeout);
+        let mut timed_out = false;
+        loop {
+            let remaining = timeout_duration.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                kill_process_tree(&mut child);
+                let _ = child.wait();
+                timed_out = true;
+                break;
+            }
+
+            if child
+                .wait_timeout(remaining.min(PROGRESS_INTERVAL))
+                .map_err(|e| Error::BatsExecutionFailed(format!("Failed to wait for BATS: {}", e)))?
+                .is_some()
+            {
+                break;
+            }
+
+            let elapsed_secs = start.elapsed().as_secs();
+            if elapsed_secs < self.timeout {
+                progress.set_message(format!(
+                    "⏳ Still running '{}' ({}/{}s elapsed)...",
+                    label, elapsed_secs, self.timeout
+                ));
+            }
+        }
+
+        let stdout = stdout_reader.join().unwrap_or_default();
+        let stderr = stderr_reader.join().unwrap_or_default();
+
+        debug!("BATS stdout:\n{}", stdout);
+        if !stderr.is_empty() {
+            debug!("BATS stderr:\n{}", stderr);
+        }
+
+        Ok(BatsRun {
+            stdout,
+            stderr,
+            timed_out,
+        })
+    }
+
+    /// Re-run each failed test on its own, up to `retry_count` times
+    ///
+    /// Every attempt is kept on the result; a pass after a failure marks it flaky.
+    fn retry_failed_tests(
+        &self,
+        tests: &mut [TestResult],
+        bats_file: &Path,
+        suite_name: &str,
+        progress: &ProgressBar,
+    ) {
+        for test in tests.iter_mut() {
+            for attempt in 1..=self.retry_count {
+                if !test.status.is_failure() {
+                    break;
+                }
+                debug!(
+                    "Retrying '{}' (attempt {}/{})",
+                    test.name,
+                    attempt + 1,
+                    self.retry_count + 1
+                );
+
+                let filter = format!("^{}$", escape_ere(&test.name));
+                let label = format!("{} (retry {})", suite_name, attempt);
+                progress.set_message(format!("Retrying '{}' in {}", test.name, label));
+                let retry = match self.run_bats(bats_file, Some(&filter), &label, progress) {
+                    Ok(run) => self.retry_result(&run, &test.name, bats_file),
+                    Err(e) => {
+                        warn!("Failed to retry '{}': {}", test.name, e);
+                        break;
+                    }
+                };
+
+                *test = test.clone().with_retry(retry);
+            }
+
+            if test.flaky {
+                warn!(
+                    "Flaky test '{}': passed after {} attempts",
+                    test.name,
+                    test.attempts.len()
+                );
+            }
+        }
+    }
+
+    /// Extract the result of a single-test retry run
+    fn retry_result(&self, run: &BatsRun, name: &str, bats_file: &Path) -> TestResult {
+        let reported = self
+            .parse_tap_output(&run.stdout, bats_file)
+            .ok()
+            .and_then(|results| results.into_iter().find(|t| t.name == name));
+
+        reported.unwrap_or_else(|| {
+            let (status, message) = if run.timed_out {
+                (
+                    TestStatus::Timeout,
+                    format!("Retry timed out after {}s", self.timeout),
+                )
+            } else {
+                (
+                    TestStatus::Failed,
+                    "Retry did not report a result".to_string(),
+                )
+            };
+            let mut result = self.unfinished_result(name.to_string(), bats_file, status);
+            result.error_message = Some(message);
+            result.output = trailing_output(&run.stdout);
+            result.stderr = run.stderr.clone();
+            result
+        })
+    }
+
+    /// Record tests that did not report before a suite timeout
+    ///
+    /// The first unreported test is the one that hung (Timeout, with the partial
+    /// output); the rest never started (Skipped).
+    fn record_unfinished_tests(
+        &self,
+        tests: &mut Vec<TestResult>,
+        test_names: &[String],
+        bats_file: &Path,
+        partial_output: String,
+        stderr: String,
+    ) {
+        let mut pending = test_names
+            .iter()
+            .filter(|name| !tests.iter().any(|t| &t.name == *name))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let Some(hung) = pending.next() else {
+            return;
+        };
+
+        let mut hung_result = self.unfinished_result(hung, bats_file, TestStatus::Timeout);
+        hung_result.error_message = Some(format!(
+            "Test suite timed out after {}s while this test was running",
+            self.timeout
+        ));
+        hung_result.output = partial_output;
+        hung_result.stderr = stderr;
+        tests.push(hung_result);
+
+        for name in pending {
+            let mut skipped = self.unfinished_result(name, bats_file, TestStatus::Skipped);
+            skipped.error_message = Some("Not run: test suite timed out".to_string());
+            tests.push(skipped);
+        }
+    }
+
+    fn unfinished_result(&self, name: String, bats_file: &Path, status: TestStatus) -> TestResult {
+        TestResult {
+            name,
+            status,
+            duration: Duration::ZERO,
+            output: String::new(),
+            error_message: None,
+            file_path: bats_file.to_string_lossy().to_string(),
+            line_number: None,
+            tags: vec![],
+            priority: TestPriority::Important,
+            exit_code: None,
+            stderr: String::new(),
+            flaky: false,
+            attempts: vec![],
+            id: None,
+            command: None,
+            performance: None,
+        }
+    }
+
     /// Parse TAP (Test Anything Protocol) output from BATS
     fn parse_tap_output(&self, output: &str, bats_file: &Path) -> Result<Vec<TestResult>> {
-        let mut tests = Vec::new();
+        let mut tests: Vec<TestResult> = Vec::new();
         let lines: Vec<&str> = output.lines().collect();
 
         // TAP format:
@@ -382,6 +707,13 @@ impl BatsExecutor {
 
         let test_line_re = Regex::new(r"^(ok|not ok)\s+(\d+)\s+(.+)$").unwrap();
         let skip_re = Regex::new(r"#\s*skip").unwrap();
+        // bats-core BATS_TEST_TIMEOUT directive, or the diagnostic written for
+        // per-test timeout overrides by BatsWriter
+        let timeout_re = Regex::new(r"(?i)#\s*timeout after (\d+)s").unwrap();
+        let timeout_diag_re = Regex::new(r"(?i)^timeout after (\d+)s$").unwrap();
+
+        // Benchmark results are written to FD 3 while the test runs, i.e. before its TAP line
+        let mut pending_performance: Option<PerformanceMetrics> = None;
 
         for line in lines {
             if let Some(caps) = test_line_re.captures(line) {
@@ -391,35 +723,71 @@ impl BatsExecutor {
 
                 // Check if test was skipped
                 let is_skipped = skip_re.is_match(test_name);
+                let timeout_secs = timeout_re.captures(test_name).map(|c| c[1].to_string());
 
                 let status = if is_skipped {
                     TestStatus::Skipped
+                } else if timeout_secs.is_some() {
+                    TestStatus::Timeout
                 } else if status_str == "ok" {
                     TestStatus::Passed
                 } else {
                     TestStatus::Failed
                 };
 
-                // Extract clean test name (remove skip directive)
-                let clean_name = skip_re.replace(test_name, "").trim().to_string();
+                // Extract clean test name (remove skip/timeout directive)
+                let clean_name = skip_re.replace(test_name, "");
+                let clean_name = timeout_re.replace(&clean_name, "").trim().to_string();
 
                 tests.push(TestResult {
                     name: clean_name,
                     status,
                     duration: Duration::from_millis(100), // Default duration, BATS doesn't provide timing
                     output: String::new(),
-                    error_message: if status == TestStatus::Failed {
-                        Some(format!("Test {} failed", test_num))
-                    } else {
-                        None
+                    error_message: match (status, timeout_secs) {
+                        (TestStatus::Timeout, Some(secs)) => {
+                            Some(format!("Test timed out after {}s", secs))
+                        }
+                        (TestStatus::Failed, _) => Some(format!("Test {} failed", test_num)),
+                        _ => None,
                     },
                     file_path: bats_file.to_string_lossy().to_string(),
                     line_number: None,
-                    tags: vec![], // TODO: Extract from BATS comments
-                    priority: crate::types::TestPriority::Important, // TODO: Determine from test metadata
+                    tags: vec![],                      // Filled from BATS file metadata
+                    priority: TestPriority::Important, // Filled from BATS file metadata
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: pending_performance.take(),
                 });
 
                 debug!("Parsed test: {} - {:?}", test_name, status);
+            } else if let Some(diagnostic) = line.strip_prefix('#') {
+                let diagnostic = diagnostic.strip_prefix(' ').unwrap_or(diagnostic);
+
+                if let Some(metrics) = PerformanceMetrics::parse_diagnostic(diagnostic) {
+                    pending_performance = Some(metrics);
+                    continue;
+                }
+
+                // Other diagnostics belong to the preceding test (failure details and output)
+                let Some(test) = tests.last_mut() else {
+                    continue;
+                };
+
+                if test.status == TestStatus::Failed {
+                    if let Some(caps) = timeout_diag_re.captures(diagnostic.trim()) {
+                        test.status = TestStatus::Timeout;
+                        test.error_message = Some(format!("Test timed out after {}s", &caps[1]));
+                        continue;
+                    }
+                }
+
+                test.output.push_str(diagnostic);
+                test.output.push('\n');
             }
         }
 
@@ -469,6 +837,102 @@ impl BatsExecutor {
     }
 }
 
+/// Escape a test name for use in a POSIX extended regex (`bats --filter`)
+fn escape_ere(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.[]()*+?{}|^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Metadata of one `@test` block, read from the comments written by `BatsWriter`
+#[derive(Debug, Clone, Default, PartialEq)]
+struct BatsTestMetadata {
+    name: String,
+    line_number: usize,
+    id: Option<String>,
+    tags: Vec<String>,
+    priority: Option<TestPriority>,
+    command: Option<String>,
+}
+
+impl BatsTestMetadata {
+    /// Fill in the result fields that TAP output does not carry
+    fn apply_to(&self, result: &mut TestResult) {
+        result.line_number = Some(self.line_number);
+        result.id = self.id.clone();
+        result.tags = self.tags.clone();
+        if let Some(priority) = self.priority {
+            result.priority = priority;
+        }
+        result.command = self.command.clone();
+    }
+}
+
+/// Extract `@test` blocks (name, line, Test ID, Tags, Priority, `run` command) in file order
+fn extract_test_metadata(content: &str) -> Vec<BatsTestMetadata> {
+    let test_re = Regex::new(r#"^\s*@test\s+"((?:[^"\\]|\\.)*)"\s*\{"#).unwrap();
+    let comment_re = Regex::new(r"^\s*#\s*(Test ID|Tags|Priority):\s*(.*)$").unwrap();
+    let run_re = Regex::new(r"^\s*run\s+(.+)$").unwrap();
+
+    let mut tests: Vec<BatsTestMetadata> = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if let Some(caps) = test_re.captures(line) {
+            tests.push(BatsTestMetadata {
+                name: caps[1].replace("\\\"", "\""),
+                line_number: index + 1,
+                ..Default::default()
+            });
+            continue;
+        }
+
+        let Some(test) = tests.last_mut() else {
+            continue;
+        };
+
+        if let Some(caps) = comment_re.captures(line) {
+            let value = caps[2].trim();
+            match &caps[1] {
+                "Test ID" => test.id = Some(value.to_string()),
+                "Tags" => {
+                    test.tags = value
+                        .split(',')
+                        .map(|t| t.trim().to_string())
+                        .filter(|t| !t.is_empty())
+                        .collect()
+                }
+                _ => test.priority = TestPriority::from_name(value),
+            }
+        } else if test.command.is_none() {
+            if let Some(caps) = run_re.captures(line) {
+                test.command = Some(caps[1].trim().to_string());
+            }
+        }
+    }
+
+    tests
+}
+
+/// Non-TAP output printed after the last test line (e.g. by a test that was killed)
+fn trailing_output(tap_output: &str) -> String {
+    let test_line_re = Regex::new(r"^(ok|not ok)\s+\d+\s").unwrap();
+    let lines: Vec<&str> = tap_output.lines().collect();
+    let start = lines
+        .iter()
+        .rposition(|line| test_line_re.is_match(line) || line.starts_with("1.."))
+        .map_or(0, |index| index + 1);
+
+    lines[start..]
+        .iter()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -537,7 +1001,240 @@ ok 2 test two
         assert_eq!(executor.binary_version, Some("1.0.0".to_string()));
         assert_eq!(executor.timeout, 300);
 
-        let custom = BatsExecutor::with_timeout("cli".to_string(), None, 600);
+        let custom = BatsExecutor::with_timeout("cli".to_string(), None, 600).with_test_timeout(20);
         assert_eq!(custom.timeout, 600);
+        assert_eq!(custom.test_timeout, Some(20));
+    }
+
+    #[test]
+    fn test_find_target_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("notes.txt"), "CLI_BINARY=\"/wrong\"").unwrap();
+        fs::write(
+            dir.path().join("basic.bats"),
+            "setup() {\n    CLI_BINARY=\"/usr/local/bin/mycli\"\n    BINARY_BASENAME=\"mycli\"\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            BatsExecutor::find_target_binary(dir.path()),
+            Some(("mycli".to_string(), PathBuf::from("/usr/local/bin/mycli")))
+        );
+
+        let empty = tempfile::tempdir().unwrap();
+        assert_eq!(BatsExecutor::find_target_binary(empty.path()), None);
+    }
+
+    #[test]
+    fn test_parse_tap_output_collects_diagnostics() {
+        let executor = BatsExecutor::new("test-cli".to_string(), None);
+        let tap_output = r#"
+1..2
+not ok 1 test one
+# (in test file basic.bats, line 12)
+#   `[ "$status" -eq 0 ]' failed
+ok 2 test two
+"#;
+
+        let bats_file = Path::new("/tmp/test.bats");
+        let results = executor.parse_tap_output(tap_output, bats_file).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].status, TestStatus::Failed);
+        assert!(results[0].output.contains("line 12"));
+        assert!(results[1].output.is_empty());
+    }
+
+    #[test]
+    fn test_parse_tap_output_performance_metrics() {
+        let executor = BatsExecutor::new("test-cli".to_string(), None);
+        let tap_output = r#"
+1..3
+# perf: iterations=20 mean_ms=1.454 p95_ms=1.643 max_ms=1.709 max_rss_kb=3072
+ok 1 [performance] Startup time for --help < 100ms
+# perf: iterations=20 mean_ms=150.000 p95_ms=180.500 max_ms=190.000
+not ok 2 [performance] Startup time for --version < 100ms
+# p95 startup time 180ms exceeds budget of 100ms
+ok 3 [basic] Display help
+"#;
+
+        let bats_file = Path::new("/tmp/test.bats");
+        let results = executor.parse_tap_output(tap_output, bats_file).unwrap();
+
+        let metrics = results[0].performance.as_ref().unwrap();
+        assert!((metrics.p95_ms - 1.643).abs() < 1e-9);
+        assert_eq!(metrics.max_rss_kb, Some(3072));
+
+        assert_eq!(results[1].status, TestStatus::Failed);
+        assert_eq!(results[1].performance.as_ref().unwrap().max_rss_kb, None);
+        assert!(results[1].output.contains("exceeds budget"));
+        assert!(!results[0].output.contains("perf:"));
+
+        assert!(results[2].performance.is_none());
+    }
+
+    #[test]
+    fn test_parse_tap_output_timeouts() {
+        let executor = BatsExecutor::new("test-cli".to_string(), None);
+        let tap_output = r#"
+1..3
+not ok 1 test one in 2000ms # timeout after 2s
+not ok 2 test two
+# partial output
+# timeout after 5s
+ok 3 test three
+"#;
+
+        let bats_file = Path::new("/tmp/test.bats");
+        let results = executor.parse_tap_output(tap_output, bats_file).unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].status, TestStatus::Timeout);
+        assert_eq!(results[0].name, "test one in 2000ms");
+        assert_eq!(
+            results[0].error_message.as_deref(),
+            Some("Test timed out after 2s")
+        );
+        assert_eq!(results[1].status, TestStatus::Timeout);
+        assert_eq!(results[1].output, "partial output\n");
+        assert_eq!(
+            results[1].error_message.as_deref(),
+            Some("Test timed out after 5s")
+        );
+        assert_eq!(results[2].status, TestStatus::Passed);
+    }
+
+    #[test]
+    fn test_record_unfinished_tests_after_suite_timeout() {
+        let executor = BatsExecutor::with_timeout("test-cli".to_string(), None, 60);
+        let bats_content = r#"#!/usr/bin/env bats
+@test "[basic] test one" {
+    run true
+}
+
+@test "[basic] test \"two\"" {
+    run sleep 1000
+}
+
+@test "[basic] test three" {
+    run true
+}
+"#;
+        let tap_output = "1..3\nok 1 [basic] test one\nstarted\n";
+        let bats_file = Path::new("/tmp/basic.bats");
+
+        let names: Vec<String> = extract_test_metadata(bats_content)
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "[basic] test one",
+                "[basic] test \"two\"",
+                "[basic] test three"
+            ]
+        );
+
+        let mut results = executor.parse_tap_output(tap_output, bats_file).unwrap();
+        executor.record_unfinished_tests(
+            &mut results,
+            &names,
+            bats_file,
+            trailing_output(tap_output),
+            "stderr text".to_string(),
+        );
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].status, TestStatus::Passed);
+        assert_eq!(results[1].status, TestStatus::Timeout);
+        assert_eq!(results[1].output, "started\n");
+        assert_eq!(results[1].stderr, "stderr text");
+        assert_eq!(results[2].status, TestStatus::Skipped);
+    }
+
+    #[test]
+    fn test_escape_ere() {
+        assert_eq!(
+            escape_ere("[basic] Help (long) $x"),
+            r"\[basic\] Help \(long\) \$x"
+        );
+        assert_eq!(escape_ere("plain name"), "plain name");
+    }
+
+    #[test]
+    fn test_retry_result_from_filtered_run() {
+        let executor = BatsExecutor::new("test-cli".to_string(), None).with_retry_count(1);
+        let bats_file = Path::new("/tmp/basic.bats");
+
+        let passed = BatsRun {
+            stdout: "1..1\nok 1 [basic] test one\n".to_string(),
+            stderr: String::new(),
+            timed_out: false,
+        };
+        let retry = executor.retry_result(&passed, "[basic] test one", bats_file);
+        assert_eq!(retry.status, TestStatus::Passed);
+
+        let hung = BatsRun {
+            stdout: "1..1\n".to_string(),
+            stderr: "still waiting".to_string(),
+            timed_out: true,
+        };
+        let retry = executor.retry_result(&hung, "[basic] test one", bats_file);
+        assert_eq!(retry.status, TestStatus::Timeout);
+        assert_eq!(retry.stderr, "still waiting");
+    }
+
+    #[test]
+    fn test_extract_test_metadata() {
+        let bats_content = r#"#!/usr/bin/env bats
+setup() {
+    CLI_BINARY="/usr/bin/test-cli"
+}
+
+@test "[security] Reject command injection" {
+    # Test ID: security-001
+    # Tags: injection, critical
+    # Priority: security_check
+
+    # Execute command
+    run "$CLI_BINARY" --name 'test; rm -rf /'
+
+    # Assert exit code
+    [ "$status" -ne 0 ]
+}
+
+@test "[basic] Help" {
+    # Test ID: basic-001
+    run "$CLI_BINARY" --help
+}
+"#;
+
+        let metadata = extract_test_metadata(bats_content);
+
+        assert_eq!(metadata.len(), 2);
+        assert_eq!(metadata[0].name, "[security] Reject command injection");
+        assert_eq!(metadata[0].line_number, 6);
+        assert_eq!(metadata[0].id.as_deref(), Some("security-001"));
+        assert_eq!(metadata[0].tags, vec!["injection", "critical"]);
+        assert_eq!(metadata[0].priority, Some(TestPriority::SecurityCheck));
+        assert_eq!(
+            metadata[0].command.as_deref(),
+            Some(r#""$CLI_BINARY" --name 'test; rm -rf /'"#)
+        );
+        assert_eq!(metadata[1].id.as_deref(), Some("basic-001"));
+        assert!(metadata[1].tags.is_empty());
+        assert_eq!(metadata[1].priority, None);
+
+        let executor = BatsExecutor::new("test-cli".to_string(), None);
+        let mut results = executor
+            .parse_tap_output(
+                "1..1\nnot ok 1 [security] Reject command injection\n",
+                Path::new("/tmp/security.bats"),
+            )
+            .unwrap();
+        metadata[0].apply_to(&mut results[0]);
+        assert_eq!(results[0].priority, TestPriority::SecurityCheck);
+        assert_eq!(results[0].line_number, Some(6));
     }
 }
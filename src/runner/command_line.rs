@@ -0,0 +1,483 @@
+//! Minimal shell command-line parser for the native runner
+//!
+//! Generated `TestCase` commands are written in a small subset of POSIX shell
+//! syntax (quoting, `$VAR` expansion, `NAME=value` prefixes and `echo ... |`
+//! stdin feeding). This module interprets that subset directly so tests can be
+//! executed without bash. Anything outside the subset is rejected.
+
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+
+/// A parsed command ready for direct execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCommand {
+    /// Environment assignments preceding the command (`NAME=value cmd`)
+    pub env: Vec<(String, String)>,
+
+    /// Program to execute
+    pub program: String,
+
+    /// Program arguments
+    pub args: Vec<String>,
+
+    /// Data written to stdin (from `echo ... | cmd`)
+    pub stdin: Option<String>,
+}
+
+/// Lexical token
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// Word with optional assignment name (`NAME=value` written unquoted)
+    Word {
+        value: String,
+        assignment: Option<String>,
+    },
+
+    /// Unquoted pipe operator
+    Pipe,
+}
+
+/// Parse a command line, expanding variables from `vars` (falling back to the process environment)
+///
+/// # Examples
+///
+/// ```
+/// use cli_testing_specialist::runner::command_line::parse_command;
+/// use std::collections::HashMap;
+///
+/// let mut vars = HashMap::new();
+/// vars.insert("CLI_BINARY".to_string(), "/usr/bin/tool".to_string());
+///
+/// let cmd = parse_command(r#"echo 'n' | "$CLI_BINARY" delete --force"#, &vars)?;
+/// assert_eq!(cmd.program, "/usr/bin/tool");
+/// assert_eq!(cmd.args, vec!["delete", "--force"]);
+/// assert_eq!(cmd.stdin.as_deref(), Some("n\n"));
+/// # Ok::<(), cli_testing_specialist::error::CliTestError>(())
+/// ```
+pub fn parse_command(command: &str, vars: &HashMap<String, String>) -> Result<ParsedCommand> {
+    let tokens = tokenize(command, vars)?;
+
+    // Split into pipeline segments
+    let mut segments: Vec<Vec<Token>> = vec![Vec::new()];
+    for token in tokens {
+        match token {
+            Token::Pipe => segments.push(Vec::new()),
+            word => segments.last_mut().unwrap().push(word),
+        }
+    }
+
+    if segments.iter().any(|s| s.is_empty()) {
+        return Err(unsupported(command, "empty pipeline segment"));
+    }
+
+    let stdin = match segments.len() {
+        1 => None,
+        2 => {
+            let feeder = segments.remove(0);
+            Some(echo_output(command, &feeder)?)
+        }
+        _ => {
+            return Err(unsupported(
+                command,
+                "pipelines with more than two commands",
+            ))
+        }
+    };
+
+    let mut env = Vec::new();
+    let mut words = Vec::new();
+    for token in segments.remove(0) {
+        if let Token::Word { value, assignment } = token {
+            match assignment {
+                Some(name) if words.is_empty() => {
+                    let assigned = value[name.len() + 1..].to_string();
+                    env.push((name, assigned));
+                }
+                _ => words.push(value),
+            }
+        }
+    }
+
+    if words.is_empty() {
+        return Err(unsupported(command, "no command to execute"));
+    }
+
+    let program = words.remove(0);
+
+    Ok(ParsedCommand {
+        env,
+        program,
+        args: words,
+        stdin,
+    })
+}
+
+/// Evaluate the left side of `echo ... | cmd` into stdin data
+fn echo_output(command: &str, feeder: &[Token]) -> Result<String> {
+    let words: Vec<&str> = feeder
+        .iter()
+        .filter_map(|t| match t {
+            Token::Word { value, .. } => Some(value.as_str()),
+            Token::Pipe => None,
+        })
+        .collect();
+
+    match words.split_first() {
+        Some((&"echo", rest)) => Ok(format!("{}\n", rest.join(" "))),
+        _ => Err(unsupported(
+            command,
+            "only `echo ... |` pipelines are supported",
+        )),
+    }
+}
+
+fn unsupported(command: &str, reason: &str) -> Error {
+    Error::ExecutionFailed(format!(
+        "Unsupported shell syntax ({}): {}",
+        reason, command
+    ))
+}
+
+/// Split a command line into words and pipe operators
+fn tokenize(command: &str, vars: &HashMap<String, String>) -> Result<Vec<Token>> {
+    let chars: Vec<char> = command.chars().collect();
+    let mut tokens = Vec::new();
+
+    let mut current = String::new();
+    let mut started = false;
+    // True while every character of the current word was unquoted literal text
+    let mut plain = true;
+    let mut assignment: Option<String> = None;
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' | '\n' => {
+                if started {
+                    tokens.push(finish_word(&mut current, &mut assignment));
+                    started = false;
+                    plain = true;
+                }
+                i += 1;
+            }
+            '|' => {
+                if chars.get(i + 1) == Some(&'|') {
+                    return Err(unsupported(command, "`||`"));
+                }
+                if started {
+                    tokens.push(finish_word(&mut current, &mut assignment));
+                    started = false;
+                    plain = true;
+                }
+                tokens.push(Token::Pipe);
+                i += 1;
+            }
+            ';' | '&' | '<' | '>' | '(' | ')' | '`' => {
+                return Err(unsupported(command, &format!("`{}`", c)));
+            }
+            '\'' => {
+                let end = find_closing(&chars, i + 1, '\'')
+                    .ok_or_else(|| unsupported(command, "unterminated single quote"))?;
+                current.extend(&chars[i + 1..end]);
+                started = true;
+                plain = false;
+                i = end + 1;
+            }
+            '"' => {
+                i = read_double_quoted(command, &chars, i + 1, vars, &mut current)?;
+                started = true;
+                plain = false;
+            }
+            '$' if chars.get(i + 1) == Some(&'\'') => {
+                i = read_ansi_c_quoted(command, &chars, i + 2, &mut current)?;
+                started = true;
+                plain = false;
+            }
+            '$' => {
+                i = expand_variable(command, &chars, i, vars, &mut current)?;
+                started = true;
+                plain = false;
+            }
+            '\\' => {
+                if let Some(&next) = chars.get(i + 1) {
+                    current.push(next);
+                }
+                started = true;
+                plain = false;
+                i += 2;
+            }
+            '=' if plain && assignment.is_none() && is_identifier(&current) => {
+                assignment = Some(current.clone());
+                current.push('=');
+                started = true;
+                i += 1;
+            }
+            _ => {
+                current.push(c);
+                started = true;
+                i += 1;
+            }
+        }
+    }
+
+    if started {
+        tokens.push(finish_word(&mut current, &mut assignment));
+    }
+
+    Ok(tokens)
+}
+
+/// Complete the current word; NUL bytes truncate arguments as they do in bash
+fn finish_word(current: &mut String, assignment: &mut Option<String>) -> Token {
+    let mut value = std::mem::take(current);
+    if let Some(pos) = value.find('\0') {
+        value.truncate(pos);
+    }
+    Token::Word {
+        value,
+        assignment: assignment.take(),
+    }
+}
+
+fn find_closing(chars: &[char], start: usize, quote: char) -> Option<usize> {
+    (start..chars.len()).find(|&j| chars[j] == quote)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Read a double-quoted string starting after the opening quote; returns index after closing quote
+fn read_double_quoted(
+    command: &str,
+    chars: &[char],
+    mut i: usize,
+    vars: &HashMap<String, String>,
+    out: &mut String,
+) -> Result<usize> {
+    while i < chars.len() {
+        match chars[i] {
+            '"' => return Ok(i + 1),
+            '\\' => {
+                match chars.get(i + 1) {
+                    Some(&next @ ('"' | '\\' | '$' | '`')) => out.push(next),
+                    Some('\n') => {}
+                    Some(&next) => {
+                        out.push('\\');
+                        out.push(next);
+                    }
+                    None => out.push('\\'),
+                }
+                i += 2;
+            }
+            '$' => {
+                i = expand_variable(command, chars, i, vars, out)?;
+            }
+            '`' => return Err(unsupported(command, "command substitution")),
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    Err(unsupported(command, "unterminated double quote"))
+}
+
+/// Read an ANSI-C quoted string (`$'...'`) starting after `$'`; returns index after closing quote
+fn read_ansi_c_quoted(
+    command: &str,
+    chars: &[char],
+    mut i: usize,
+    out: &mut String,
+) -> Result<usize> {
+    while i < chars.len() {
+        match chars[i] {
+            '\'' => return Ok(i + 1),
+            '\\' => {
+                let Some(&esc) = chars.get(i + 1) else {
+                    break;
+                };
+                i += 2;
+                match esc {
+                    'n' => out.push('\n'),
+                    't' => out.push('\t'),
+                    'r' => out.push('\r'),
+                    'a' => out.push('\x07'),
+                    'b' => out.push('\x08'),
+                    'e' | 'E' => out.push('\x1b'),
+                    'f' => out.push('\x0c'),
+                    'v' => out.push('\x0b'),
+                    'x' => {
+                        let digits: String = chars[i..]
+                            .iter()
+                            .take(2)
+                            .take_while(|c| c.is_ascii_hexdigit())
+                            .collect();
+                        i += digits.len();
+                        match u32::from_str_radix(&digits, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                        {
+                            Some(c) => out.push(c),
+                            None => out.push_str("\\x"),
+                        }
+                    }
+                    '0'..='7' => {
+                        let mut digits = esc.to_string();
+                        digits.extend(
+                            chars[i..]
+                                .iter()
+                                .take(2)
+                                .take_while(|c| ('0'..='7').contains(c)),
+                        );
+                        i += digits.len() - 1;
+                        if let Some(c) = u32::from_str_radix(&digits, 8)
+                            .ok()
+                            .and_then(char::from_u32)
+                        {
+                            out.push(c);
+                        }
+                    }
+                    other => out.push(other),
+                }
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    Err(unsupported(command, "unterminated $'...' quote"))
+}
+
+/// Expand `$NAME` / `${NAME}` at `chars[i] == '$'`; returns index after the expansion
+fn expand_variable(
+    command: &str,
+    chars: &[char],
+    i: usize,
+    vars: &HashMap<String, String>,
+    out: &mut String,
+) -> Result<usize> {
+    let (name, next) = match chars.get(i + 1) {
+        Some('(') => return Err(unsupported(command, "command substitution")),
+        Some('{') => {
+            let end = find_closing(chars, i + 2, '}')
+                .ok_or_else(|| unsupported(command, "unterminated ${...}"))?;
+            let name: String = chars[i + 2..end].iter().collect();
+            if !is_identifier(&name) {
+                return Err(unsupported(command, "parameter expansion operators"));
+            }
+            (name, end + 1)
+        }
+        Some(c) if c.is_ascii_alphabetic() || *c == '_' => {
+            let name: String = chars[i + 1..]
+                .iter()
+                .take_while(|c| c.is_ascii_alphanumeric() || **c == '_')
+                .collect();
+            let next = i + 1 + name.len();
+            (name, next)
+        }
+        _ => {
+            // Lone '$' is literal
+            out.push('$');
+            return Ok(i + 1);
+        }
+    };
+
+    let value = vars
+        .get(&name)
+        .cloned()
+        .or_else(|| std::env::var(&name).ok())
+        .unwrap_or_default();
+    out.push_str(&value);
+
+    Ok(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> HashMap<String, String> {
+        let mut vars = HashMap::new();
+        vars.insert("CLI_BINARY".to_string(), "/opt/my tool/bin".to_string());
+        vars
+    }
+
+    #[test]
+    fn test_parse_simple_command() {
+        let cmd = parse_command("\"$CLI_BINARY\" --help", &vars()).unwrap();
+        assert_eq!(cmd.program, "/opt/my tool/bin");
+        assert_eq!(cmd.args, vec!["--help"]);
+        assert!(cmd.env.is_empty());
+        assert!(cmd.stdin.is_none());
+    }
+
+    #[test]
+    fn test_parse_quoted_arguments() {
+        let cmd = parse_command(
+            "\"$CLI_BINARY\" --name 'test; rm -rf /' '/tmp/test dir/file.txt'",
+            &vars(),
+        )
+        .unwrap();
+        assert_eq!(
+            cmd.args,
+            vec!["--name", "test; rm -rf /", "/tmp/test dir/file.txt"]
+        );
+    }
+
+    #[test]
+    fn test_parse_env_prefix() {
+        let cmd = parse_command("FORCE=\"1\" CI=true \"$CLI_BINARY\" delete", &vars()).unwrap();
+        assert_eq!(
+            cmd.env,
+            vec![
+                ("FORCE".to_string(), "1".to_string()),
+                ("CI".to_string(), "true".to_string())
+            ]
+        );
+        assert_eq!(cmd.args, vec!["delete"]);
+    }
+
+    #[test]
+    fn test_parse_echo_pipe() {
+        let cmd = parse_command("echo '' | \"$CLI_BINARY\"", &vars()).unwrap();
+        assert_eq!(cmd.stdin.as_deref(), Some("\n"));
+        assert!(cmd.args.is_empty());
+    }
+
+    #[test]
+    fn test_parse_ansi_c_null_byte_truncates() {
+        let cmd =
+            parse_command(r#""$CLI_BINARY" --file $'/tmp/test\x00malicious'"#, &vars()).unwrap();
+        assert_eq!(cmd.args, vec!["--file", "/tmp/test"]);
+    }
+
+    #[test]
+    fn test_parse_nested_shell_command() {
+        let cmd = parse_command("bash -c \"\\\"$CLI_BINARY\\\" --help\"", &vars()).unwrap();
+        assert_eq!(cmd.program, "bash");
+        assert_eq!(cmd.args, vec!["-c", "\"/opt/my tool/bin\" --help"]);
+    }
+
+    #[test]
+    fn test_assignment_after_command_is_argument() {
+        let cmd = parse_command("\"$CLI_BINARY\" KEY=value", &vars()).unwrap();
+        assert!(cmd.env.is_empty());
+        assert_eq!(cmd.args, vec!["KEY=value"]);
+    }
+
+    #[test]
+    fn test_unsupported_syntax() {
+        assert!(parse_command("\"$CLI_BINARY\" > /dev/null", &vars()).is_err());
+        assert!(parse_command("\"$CLI_BINARY\" && ls", &vars()).is_err());
+        assert!(parse_command("\"$CLI_BINARY\" $(whoami)", &vars()).is_err());
+        assert!(parse_command("cat x | grep y | wc", &vars()).is_err());
+        assert!(parse_command("\"$CLI_BINARY\" 'unterminated", &vars()).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::runner::command_line::parse_command;
+use crate::types::test_case::value_rejected;
+use crate::types::{EnumMatrix, EnumMatrixCell, TestResult, TestStatus, TestSuite};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+lazy_static! {
+    /// BATS diagnostic of a failed zero/non-zero exit-status check
+    /// (`` `[ "$status" -eq 0 ]' failed ``)
+    static ref BATS_STATUS_CHECK_FAILED: Regex =
+        Regex::new(r#"`\[ "\$status" -(?:eq|ne) 0 \]' failed"#).unwrap();
+}
+
+/// Group enum input-validation results into one accept/reject matrix per option
+///
+/// Tests tagged `option:<flag>` contribute a cell for the value following
+/// `<flag>` in their command; tests also tagged `validation` expect the value to
+/// be rejected. Whether the tool accepted a value is read from its exit status;
+/// subcommands run with placeholder arguments, so there a non-zero exit only
+/// rejects an expected-valid value when stderr names the option and value.
+/// When the runner did not record a status, a passed test got the expected
+/// outcome and a test that failed its exit-status or rejection check got the
+/// other one; other failures, including a missed exact code such as `-eq 1`,
+/// leave the cell unknown.
+///
+/// # Examples
+///
+/// ```
+/// use cli_testing_specialist::runner::extract_enum_matrices;
+///
+/// let matrices = extract_enum_matrices(&[]);
+/// assert!(matrices.is_empty());
+/// ```
+pub fn extract_enum_matrices(suites: &[TestSuite]) -> Vec<EnumMatrix> {
+    let mut matrices: Vec<EnumMatrix> = Vec::new();
+
+    for test in suites.iter().flat_map(|suite| &suite.tests) {
+        let Some((option, cell)) = to_cell(test) else {
+            continue;
+        };
+        match matrices.iter_mut().find(|matrix| matrix.option == option) {
+            Some(matrix) => matrix.cells.push(cell),
+            None => matrices.push(EnumMatrix {
+                option,
+                cells: vec![cell],
+            }),
+        }
+    }
+
+    matrices
+}
+
+/// Option (with subcommand path) and matrix cell of one enum test
+fn to_cell(test: &TestResult) -> Option<(String, EnumMatrixCell)> {
+    let flag = test
+        .tags
+        .iter()
+        .find_map(|tag| tag.strip_prefix(EnumMatrix::OPTION_TAG_PREFIX))?;
+
+    // `$CLI_BINARY` only needs to expand to something; the program is discarded
+    let vars = HashMap::from([("CLI_BINARY".to_string(), "cli".to_string())]);
+    let parsed = parse_command(test.command.as_deref()?, &vars).ok()?;
+    let position = parsed.args.iter().position(|arg| arg == flag)?;
+    let value = parsed.args.get(position + 1)?.clone();
+
+    let expected_accepted = !test.tags.iter().any(|tag| tag == "validation");
+    // Arguments before the flag are the subcommand path
+    let parser_check = expected_accepted && position > 0;
+    let accepted = match (test.exit_code, test.status) {
+        (Some(code), _) if parser_check => {
+            Some(code == 0 || !value_rejected(&test.stderr, flag, &value))
+        }
+        (Some(code), _) => Some(code == 0),
+        (None, TestStatus::Passed) => Some(expected_accepted),
+        (None, TestStatus::Failed) if exit_check_failed(test) => Some(!expected_accepted),
+        (None, TestStatus::Failed | TestStatus::Skipped | TestStatus::Timeout) => None,
+    };
+
+    Some((
+        parsed.args[..=position].join(" "),
+        EnumMatrixCell {
+            value,
+            expected_accepted,
+            accepted,
+            test_id: test.id.clone().unwrap_or_default(),
+        },
+    ))
+}
+
+/// Whether a failed test failed on a zero/non-zero exit-status check
+///
+/// The native runner reports "expected exit code 0, …" or "expected non-zero
+/// exit code, …", BATS names the failed `$status` test in its diagnostics.
+/// Both report "… was rejected" when the parser check of a subcommand fails.
+/// Failing an exact non-zero code says nothing about acceptance: a tool
+/// exiting 2 instead of 1 still rejected the value.
+fn exit_check_failed(test: &TestResult) -> bool {
+    test.error_message.iter().chain([&test.output]).any(|text| {
+        text.contains("expected exit code 0,")
+            || text.contains("expected non-zero exit code")
+            || text.contains("was rejected")
+            || BATS_STATUS_CHECK_FAILED.is_match(text)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TestPriority;
+    use chrono::Utc;
+    use std::time::Duration;
+
+    fn result(
+        command: &str,
+        tags: &[&str],
+        status: TestStatus,
+        exit_code: Option<i32>,
+    ) -> TestResult {
+        TestResult {
+            name: command.to_string(),
+            status,
+            duration: Duration::from_millis(10),
+            output: String::new(),
+            error_message: None,
+            file_path: "input-validation.bats".to_string(),
+            line_number: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            priority: TestPriority::Important,
+            exit_code,
+            stderr: String::new(),
+            flaky: false,
+            attempts: vec![],
+            id: Some("enum-001".to_string()),
+            command: Some(command.to_string()),
+            performance: None,
+        }
+    }
+
+    #[test]
+    fn test_enum_matrices_group_by_option() {
+        let suites = vec![TestSuite {
+            name: "input-validation".to_string(),
+            file_path: "input-validation.bats".to_string(),
+            tests: vec![
+                result(
+                    "\"$CLI_BINARY\" --format json",
+                    &["enum", "option:--format"],
+                    TestStatus::Passed,
+                    Some(0),
+                ),
+                // Case variant expected to pass, rejected by the tool
+                result(
+                    "\"$CLI_BINARY\" --format JSON",
+                    &["enum", "option:--format"],
+                    TestStatus::Failed,
+                    Some(2),
+                ),
+                // BATS results carry no exit status
+                TestResult {
+                    output: "(in test file input-validation.bats, line 12)\n  `[ \"$status\" -ne 0 ]' failed\n".to_string(),
+                    ..result(
+                        "\"$CLI_BINARY\" --format ' json '",
+                        &["enum", "option:--format", "validation"],
+                        TestStatus::Failed,
+                        None,
+                    )
+                },
+                // Failed on something other than the exit status
+                TestResult {
+                    output: "(in test file input-validation.bats, line 20)\n  `[[ \"$output\" =~ \"json\" ]]' failed\n".to_string(),
+                    ..result(
+                        "\"$CLI_BINARY\" --format yaml",
+                        &["enum", "option:--format"],
+                        TestStatus::Failed,
+                        None,
+                    )
+                },
+                // Rejected with exit 2 by a probe that insisted on exit 1
+                TestResult {
+                    output: "(in test file input-validation.bats, line 28)\n  `[ \"$status\" -eq 1 ]' failed\n".to_string(),
+                    ..result(
+                        "\"$CLI_BINARY\" --format 'invalid-value-xyz'",
+                        &["enum", "option:--format", "validation"],
+                        TestStatus::Failed,
+                        None,
+                    )
+                },
+                result(
+                    "\"$CLI_BINARY\" remote add --format '' origin",
+                    &["enum", "option:--format", "validation"],
+                    TestStatus::Timeout,
+                    None,
+                ),
+                result(
+                    "\"$CLI_BINARY\" --timeout 10",
+                    &["numeric"],
+                    TestStatus::Passed,
+                    Some(0),
+                ),
+            ],
+            duration: Duration::from_millis(40),
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+        }];
+
+        let matrices = extract_enum_matrices(&suites);
+
+        assert_eq!(matrices.len(), 2);
+        let global = &matrices[0];
+        assert_eq!(global.option, "--format");
+        let cells: Vec<(&str, bool, Option<bool>)> = global
+            .cells
+            .iter()
+            .map(|cell| (cell.value.as_str(), cell.expected_accepted, cell.accepted))
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                ("json", true, Some(true)),
+                ("JSON", true, Some(false)),
+                (" json ", false, Some(true)),
+                ("yaml", true, None),
+                ("invalid-value-xyz", false, None),
+            ]
+        );
+        assert_eq!(global.mismatches(), 4);
+
+        assert_eq!(matrices[1].option, "remote add --format");
+        assert_eq!(matrices[1].cells[0].value, "");
+        assert_eq!(matrices[1].cells[0].accepted, None);
+    }
+
+    #[test]
+    fn test_subcommand_enum_cells_only_read_parser_rejections() {
+        let suites = vec![TestSuite {
+            name: "input-validation".to_string(),
+            file_path: "input-validation.bats".to_string(),
+            tests: vec![
+                // Exits 2 for the missing positional, not for the value
+                TestResult {
+                    stderr:
+                        "error: the following required arguments were not provided:\n  <TEST_DIR>\n"
+                            .to_string(),
+                    ..result(
+                        "\"$CLI_BINARY\" run --format json",
+                        &["enum", "option:--format"],
+                        TestStatus::Passed,
+                        Some(2),
+                    )
+                },
+                TestResult {
+                    stderr: "error: invalid value 'JSON' for '--format <FORMAT>'\n".to_string(),
+                    ..result(
+                        "\"$CLI_BINARY\" run --format JSON",
+                        &["enum", "option:--format"],
+                        TestStatus::Failed,
+                        Some(2),
+                    )
+                },
+                TestResult {
+                    output: "value 'yaml' for --format was rejected\n".to_string(),
+                    ..result(
+                        "\"$CLI_BINARY\" run --format yaml",
+                        &["enum", "option:--format"],
+                        TestStatus::Failed,
+                        None,
+                    )
+                },
+                result(
+                    "\"$CLI_BINARY\" run --format 'invalid-value-xyz'",
+                    &["enum", "option:--format", "validation"],
+                    TestStatus::Passed,
+                    Some(2),
+                ),
+            ],
+            duration: Duration::from_millis(40),
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+        }];
+
+        let matrices = extract_enum_matrices(&suites);
+
+        assert_eq!(matrices.len(), 1);
+        assert_eq!(matrices[0].option, "run --format");
+        let accepted: Vec<Option<bool>> =
+            matrices[0].cells.iter().map(|cell| cell.accepted).collect();
+        assert_eq!(
+            accepted,
+            vec![Some(true), Some(false), Some(false), Some(false)]
+        );
+        assert_eq!(matrices[0].mismatches(), 2);
+    }
+}
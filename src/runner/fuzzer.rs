@@ -0,0 +1,946 @@
+use crate::analyzer::{numeric_range, NumericRange};
+use crate::error::{Error, Result};
+use crate::generator::test_generator::{
+    allowed_exit_codes, command_line, subcommand_scopes, PANIC_MARKER,
+};
+use crate::runner::native_executor::{spawn_reader, wait_with_rusage, NativeExecutor};
+use crate::types::{
+    Assertion, CliAnalysis, CliOption, OptionType, PositionalArg, TestCase, TestCategory,
+    TestPriority, TestStatus,
+};
+use crate::utils::{deserialize_json_safe, ResourceLimits};
+use log::{debug, info, warn};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Default timeout per fuzzed execution in seconds
+const DEFAULT_EXECUTION_TIMEOUT: u64 = 5;
+
+/// Most options put on one generated command line
+const MAX_OPTIONS_PER_INPUT: usize = 6;
+
+/// Re-executions spent minimizing one crashing input
+const MAX_MINIMIZE_RUNS: usize = 100;
+
+/// Length of the long-input payload
+const LONG_INPUT_LEN: usize = 4096;
+
+/// Values mixed into option and argument values, grouped by payload class
+const PAYLOADS: &[&str] = &[
+    // Command injection
+    "test; rm -rf /",
+    "$(id)",
+    "`id`",
+    "a | cat /etc/passwd",
+    // Path traversal
+    "../../../etc/passwd",
+    "/../../../../../../etc/shadow",
+    // Format strings
+    "%s%s%s%s%n",
+    "{0}{1}%x%x",
+    // Control characters and Unicode
+    "\u{1b}[2J\u{7}\r",
+    "\u{202e}ｆｕｚｚ\u{fe0f}😀",
+    // Numeric edge cases
+    "-1",
+    "9223372036854775808",
+    "NaN",
+    // Empty value
+    "",
+];
+
+/// Output fragments of sanitizer reports
+const SANITIZER_MARKERS: &[&str] = &[
+    "ERROR: AddressSanitizer",
+    "ERROR: LeakSanitizer",
+    "ERROR: MemorySanitizer",
+    "WARNING: ThreadSanitizer",
+    "SUMMARY: UndefinedBehaviorSanitizer",
+];
+
+/// Grammar-based fuzzer driven by the analysis model
+///
+/// Generates random but plausible command lines from the subcommands, options
+/// and option types of a [`CliAnalysis`], mixing in the security payload
+/// classes, and runs them under [`ResourceLimits`]. An input crashes the tool
+/// when it is killed by a signal, panics, triggers a sanitizer report or times
+/// out. Crashing inputs are minimized and saved to the corpus directory as
+/// [`TestCase`]s (`<id>.json`) that [`NativeExecutor`](crate::runner::NativeExecutor)
+/// can replay.
+///
+/// The same seed generates the same sequence of inputs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cli_testing_specialist::analyzer::CliParser;
+/// use cli_testing_specialist::runner::Fuzzer;
+/// use std::path::{Path, PathBuf};
+/// use std::time::Duration;
+///
+/// let analysis = CliParser::new().analyze(Path::new("/usr/bin/curl"))?;
+/// let report = Fuzzer::new(analysis, PathBuf::from("fuzz-corpus"))
+///     .with_seed(42)
+///     .run(Duration::from_secs(60))?;
+/// println!("{} executions, {} crashes", report.executions, report.crashes.len());
+/// # Ok::<(), cli_testing_specialist::error::CliTestError>(())
+/// ```
+pub struct Fuzzer {
+    /// Analysis the command lines are generated from
+    analysis: CliAnalysis,
+
+    /// Directory crashing test cases are saved to
+    corpus_dir: PathBuf,
+
+    /// Limits for every execution (`execution_timeout` is the per-run timeout)
+    limits: ResourceLimits,
+
+    /// Seed of the input generator
+    seed: u64,
+
+    /// Stop after this many executions (optional)
+    max_runs: Option<u64>,
+}
+
+/// Outcome of a fuzzing session
+#[derive(Debug)]
+pub struct FuzzReport {
+    /// Seed the inputs were generated from
+    pub seed: u64,
+
+    /// Number of generated inputs executed
+    pub executions: u64,
+
+    /// Wall-clock time spent fuzzing
+    pub elapsed: Duration,
+
+    /// Distinct minimized crashes found in this session
+    pub crashes: Vec<TestCase>,
+
+    /// Corpus files written in this session (crashes not already in the corpus)
+    pub saved: Vec<PathBuf>,
+}
+
+/// How a fuzzed input crashed the tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrashKind {
+    /// Terminated by a signal (e.g. 11 for SIGSEGV)
+    Signal(i32),
+
+    /// Printed a Rust panic message
+    Panic,
+
+    /// Printed a sanitizer report (the matching report header)
+    Sanitizer(&'static str),
+
+    /// Did not finish within the execution timeout
+    Timeout,
+}
+
+impl CrashKind {
+    /// ID fragment and tag (`signal-11`, `panic`, `sanitizer`, `timeout`)
+    pub fn slug(&self) -> String {
+        match self {
+            Self::Signal(signal) => format!("signal-{}", signal),
+            Self::Panic => "panic".to_string(),
+            Self::Sanitizer(_) => "sanitizer".to_string(),
+            Self::Timeout => "timeout".to_string(),
+        }
+    }
+
+    /// Human-readable description (`killed by signal 11`)
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Signal(signal) => format!("killed by signal {}", signal),
+            Self::Panic => "panicked".to_string(),
+            Self::Sanitizer(marker) => format!("reported {}", marker),
+            Self::Timeout => "timed out".to_string(),
+        }
+    }
+
+    /// Crash shown by an execution, if any
+    ///
+    /// Sanitizers exit with status 1 by default, so their reports are checked
+    /// before the exit status.
+    fn detect(outcome: &Outcome) -> Option<Self> {
+        if outcome.timed_out {
+            return Some(Self::Timeout);
+        }
+        let output =
+            |marker: &str| outcome.stdout.contains(marker) || outcome.stderr.contains(marker);
+        if let Some(marker) = SANITIZER_MARKERS.iter().find(|marker| output(marker)) {
+            return Some(Self::Sanitizer(marker));
+        }
+        if output(PANIC_MARKER) {
+            return Some(Self::Panic);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = outcome.status.and_then(|status| status.signal()) {
+                return Some(Self::Signal(signal));
+            }
+        }
+        None
+    }
+}
+
+/// Raw result of one execution
+struct Outcome {
+    status: Option<ExitStatus>,
+    timed_out: bool,
+    stdout: String,
+    stderr: String,
+}
+
+impl Fuzzer {
+    /// Create a fuzzer saving crashes to `corpus_dir`
+    ///
+    /// The seed is taken from the clock (see [`seed`](Self::seed)), executions
+    /// time out after 5 seconds and use the default [`ResourceLimits`].
+    pub fn new(analysis: CliAnalysis, corpus_dir: PathBuf) -> Self {
+        Self {
+            analysis,
+            corpus_dir,
+            limits: ResourceLimits {
+                execution_timeout: Duration::from_secs(DEFAULT_EXECUTION_TIMEOUT),
+                ..ResourceLimits::default()
+            },
+            seed: clock_seed(),
+            max_runs: None,
+        }
+    }
+
+    /// Set the seed of the input generator
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Set the limits applied to every execution
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Stop after `max_runs` executions even if time is left
+    pub fn with_max_runs(mut self, max_runs: u64) -> Self {
+        self.max_runs = Some(max_runs);
+        self
+    }
+
+    /// Seed of the input generator (pass it to `with_seed` to repeat a run)
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Fuzz for `duration` and save new crashes to the corpus directory
+    pub fn run(&self, duration: Duration) -> Result<FuzzReport> {
+        fs::create_dir_all(&self.corpus_dir)?;
+        let work_dir = std::env::temp_dir().join(format!("cli-test-fuzz-{}", std::process::id()));
+        fs::create_dir_all(&work_dir)?;
+
+        let grammar = Grammar::new(&self.analysis);
+        let allowed = allowed_exit_codes(&self.analysis);
+        let mut rng = FuzzRng(self.seed);
+        let mut seen = HashSet::new();
+        let mut crashes = Vec::new();
+        let mut saved = Vec::new();
+        let mut executions = 0;
+
+        let start = Instant::now();
+        let deadline = start + duration;
+        info!(
+            "Fuzzing {} with {} command shapes (seed {})",
+            self.analysis.binary_name,
+            grammar.commands.len(),
+            self.seed
+        );
+
+        while Instant::now() < deadline && !matches!(self.max_runs, Some(max) if executions >= max)
+        {
+            let args = grammar.generate(&mut rng);
+            executions += 1;
+
+            let outcome = match self.execute(&args, &work_dir) {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    debug!("Skipping input {:?}: {}", args, e);
+                    continue;
+                }
+            };
+            let Some(kind) = CrashKind::detect(&outcome) else {
+                continue;
+            };
+
+            let minimized = minimize(args, MAX_MINIMIZE_RUNS, |candidate| {
+                Instant::now() < deadline
+                    && self
+                        .execute(candidate, &work_dir)
+                        .ok()
+                        .and_then(|outcome| CrashKind::detect(&outcome))
+                        == Some(kind)
+            });
+            let test = self.crash_test(&minimized, kind, &allowed);
+            if !seen.insert(test.id.clone()) {
+                continue;
+            }
+            warn!("Crash ({}): {}", kind.describe(), test.command);
+
+            let path = self.corpus_dir.join(format!("{}.json", test.id));
+            if !path.exists() {
+                fs::write(&path, serde_json::to_string_pretty(&test)?)?;
+                saved.push(path);
+            }
+            crashes.push(test);
+        }
+
+        let _ = fs::remove_dir_all(&work_dir);
+
+        Ok(FuzzReport {
+            seed: self.seed,
+            executions,
+            elapsed: start.elapsed(),
+            crashes,
+            saved,
+        })
+    }
+
+    /// Saved crashes that still reproduce: fail or time out under the same limits
+    pub fn replay<'a>(&self, tests: &'a [TestCase]) -> Vec<&'a TestCase> {
+        let executor = NativeExecutor::new(
+            self.analysis.binary_path.clone(),
+            self.analysis.binary_name.clone(),
+            self.analysis.version.clone(),
+        )
+        .with_timeout(self.limits.execution_timeout.as_secs().max(1))
+        .with_limits(self.limits.clone());
+
+        tests
+            .iter()
+            .filter(|test| {
+                matches!(
+                    executor.run_test(test).status,
+                    TestStatus::Failed | TestStatus::Timeout
+                )
+            })
+            .collect()
+    }
+
+    /// Run the binary with `args` under the resource limits
+    fn execute(&self, args: &[String], work_dir: &Path) -> Result<Outcome> {
+        let mut command = Command::new(&self.analysis.binary_path);
+        command
+            .args(args)
+            .current_dir(work_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        // Own process group so a timeout kills the whole tree
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+            self.limits.limit_child(&mut command);
+        }
+
+        let mut child = command.spawn().map_err(|e| {
+            Error::ExecutionFailed(format!(
+                "Failed to spawn '{}': {}",
+                self.analysis.binary_path.display(),
+                e
+            ))
+        })?;
+
+        let stdout_reader = spawn_reader(child.stdout.take());
+        let stderr_reader = spawn_reader(child.stderr.take());
+        let (status, timed_out, _) = wait_with_rusage(&mut child, self.limits.execution_timeout)?;
+
+        Ok(Outcome {
+            status,
+            timed_out,
+            stdout: stdout_reader.join().unwrap_or_default(),
+            stderr: stderr_reader.join().unwrap_or_default(),
+        })
+    }
+
+    /// Reproducer for a minimized crash that passes once the crash is fixed
+    ///
+    /// The ID hashes the arguments, so the same crash keeps its corpus file
+    /// across sessions and seeds.
+    fn crash_test(&self, args: &[String], kind: CrashKind, allowed: &[i32]) -> TestCase {
+        let mut test = TestCase::new(
+            format!("fuzz-{}-{:08x}", kind.slug(), fnv1a(args)),
+            format!("Fuzzed input does not crash ({})", kind.describe()),
+            TestCategory::Security,
+            command_line(args),
+        )
+        .with_assertion(Assertion::ExitCodeIn(allowed.to_vec()))
+        .with_assertion(Assertion::OutputNotContains(PANIC_MARKER.to_string()))
+        .with_priority(TestPriority::SecurityCheck)
+        .with_timeout(self.limits.execution_timeout.as_secs().max(1))
+        .with_tag("fuzz".to_string())
+        .with_tag(kind.slug())
+        .with_tag(format!("seed:{}", self.seed));
+        if let CrashKind::Sanitizer(marker) = kind {
+            test = test.with_assertion(Assertion::OutputNotContains(marker.to_string()));
+        }
+        test
+    }
+}
+
+/// Load the crashing test cases saved in a corpus directory (sorted by file name)
+///
+/// A missing directory is an empty corpus.
+pub fn load_corpus(corpus_dir: &Path) -> Result<Vec<TestCase>> {
+    if !corpus_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(corpus_dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| deserialize_json_safe(&fs::read_to_string(path)?))
+        .collect()
+}
+
+/// Shrink a crashing input while it still crashes the same way
+///
+/// Removes chunks of arguments, halving the chunk size down to single
+/// arguments (a simplified delta debugging), with at most `budget` attempts.
+fn minimize(
+    mut args: Vec<String>,
+    budget: usize,
+    mut still_crashes: impl FnMut(&[String]) -> bool,
+) -> Vec<String> {
+    let mut attempts = 0;
+    let mut chunk = args.len().div_ceil(2).max(1);
+
+    loop {
+        let mut start = 0;
+        while start < args.len() && attempts < budget {
+            let end = (start + chunk).min(args.len());
+            let candidate: Vec<String> =
+                args[..start].iter().chain(&args[end..]).cloned().collect();
+            attempts += 1;
+            if still_crashes(&candidate) {
+                args = candidate;
+            } else {
+                start = end;
+            }
+        }
+        if chunk == 1 || attempts >= budget {
+            return args;
+        }
+        chunk = chunk.div_ceil(2);
+    }
+}
+
+/// Seed for runs without `with_seed`
+fn clock_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or_default();
+    nanos ^ u64::from(std::process::id())
+}
+
+/// 32-bit FNV-1a hash of the arguments (NUL-separated)
+fn fnv1a(args: &[String]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in args.iter().flat_map(|arg| arg.bytes().chain([0])) {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// Small deterministic PRNG (SplitMix64), so a seed reproduces a session
+struct FuzzRng(u64);
+
+impl FuzzRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform index in `0..n` (`n` > 0)
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True with probability 1/n
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Uniform value in `min..=max`
+    fn between(&mut self, min: i64, max: i64) -> i64 {
+        let span = (i128::from(max) - i128::from(min) + 1) as u128;
+        (i128::from(min) + (u128::from(self.next_u64()) % span) as i128) as i64
+    }
+}
+
+/// Value an option or positional argument takes
+#[derive(Debug, Clone)]
+enum ValueKind {
+    Flag,
+    Text,
+    Path,
+    Number(NumericRange),
+    Choice(Vec<String>),
+}
+
+impl ValueKind {
+    fn of(option_type: &OptionType, range: Option<NumericRange>) -> Self {
+        match option_type {
+            OptionType::Flag => Self::Flag,
+            OptionType::String => Self::Text,
+            OptionType::Path => Self::Path,
+            OptionType::Numeric { .. } => range.map_or(Self::Text, Self::Number),
+            OptionType::Enum { values } => Self::Choice(values.clone()),
+        }
+    }
+}
+
+/// An option with the spellings it can be given by
+#[derive(Debug)]
+struct GrammarOption {
+    flags: Vec<String>,
+    value: ValueKind,
+}
+
+/// A positional argument slot
+#[derive(Debug)]
+struct GrammarPositional {
+    value: ValueKind,
+    required: bool,
+    variadic: bool,
+}
+
+/// The binary itself or one of its subcommands
+#[derive(Debug)]
+struct GrammarCommand {
+    path: Vec<String>,
+    options: Vec<GrammarOption>,
+    positionals: Vec<GrammarPositional>,
+}
+
+/// Command-line grammar derived from the analysis
+///
+/// Destructive subcommands are left out, as in the generated test categories.
+#[derive(Debug)]
+struct Grammar {
+    global_options: Vec<GrammarOption>,
+    commands: Vec<GrammarCommand>,
+}
+
+impl Grammar {
+    fn new(analysis: &CliAnalysis) -> Self {
+        let global_options = grammar_options(&analysis.global_options);
+        let mut commands = vec![GrammarCommand {
+            path: Vec::new(),
+            options: grammar_options(&analysis.global_options),
+            positionals: grammar_positionals(&analysis.positional_args, &[]),
+        }];
+        for scope in subcommand_scopes(analysis) {
+            if scope.destructive {
+                continue;
+            }
+            commands.push(GrammarCommand {
+                path: scope.path.clone(),
+                options: grammar_options(&scope.subcommand.options),
+                positionals: grammar_positionals(
+                    &scope.subcommand.positional_args,
+                    &scope.subcommand.required_args,
+                ),
+            });
+        }
+
+        Self {
+            global_options,
+            commands,
+        }
+    }
+
+    /// Generate one command line
+    fn generate(&self, rng: &mut FuzzRng) -> Vec<String> {
+        let command = rng.pick(&self.commands);
+        let mut args = Vec::new();
+
+        // Global options go before the subcommand path
+        if !command.path.is_empty() && !self.global_options.is_empty() && rng.one_in(4) {
+            push_option(rng.pick(&self.global_options), rng, &mut args);
+        }
+        args.extend(command.path.iter().cloned());
+
+        if !command.options.is_empty() {
+            let count = rng.below(MAX_OPTIONS_PER_INPUT.min(command.options.len()) + 1);
+            for _ in 0..count {
+                push_option(rng.pick(&command.options), rng, &mut args);
+            }
+        }
+
+        // Usually give required arguments, sometimes optional ones
+        for positional in &command.positionals {
+            let given = if positional.required {
+                !rng.one_in(8)
+            } else {
+                rng.one_in(2)
+            };
+            if given {
+                let count = if positional.variadic {
+                    1 + rng.below(3)
+                } else {
+                    1
+                };
+                for _ in 0..count {
+                    args.push(fuzz_value(&positional.value, rng));
+                }
+            }
+        }
+
+        // Occasional structural mutations
+        let position = rng.below(args.len() + 1);
+        match rng.below(10) {
+            0 => args.insert(position, "--fuzz-unknown-option".to_string()),
+            1 => args.insert(position, "--".to_string()),
+            2 => args.push(payload(rng)),
+            _ => {}
+        }
+
+        args
+    }
+}
+
+/// Options that can be fuzzed (help and version only print and exit)
+fn grammar_options(options: &[CliOption]) -> Vec<GrammarOption> {
+    options
+        .iter()
+        .filter_map(|option| {
+            let flags: Vec<String> = [&option.long, &option.short]
+                .into_iter()
+                .flatten()
+                .filter(|flag| !matches!(flag.as_str(), "--help" | "--version" | "-h" | "-V"))
+                .cloned()
+                .collect();
+            if flags.is_empty() {
+                return None;
+            }
+            Some(GrammarOption {
+                flags,
+                value: ValueKind::of(&option.option_type, numeric_range(option)),
+            })
+        })
+        .collect()
+}
+
+/// Positional slots; analysis files without typed positionals only name the required ones
+fn grammar_positionals(
+    positionals: &[PositionalArg],
+    required: &[String],
+) -> Vec<GrammarPositional> {
+    if positionals.is_empty() {
+        return required
+            .iter()
+            .map(|_| GrammarPositional {
+                value: ValueKind::Text,
+                required: true,
+                variadic: false,
+            })
+            .collect();
+    }
+    positionals
+        .iter()
+        .map(|arg| GrammarPositional {
+            value: ValueKind::of(&arg.arg_type, None),
+            required: arg.required,
+            variadic: arg.variadic,
+        })
+        .collect()
+}
+
+/// Append an option, its value given separately or as `--flag=value`
+fn push_option(option: &GrammarOption, rng: &mut FuzzRng, args: &mut Vec<String>) {
+    let flag = rng.pick(&option.flags).clone();
+    if let ValueKind::Flag = option.value {
+        if flag.starts_with("--") && rng.one_in(10) {
+            args.push(format!("{}={}", flag, payload(rng)));
+        } else {
+            args.push(flag);
+        }
+        return;
+    }
+
+    let value = fuzz_value(&option.value, rng);
+    if flag.starts_with("--") && rng.one_in(3) {
+        args.push(format!("{}={}", flag, value));
+    } else {
+        args.push(flag);
+        args.push(value);
+    }
+}
+
+/// A plausible value for `kind`, or a security payload one time in four
+fn fuzz_value(kind: &ValueKind, rng: &mut FuzzRng) -> String {
+    if rng.one_in(4) {
+        return payload(rng);
+    }
+
+    match kind {
+        ValueKind::Flag => payload(rng),
+        ValueKind::Text => rng
+            .pick(&["fuzz", "a", "hello world", "-", "*", "0"])
+            .to_string(),
+        ValueKind::Path => rng
+            .pick(&[".", "..", "/", "/dev/null", "/tmp", "missing/file.txt", "-"])
+            .to_string(),
+        ValueKind::Number(range) => match rng.below(6) {
+            0 => range.min.to_string(),
+            1 => range.max.to_string(),
+            2 => range.min.saturating_sub(1).to_string(),
+            3 => range.max.saturating_add(1).to_string(),
+            4 => rng.between(range.min, range.max).to_string(),
+            _ => rng
+                .pick(&["0", "-0", "1e3", "0x10", "1.5", "-9223372036854775809"])
+                .to_string(),
+        },
+        ValueKind::Choice(values) if values.is_empty() => payload(rng),
+        ValueKind::Choice(values) => {
+            let value = rng.pick(values).clone();
+            match rng.below(8) {
+                0 => value.to_uppercase(),
+                1 => value.chars().take(value.chars().count() / 2).collect(),
+                2 => format!("{},{}", value, value),
+                3 => format!("{}x", value),
+                _ => value,
+            }
+        }
+    }
+}
+
+/// A random security payload
+fn payload(rng: &mut FuzzRng) -> String {
+    let index = rng.below(PAYLOADS.len() + 1);
+    match PAYLOADS.get(index) {
+        Some(payload) => payload.to_string(),
+        None => "A".repeat(LONG_INPUT_LEN),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::analysis::OptionSource;
+    use crate::types::Subcommand;
+
+    fn option(long: &str, option_type: OptionType) -> CliOption {
+        CliOption {
+            short: None,
+            long: Some(long.to_string()),
+            description: None,
+            option_type,
+            required: false,
+            default_value: None,
+            env_var: None,
+            source: OptionSource::Help,
+        }
+    }
+
+    fn create_test_analysis(binary_path: PathBuf) -> CliAnalysis {
+        let mut analysis = CliAnalysis::new(binary_path, "fuzz-target".to_string(), String::new());
+        analysis.global_options = vec![
+            option("--crash", OptionType::Flag),
+            option("--help", OptionType::Flag),
+            option(
+                "--format",
+                OptionType::Enum {
+                    values: vec!["json".to_string(), "text".to_string()],
+                },
+            ),
+            option("--name", OptionType::String),
+        ];
+        for name in ["build", "delete"] {
+            analysis.subcommands.push(Subcommand {
+                name: name.to_string(),
+                description: None,
+                options: vec![option(
+                    "--jobs",
+                    OptionType::Numeric {
+                        min: Some(1),
+                        max: Some(8),
+                    },
+                )],
+                required_args: vec!["TARGET".to_string()],
+                positional_args: Vec::new(),
+                subcommands: Vec::new(),
+                depth: 1,
+                option_relations: Vec::new(),
+            });
+        }
+        analysis
+    }
+
+    fn outcome(stdout: &str, stderr: &str, timed_out: bool) -> Outcome {
+        Outcome {
+            status: None,
+            timed_out,
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_inputs() {
+        let grammar = Grammar::new(&create_test_analysis(PathBuf::from("/bin/true")));
+        let inputs = |seed| {
+            let mut rng = FuzzRng(seed);
+            (0..50)
+                .map(|_| grammar.generate(&mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(inputs(7), inputs(7));
+        assert_ne!(inputs(7), inputs(8));
+    }
+
+    #[test]
+    fn test_grammar_follows_analysis() {
+        let grammar = Grammar::new(&create_test_analysis(PathBuf::from("/bin/true")));
+
+        // Destructive subcommands and help flags are left out
+        let paths: Vec<&[String]> = grammar.commands.iter().map(|c| &c.path[..]).collect();
+        assert_eq!(paths, vec![&[][..], &["build".to_string()][..]]);
+        assert!(grammar.global_options.iter().all(|o| o.flags != ["--help"]));
+
+        let mut rng = FuzzRng(1);
+        let inputs: Vec<Vec<String>> = (0..500).map(|_| grammar.generate(&mut rng)).collect();
+        assert!(inputs
+            .iter()
+            .all(|args| !args.contains(&"--help".to_string())));
+        assert!(inputs
+            .iter()
+            .any(|args| args.first().map(String::as_str) == Some("build")));
+        assert!(inputs
+            .iter()
+            .any(|args| args.windows(2).any(|w| w[0] == "--jobs" && w[1] == "8")));
+        assert!(inputs
+            .iter()
+            .any(|args| args.iter().any(|arg| arg == "../../../etc/passwd")));
+    }
+
+    #[test]
+    fn test_minimize_keeps_crashing_arguments() {
+        let args: Vec<String> = ["build", "--jobs", "3", "--crash", "x", "--name", "y"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let minimized = minimize(args.clone(), 100, |candidate| {
+            candidate.contains(&"--crash".to_string()) && candidate.contains(&"x".to_string())
+        });
+        assert_eq!(minimized, vec!["--crash", "x"]);
+
+        // Without budget the input is returned as is
+        assert_eq!(minimize(args.clone(), 0, |_| true), args);
+    }
+
+    #[test]
+    fn test_detect_crash_kind() {
+        assert_eq!(CrashKind::detect(&outcome("", "", false)), None);
+        assert_eq!(
+            CrashKind::detect(&outcome("", "", true)),
+            Some(CrashKind::Timeout)
+        );
+        assert_eq!(
+            CrashKind::detect(&outcome(
+                "",
+                "thread 'main' panicked at src/main.rs:3:5",
+                false
+            )),
+            Some(CrashKind::Panic)
+        );
+        assert_eq!(
+            CrashKind::detect(&outcome(
+                "",
+                "==1==ERROR: AddressSanitizer: heap-buffer-overflow",
+                false
+            )),
+            Some(CrashKind::Sanitizer("ERROR: AddressSanitizer"))
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            let segfault = Outcome {
+                status: Some(ExitStatus::from_raw(11)),
+                ..outcome("", "", false)
+            };
+            assert_eq!(CrashKind::detect(&segfault), Some(CrashKind::Signal(11)));
+            assert_eq!(CrashKind::Signal(11).slug(), "signal-11");
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_fuzz_saves_minimized_reproducible_crash() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("cli-test-fuzz-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let binary = dir.join("fuzz-target");
+        fs::write(
+            &binary,
+            "#!/bin/sh\nfor arg in \"$@\"; do [ \"$arg\" = --crash ] && kill -SEGV $$; done\nexit 0\n",
+        )
+        .unwrap();
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+        let corpus = dir.join("corpus");
+
+        let report = Fuzzer::new(create_test_analysis(binary.clone()), corpus.clone())
+            .with_seed(42)
+            .with_max_runs(200)
+            .run(Duration::from_secs(60))
+            .unwrap();
+
+        assert_eq!(report.seed, 42);
+        assert_eq!(report.executions, 200);
+        let crash = report
+            .crashes
+            .iter()
+            .find(|test| test.command == "\"$CLI_BINARY\" --crash")
+            .expect("minimized --crash input");
+        assert!(crash.id.starts_with("fuzz-signal-11-"));
+        assert!(crash.tags.contains(&"seed:42".to_string()));
+        assert_eq!(crash.allowed_exit_codes(), Some(&[0, 1, 2][..]));
+
+        // Saved once, and replaying it still fails
+        let corpus_tests = load_corpus(&corpus).unwrap();
+        assert_eq!(corpus_tests.len(), report.saved.len());
+        let saved = corpus_tests
+            .iter()
+            .find(|test| test.id == crash.id)
+            .unwrap();
+        let executor = NativeExecutor::new(binary.clone(), "fuzz-target".to_string(), None);
+        assert_eq!(executor.run_test(saved).status, TestStatus::Failed);
+        let fuzzer = Fuzzer::new(create_test_analysis(binary), corpus);
+        assert_eq!(fuzzer.replay(&corpus_tests).len(), corpus_tests.len());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,426 @@
+//! Performance history across runs
+//!
+//! Each run with performance measurements is appended as one JSON line to
+//! `<history_dir>/<binary_name>.jsonl`. A later run is compared against a baseline
+//! entry with a one-sided Mann-Whitney U test on the per-run wall-time samples,
+//! so noise does not trigger regressions the way a fixed threshold would.
+
+use crate::error::Result;
+use crate::types::{PerformanceMetrics, PerformanceTrend, TestReport};
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Number of runs shown in a trend (including the current run)
+const TREND_LENGTH: usize = 10;
+
+/// Performance measurements of one run
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistoryEntry {
+    /// Binary version of the run (`None` if unknown)
+    pub binary_version: Option<String>,
+
+    /// When the run finished
+    pub recorded_at: DateTime<Utc>,
+
+    /// Measurements of each performance test
+    pub tests: Vec<HistoryRecord>,
+}
+
+/// Measurements of one performance test in a run
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistoryRecord {
+    /// Generated test identifier (e.g. "perf-001")
+    pub id: String,
+
+    /// Test name
+    pub name: String,
+
+    /// Benchmark measurements
+    pub metrics: PerformanceMetrics,
+}
+
+impl HistoryEntry {
+    /// Performance measurements of a report (`None` if it has none)
+    pub fn from_report(report: &TestReport) -> Option<Self> {
+        let tests: Vec<HistoryRecord> = report
+            .performance_results()
+            .into_iter()
+            .filter_map(|test| {
+                Some(HistoryRecord {
+                    id: test.id.clone()?,
+                    name: test.name.clone(),
+                    metrics: test.performance.clone()?,
+                })
+            })
+            .collect();
+
+        if tests.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            binary_version: report.binary_version.clone(),
+            recorded_at: report.finished_at,
+            tests,
+        })
+    }
+
+    fn record(&self, id: &str) -> Option<&HistoryRecord> {
+        self.tests.iter().find(|record| record.id == id)
+    }
+}
+
+/// Performance history of one binary
+pub struct PerformanceHistory {
+    path: PathBuf,
+}
+
+impl PerformanceHistory {
+    /// History of `binary_name` stored under `history_dir`
+    pub fn new(history_dir: &Path, binary_name: &str) -> Self {
+        let file_name: String = binary_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || "-_.".contains(c) {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        Self {
+            path: history_dir.join(format!("{}.jsonl", file_name)),
+        }
+    }
+
+    /// Path of the history file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load all entries, oldest first (malformed lines are skipped with a warning)
+    pub fn load(&self) -> Result<Vec<HistoryEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path)?;
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(idx, line)| match serde_json::from_str(line) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Ignoring malformed history entry {}:{}: {}",
+                        self.path.display(),
+                        idx + 1,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    /// Append the report's measurements; returns `false` if it has none
+    pub fn record(&self, report: &TestReport) -> Result<bool> {
+        let Some(entry) = HistoryEntry::from_report(report) else {
+            return Ok(false);
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+
+        Ok(true)
+    }
+}
+
+/// Compare the report's performance tests against a baseline entry
+///
+/// The baseline is the latest entry for `baseline_version`, or the latest entry
+/// when no version is given. Returns no trends if there is no matching baseline.
+pub fn compare_with_history(
+    report: &TestReport,
+    entries: &[HistoryEntry],
+    baseline_version: Option<&str>,
+) -> Vec<PerformanceTrend> {
+    let Some(current) = HistoryEntry::from_report(report) else {
+        return Vec::new();
+    };
+
+    let baseline = entries.iter().rev().find(|entry| match baseline_version {
+        Some(version) => entry.binary_version.as_deref() == Some(version),
+        None => true,
+    });
+
+    let Some(baseline) = baseline else {
+        if let Some(version) = baseline_version {
+            warn!("No performance history for baseline version {}", version);
+        }
+        return Vec::new();
+    };
+
+    current
+        .tests
+        .iter()
+        .filter_map(|record| {
+            let base = baseline.record(&record.id)?;
+
+            let mut history_ms: Vec<f64> = entries
+                .iter()
+                .filter_map(|entry| entry.record(&record.id))
+                .map(|r| r.metrics.mean_ms)
+                .collect();
+            history_ms.push(record.metrics.mean_ms);
+            let keep_from = history_ms.len().saturating_sub(TREND_LENGTH);
+            let history_ms = history_ms.split_off(keep_from);
+
+            let change_percent = if base.metrics.mean_ms > 0.0 {
+                (record.metrics.mean_ms - base.metrics.mean_ms) / base.metrics.mean_ms * 100.0
+            } else {
+                0.0
+            };
+
+            let p_value =
+                mann_whitney_greater(&record.metrics.samples_ms, &base.metrics.samples_ms);
+
+            Some(PerformanceTrend {
+                test_id: record.id.clone(),
+                test_name: record.name.clone(),
+                baseline_version: baseline.binary_version.clone(),
+                baseline_recorded_at: baseline.recorded_at,
+                baseline_mean_ms: base.metrics.mean_ms,
+                current_mean_ms: record.metrics.mean_ms,
+                change_percent,
+                p_value,
+                history_ms,
+            })
+        })
+        .collect()
+}
+
+/// One-sided Mann-Whitney U test that `current` tends to be larger than `baseline`
+///
+/// Uses the normal approximation with continuity correction; returns `None`
+/// when either sample is empty.
+fn mann_whitney_greater(current: &[f64], baseline: &[f64]) -> Option<f64> {
+    if current.is_empty() || baseline.is_empty() {
+        return None;
+    }
+
+    let u: f64 = current
+        .iter()
+        .map(|c| {
+            baseline
+                .iter()
+                .map(|b| match c.total_cmp(b) {
+                    std::cmp::Ordering::Greater => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Less => 0.0,
+                })
+                .sum::<f64>()
+        })
+        .sum();
+
+    let (n1, n2) = (current.len() as f64, baseline.len() as f64);
+    let mean = n1 * n2 / 2.0;
+    let sigma = (n1 * n2 * (n1 + n2 + 1.0) / 12.0).sqrt();
+
+    let z = (u - mean - 0.5) / sigma;
+    Some(1.0 - standard_normal_cdf(z))
+}
+
+fn standard_normal_cdf(z: f64) -> f64 {
+    0.5 * (1.0 + erf(z / std::f64::consts::SQRT_2))
+}
+
+/// Error function (Abramowitz & Stegun 7.1.26, absolute error < 1.5e-7)
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let value = 1.0 - poly * (-x * x).exp();
+    if x >= 0.0 {
+        value
+    } else {
+        -value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EnvironmentInfo, TestResult, TestStatus, TestSuite};
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn metrics(samples_ms: Vec<f64>) -> PerformanceMetrics {
+        let samples: Vec<Duration> = samples_ms
+            .iter()
+            .map(|ms| Duration::from_secs_f64(ms / 1000.0))
+            .collect();
+        PerformanceMetrics::from_samples(&samples, None).unwrap()
+    }
+
+    fn report(version: &str, samples_ms: Vec<f64>) -> TestReport {
+        let test = TestResult {
+            name: "[performance] Startup time for --help < 100ms".to_string(),
+            status: TestStatus::Passed,
+            duration: Duration::from_millis(10),
+            output: String::new(),
+            error_message: None,
+            file_path: String::new(),
+            line_number: None,
+            tags: vec![],
+            priority: Default::default(),
+            exit_code: Some(0),
+            stderr: String::new(),
+            flaky: false,
+            attempts: vec![],
+            id: Some("perf-001".to_string()),
+            command: None,
+            performance: Some(metrics(samples_ms)),
+        };
+
+        TestReport {
+            binary_name: "test-cli".to_string(),
+            binary_version: Some(version.to_string()),
+            suites: vec![TestSuite {
+                name: "performance".to_string(),
+                file_path: String::new(),
+                tests: vec![test],
+                duration: Duration::from_millis(10),
+                started_at: Utc::now(),
+                finished_at: Utc::now(),
+            }],
+            total_duration: Duration::from_millis(10),
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+            environment: EnvironmentInfo::default(),
+            security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
+        }
+    }
+
+    fn samples(base: f64) -> Vec<f64> {
+        (0..20).map(|i| base + (i % 5) as f64 * 0.1).collect()
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let dir = TempDir::new().unwrap();
+        let history = PerformanceHistory::new(dir.path(), "test cli");
+        assert!(history.path().ends_with("test_cli.jsonl"));
+        assert!(history.load().unwrap().is_empty());
+
+        assert!(history.record(&report("1.0.0", samples(10.0))).unwrap());
+        assert!(history.record(&report("1.1.0", samples(11.0))).unwrap());
+
+        let entries = history.load().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].binary_version.as_deref(), Some("1.0.0"));
+        assert_eq!(entries[1].tests[0].id, "perf-001");
+        assert_eq!(entries[1].tests[0].metrics.samples_ms.len(), 20);
+    }
+
+    #[test]
+    fn test_record_skips_reports_without_measurements() {
+        let dir = TempDir::new().unwrap();
+        let history = PerformanceHistory::new(dir.path(), "test-cli");
+
+        let mut without = report("1.0.0", samples(10.0));
+        without.suites[0].tests[0].performance = None;
+
+        assert!(!history.record(&without).unwrap());
+        assert!(!history.path().exists());
+    }
+
+    #[test]
+    fn test_compare_detects_significant_regression() {
+        let entries = vec![
+            HistoryEntry::from_report(&report("1.0.0", samples(10.0))).unwrap(),
+            HistoryEntry::from_report(&report("1.1.0", samples(10.05))).unwrap(),
+        ];
+
+        // Latest entry is the default baseline
+        let trends = compare_with_history(&report("1.2.0", samples(13.0)), &entries, None);
+        assert_eq!(trends.len(), 1);
+        let trend = &trends[0];
+        assert_eq!(trend.baseline_version.as_deref(), Some("1.1.0"));
+        assert!(trend.change_percent > 25.0);
+        assert!(trend.p_value.unwrap() < 0.001);
+        assert!(trend.is_regression(20.0));
+        assert!(!trend.is_regression(50.0));
+        assert_eq!(trend.history_ms.len(), 3);
+
+        // Explicit baseline version
+        let trends = compare_with_history(&report("1.2.0", samples(13.0)), &entries, Some("1.0.0"));
+        assert_eq!(trends[0].baseline_version.as_deref(), Some("1.0.0"));
+
+        assert!(
+            compare_with_history(&report("1.2.0", samples(13.0)), &entries, Some("0.9.0"))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_compare_ignores_noise() {
+        let entries = vec![HistoryEntry::from_report(&report("1.0.0", samples(10.0))).unwrap()];
+
+        // Same distribution in a different order
+        let mut noisy = samples(10.0);
+        noisy.reverse();
+        let trends = compare_with_history(&report("1.0.1", noisy), &entries, None);
+
+        assert!(trends[0].p_value.unwrap() > 0.05);
+        assert!(!trends[0].is_significant());
+    }
+
+    #[test]
+    fn test_compare_without_baseline_samples_uses_means() {
+        let mut baseline = HistoryEntry::from_report(&report("1.0.0", samples(10.0))).unwrap();
+        baseline.tests[0].metrics.samples_ms.clear();
+
+        let trends = compare_with_history(&report("1.1.0", samples(13.0)), &[baseline], None);
+        let trend = &trends[0];
+        assert_eq!(trend.p_value, None);
+        assert!(!trend.is_significant());
+        assert!(trend.is_regression(20.0));
+        assert!(!trend.is_regression(50.0));
+    }
+
+    #[test]
+    fn test_mann_whitney_greater() {
+        let low: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let high: Vec<f64> = (10..20).map(|i| i as f64).collect();
+
+        assert!(mann_whitney_greater(&high, &low).unwrap() < 0.001);
+        assert!(mann_whitney_greater(&low, &high).unwrap() > 0.999);
+        assert!(mann_whitney_greater(&[], &low).is_none());
+    }
+
+    #[test]
+    fn test_erf() {
+        assert!(erf(0.0).abs() < 1e-7);
+        assert!((erf(1.0) - 0.842_700_79).abs() < 1e-6);
+        assert!((erf(-1.0) + 0.842_700_79).abs() < 1e-6);
+    }
+}
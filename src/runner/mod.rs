@@ -1,11 +1,14 @@
 //! # Runner Module
 //!
 //! Executes BATS (Bash Automated Testing System) test suites and collects results.
+//! [`NativeExecutor`] runs generated `TestCase`s directly, without bash or BATS.
+//! [`Fuzzer`] runs random command lines generated from the analysis and saves
+//! crashing inputs as `TestCase`s.
 //!
 //! ## Features
 //!
 //! - Parallel test execution with configurable workers
-//! - Timeout management per test file
+//! - Per-test and per-suite timeout management
 //! - TAP (Test Anything Protocol) output parsing
 //! - Category-based test filtering
 //! - Shell compatibility validation
@@ -48,6 +51,17 @@
 //! ```
 
 pub mod bats_executor;
+pub mod command_line;
+pub mod enum_matrix;
+pub mod fuzzer;
+pub mod history;
+pub mod native_executor;
+pub mod security_findings;
 
-// Re-export main executor
+// Re-export main executors
 pub use bats_executor::BatsExecutor;
+pub use enum_matrix::extract_enum_matrices;
+pub use fuzzer::{load_corpus, CrashKind, FuzzReport, Fuzzer};
+pub use history::{compare_with_history, PerformanceHistory};
+pub use native_executor::NativeExecutor;
+pub use security_findings::extract_security_findings;
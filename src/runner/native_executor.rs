@@ -0,0 +1,1378 @@
+use crate::error::{Error, Result};
+use crate::runner::command_line::{parse_command, ParsedCommand};
+use crate::runner::enum_matrix::extract_enum_matrices;
+use crate::runner::security_findings::extract_security_findings;
+use crate::types::test_case::value_rejected;
+use crate::types::{
+    Assertion, EnvironmentInfo, PerformanceBudget, PerformanceMetrics, TestCase, TestCategory,
+    TestReport, TestResult, TestStatus, TestSuite,
+};
+use crate::utils::ResourceLimits;
+use chrono::{DateTime, Utc};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use log::{debug, info, warn};
+use rayon::prelude::*;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+#[cfg(not(unix))]
+use wait_timeout::ChildExt;
+
+/// Counter for unique per-test temporary directories
+static TEMP_DIR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Native test executor
+///
+/// Runs generated [`TestCase`]s directly (no bash, no BATS): each command is
+/// parsed by [`parse_command`], spawned as a child process, and its exit code and
+/// output are checked against `expected_exit` and every [`Assertion`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use cli_testing_specialist::analyzer::CliParser;
+/// use cli_testing_specialist::generator::TestGenerator;
+/// use cli_testing_specialist::runner::NativeExecutor;
+/// use cli_testing_specialist::types::TestCategory;
+/// use std::path::Path;
+///
+/// let analysis = CliParser::new().analyze(Path::new("/usr/bin/curl"))?;
+/// let tests = TestGenerator::new(analysis.clone(), vec![TestCategory::Basic]).generate()?;
+///
+/// let executor = NativeExecutor::new(
+///     analysis.binary_path.clone(),
+///     analysis.binary_name.clone(),
+///     analysis.version.clone(),
+/// );
+/// let report = executor.run_tests(&tests)?;
+/// println!("Tests passed: {}/{}", report.total_passed(), report.total_tests());
+/// # Ok::<(), cli_testing_specialist::error::CliTestError>(())
+/// ```
+pub struct NativeExecutor {
+    /// Path to the binary under test (exposed to tests as `$CLI_BINARY`)
+    binary_path: PathBuf,
+
+    /// Binary name being tested
+    binary_name: String,
+
+    /// Binary version (if available)
+    binary_version: Option<String>,
+
+    /// Timeout per test case in seconds
+    timeout: u64,
+
+    /// Number of times a failed test is retried
+    retry_count: u32,
+
+    /// Number of tests executed concurrently
+    jobs: usize,
+
+    /// Categories to skip (optional)
+    skip_categories: Option<Vec<String>>,
+
+    /// Limits applied to every spawned command (optional, Unix only)
+    limits: Option<ResourceLimits>,
+}
+
+/// A test result with the wall-clock window it ran in
+struct TimedResult {
+    result: TestResult,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+}
+
+/// Raw outcome of running one command
+struct Execution {
+    stdout: String,
+    stderr: String,
+    exit_code: Option<i32>,
+    timed_out: bool,
+    duration: Duration,
+    /// Peak resident set size of the child in KiB (`None` if unavailable)
+    max_rss_kb: Option<u64>,
+}
+
+impl NativeExecutor {
+    /// Create new native executor with default timeout (30 seconds per test)
+    pub fn new(binary_path: PathBuf, binary_name: String, binary_version: Option<String>) -> Self {
+        Self {
+            binary_path,
+            binary_name,
+            binary_version,
+            timeout: 30,
+            retry_count: 0,
+            jobs: 1,
+            skip_categories: None,
+            limits: None,
+        }
+    }
+
+    /// Set timeout per test case in seconds
+    pub fn with_timeout(mut self, timeout: u64) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how many times a failed test is retried
+    pub fn with_retry_count(mut self, retry_count: u32) -> Self {
+        self.retry_count = retry_count;
+        self
+    }
+
+    /// Set how many tests run concurrently (default: 1)
+    ///
+    /// Results are merged back in generation order regardless of completion order.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs.max(1);
+        self
+    }
+
+    /// Set categories to skip
+    pub fn with_skip_categories(mut self, skip: Vec<String>) -> Self {
+        self.skip_categories = Some(skip);
+        self
+    }
+
+    /// Apply memory, file descriptor and process limits to every spawned command
+    ///
+    /// The per-test timeout is still set with [`with_timeout`](Self::with_timeout).
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Execute test cases and generate report
+    ///
+    /// Tests are grouped into one suite per category, in order of first appearance.
+    /// With [`with_jobs`](Self::with_jobs) > 1, tests run concurrently on a bounded pool.
+    pub fn run_tests(&self, test_cases: &[TestCase]) -> Result<TestReport> {
+        let start_time = Instant::now();
+        let started_at = Utc::now();
+
+        // Group by category, preserving generation order
+        let mut groups: Vec<(TestCategory, Vec<&TestCase>)> = Vec::new();
+        for test in test_cases {
+            if self.is_skipped(test.category) {
+                continue;
+            }
+            match groups.iter_mut().find(|(c, _)| *c == test.category) {
+                Some((_, tests)) => tests.push(test),
+                None => groups.push((test.category, vec![test])),
+            }
+        }
+
+        let total: usize = groups.iter().map(|(_, t)| t.len()).sum();
+        let jobs = self.jobs.clamp(1, total.max(1));
+        info!(
+            "Executing {} tests in {} suites with {} worker(s) (native runner)",
+            total,
+            groups.len(),
+            jobs
+        );
+
+        // Overall progress bar plus one bar per suite
+        let multi = MultiProgress::new();
+        let pb = multi.add(ProgressBar::new(total as u64));
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template(
+                    "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}",
+                )
+                .unwrap()
+                .progress_chars("#>-"),
+        );
+        let suite_style = ProgressStyle::default_bar()
+            .template("  {prefix:<20} [{bar:30.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("#>-");
+        let suite_bars: Vec<ProgressBar> = groups
+            .iter()
+            .map(|(category, tests)| {
+                let bar = multi.add(ProgressBar::new(tests.len() as u64));
+                bar.set_style(suite_style.clone());
+                bar.set_prefix(category.as_str());
+                bar
+            })
+            .collect();
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .map_err(|e| Error::ExecutionFailed(format!("Failed to create worker pool: {}", e)))?;
+
+        // Run every test on the bounded pool; collect() keeps generation order
+        let work: Vec<(usize, &TestCase)> = groups
+            .iter()
+            .enumerate()
+            .flat_map(|(index, (_, tests))| tests.iter().map(move |test| (index, *test)))
+            .collect();
+        let mut outcomes: Vec<Vec<TimedResult>> = groups.iter().map(|_| Vec::new()).collect();
+        let results: Vec<(usize, TimedResult)> = pool.install(|| {
+            work.par_iter()
+                .map(|&(index, test)| {
+                    suite_bars[index].set_message(test.id.clone());
+                    let started_at = Utc::now();
+                    let result = self.run_test_with_retries(test);
+                    let finished_at = Utc::now();
+                    suite_bars[index].inc(1);
+                    pb.inc(1);
+                    (
+                        index,
+                        TimedResult {
+                            result,
+                            started_at,
+                            finished_at,
+                        },
+                    )
+                })
+                .collect()
+        });
+        for (index, outcome) in results {
+            outcomes[index].push(outcome);
+        }
+
+        let mut suites = Vec::new();
+        for (((category, _), outcomes), bar) in groups.iter().zip(outcomes).zip(&suite_bars) {
+            let suite_started_at = outcomes
+                .iter()
+                .map(|o| o.started_at)
+                .min()
+                .unwrap_or_else(Utc::now);
+            let suite_finished_at = outcomes
+                .iter()
+                .map(|o| o.finished_at)
+                .max()
+                .unwrap_or(suite_started_at);
+
+            let suite = TestSuite {
+                name: category.as_str().to_string(),
+                file_path: String::new(),
+                tests: outcomes.into_iter().map(|o| o.result).collect(),
+                duration: (suite_finished_at - suite_started_at)
+                    .to_std()
+                    .unwrap_or_default(),
+                started_at: suite_started_at,
+                finished_at: suite_finished_at,
+            };
+
+            info!(
+                "Suite '{}': {}/{} tests passed in {:.1}s",
+                suite.name,
+                suite.passed_count(),
+                suite.total_count(),
+                suite.duration.as_secs_f64()
+            );
+            bar.finish_with_message(format!(
+                "{}/{} passed",
+                suite.passed_count(),
+                suite.total_count()
+            ));
+            suites.push(suite);
+        }
+
+        pb.finish_with_message("All test suites completed");
+
+        let security_findings = extract_security_findings(&suites);
+        let enum_matrices = extract_enum_matrices(&suites);
+
+        Ok(TestReport {
+            binary_name: self.binary_name.clone(),
+            binary_version: self.binary_version.clone(),
+            suites,
+            total_duration: start_time.elapsed(),
+            started_at,
+            finished_at: Utc::now(),
+            environment: EnvironmentInfo {
+                shell: "none (native runner)".to_string(),
+                bats_version: "n/a (native runner)".to_string(),
+                ..Default::default()
+            },
+            security_findings,
+            performance_trends: vec![],
+            enum_matrices,
+        })
+    }
+
+    /// Execute a test case, retrying failures up to `retry_count` times
+    ///
+    /// Every attempt is kept on the result; a pass after a failure marks it flaky.
+    pub fn run_test_with_retries(&self, test: &TestCase) -> TestResult {
+        let mut result = self.run_test(test);
+
+        for attempt in 1..=self.retry_count {
+            if !result.status.is_failure() {
+                break;
+            }
+            debug!(
+                "Retrying test {} (attempt {}/{})",
+                test.id,
+                attempt + 1,
+                self.retry_count + 1
+            );
+            result = result.with_retry(self.run_test(test));
+        }
+
+        if result.flaky {
+            warn!(
+                "Flaky test {}: passed after {} attempts",
+                test.id,
+                result.attempts.len()
+            );
+        }
+
+        result
+    }
+
+    /// Execute a single test case and evaluate its expectations
+    pub fn run_test(&self, test: &TestCase) -> TestResult {
+        let name = format!("[{}] {}", test.category.as_str(), test.name);
+        debug!("Running native test {}: {}", test.id, test.command);
+
+        let mut result = TestResult {
+            name,
+            status: TestStatus::Passed,
+            duration: Duration::ZERO,
+            output: String::new(),
+            error_message: None,
+            file_path: String::new(),
+            line_number: None,
+            tags: test.tags.clone(),
+            priority: test.priority,
+            exit_code: None,
+            stderr: String::new(),
+            flaky: false,
+            attempts: vec![],
+            id: Some(test.id.clone()),
+            command: Some(test.command.clone()),
+            performance: None,
+        };
+
+        // Required programs (e.g. the shell of a multi-shell test) must be installed
+        if let Some(missing) = test.requires.iter().find(|p| !is_installed(p)) {
+            debug!("Skipping test {}: {} is not installed", test.id, missing);
+            result.status = TestStatus::Skipped;
+            result.error_message = Some(format!("{} is not installed", missing));
+            return result;
+        }
+
+        if test.budget.as_ref().is_some_and(|b| b.skip_in_ci) && running_in_ci() {
+            debug!(
+                "Skipping test {}: performance tests are skipped in CI",
+                test.id
+            );
+            result.status = TestStatus::Skipped;
+            result.error_message = Some("Performance tests are skipped in CI".to_string());
+            return result;
+        }
+
+        let temp_dir = match create_test_temp_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                result.status = TestStatus::Failed;
+                result.error_message = Some(e.to_string());
+                return result;
+            }
+        };
+
+        let vars = self.test_variables(&temp_dir);
+
+        // Commands outside the supported shell subset cannot run natively
+        let parsed = match parse_command(&test.command, &vars) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                warn!("Skipping test {}: {}", test.id, e);
+                let _ = fs::remove_dir_all(&temp_dir);
+                result.status = TestStatus::Skipped;
+                result.error_message = Some(e.to_string());
+                return result;
+            }
+        };
+
+        let timeout = test.timeout.unwrap_or(self.timeout);
+        let execution = match self.execute(&parsed, &vars, Duration::from_secs(timeout)) {
+            Ok(execution) => execution,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&temp_dir);
+                result.status = TestStatus::Failed;
+                result.error_message = Some(e.to_string());
+                return result;
+            }
+        };
+
+        result.duration = execution.duration;
+        result.exit_code = execution.exit_code;
+
+        if execution.timed_out {
+            result.status = TestStatus::Timeout;
+            result.error_message = Some(format!("Test timed out after {}s", timeout));
+        } else {
+            let mut failures = evaluate(test, &execution, &vars);
+            failures.extend(self.compare_outcomes(
+                test,
+                &execution,
+                &vars,
+                Duration::from_secs(timeout),
+            ));
+
+            // The first run doubles as the warm-up for performance budgets
+            if let (Some(budget), true) = (&test.budget, failures.is_empty()) {
+                match self.benchmark(&parsed, &vars, Duration::from_secs(timeout), budget) {
+                    Ok(metrics) => {
+                        result.duration += Duration::from_secs_f64(
+                            metrics.mean_ms * metrics.iterations as f64 / 1000.0,
+                        );
+                        failures.extend(budget_violations(budget, &metrics));
+                        result.performance = Some(metrics);
+                    }
+                    Err(e) => failures.push(e.to_string()),
+                }
+            }
+
+            if !failures.is_empty() {
+                result.status = TestStatus::Failed;
+                result.error_message = Some(failures.join("; "));
+            }
+        }
+
+        if let Err(e) = fs::remove_dir_all(&temp_dir) {
+            debug!("Failed to remove {}: {}", temp_dir.display(), e);
+        }
+
+        result.output = execution.stdout;
+        result.stderr = execution.stderr;
+        result
+    }
+
+    fn is_skipped(&self, category: TestCategory) -> bool {
+        self.skip_categories
+            .as_ref()
+            .is_some_and(|skip| skip.iter().any(|s| category.as_str().contains(s.as_str())))
+    }
+
+    /// Variables available to test commands (mirrors the BATS `setup()` function)
+    fn test_variables(&self, temp_dir: &Path) -> HashMap<String, String> {
+        let mut vars = HashMap::new();
+        vars.insert(
+            "CLI_BINARY".to_string(),
+            self.binary_path.to_string_lossy().to_string(),
+        );
+        vars.insert("BINARY_BASENAME".to_string(), self.binary_name.clone());
+        vars.insert(
+            "TEST_TEMP_DIR".to_string(),
+            temp_dir.to_string_lossy().to_string(),
+        );
+        vars
+    }
+
+    /// Run the reference command of each `SameOutcomeAs` assertion and compare outcomes
+    fn compare_outcomes(
+        &self,
+        test: &TestCase,
+        execution: &Execution,
+        vars: &HashMap<String, String>,
+        timeout: Duration,
+    ) -> Vec<String> {
+        let mut failures = Vec::new();
+
+        for assertion in &test.assertions {
+            let Assertion::SameOutcomeAs(reference) = assertion else {
+                continue;
+            };
+
+            let expected = match parse_command(reference, vars)
+                .and_then(|parsed| self.execute(&parsed, vars, timeout))
+            {
+                Ok(expected) => expected,
+                Err(e) => {
+                    failures.push(format!("reference command `{}` failed: {}", reference, e));
+                    continue;
+                }
+            };
+
+            if expected.exit_code != execution.exit_code {
+                let code = |c: Option<i32>| c.map_or("none".to_string(), |c| c.to_string());
+                failures.push(format!(
+                    "exit code {} differs from {} of `{}`",
+                    code(execution.exit_code),
+                    code(expected.exit_code),
+                    reference
+                ));
+            }
+            if expected.stdout != execution.stdout || expected.stderr != execution.stderr {
+                failures.push(format!("output differs from `{}`", reference));
+            }
+        }
+
+        failures
+    }
+
+    /// Run the command `budget.iterations` times and collect startup and memory statistics
+    fn benchmark(
+        &self,
+        parsed: &ParsedCommand,
+        vars: &HashMap<String, String>,
+        timeout: Duration,
+        budget: &PerformanceBudget,
+    ) -> Result<PerformanceMetrics> {
+        let mut samples = Vec::with_capacity(budget.iterations as usize);
+        let mut max_rss_kb: Option<u64> = None;
+
+        for run in 1..=budget.iterations {
+            let execution = self.execute(parsed, vars, timeout)?;
+            if execution.timed_out {
+                return Err(Error::ExecutionFailed(format!(
+                    "benchmark run {} timed out after {}s",
+                    run,
+                    timeout.as_secs()
+                )));
+            }
+
+            samples.push(execution.duration);
+            if let Some(rss) = execution.max_rss_kb {
+                max_rss_kb = Some(max_rss_kb.map_or(rss, |max| max.max(rss)));
+            }
+        }
+
+        PerformanceMetrics::from_samples(&samples, max_rss_kb).ok_or_else(|| {
+            Error::ExecutionFailed("performance budget requires at least one run".to_string())
+        })
+    }
+
+    /// Spawn the command, feed stdin, capture output and enforce the timeout
+    fn execute(
+        &self,
+        parsed: &ParsedCommand,
+        vars: &HashMap<String, String>,
+        timeout: Duration,
+    ) -> Result<Execution> {
+        let start = Instant::now();
+
+        let mut command = Command::new(&parsed.program);
+
+        // Own process group so a timeout can kill the whole tree (e.g. `sh -c` children)
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+            if let Some(limits) = &self.limits {
+                limits.limit_child(&mut command);
+            }
+        }
+
+        let mut child = command
+            .args(&parsed.args)
+            .envs(vars)
+            .envs(parsed.env.iter().map(|(k, v)| (k, v)))
+            .stdin(if parsed.stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                Error::ExecutionFailed(format!("Failed to spawn '{}': {}", parsed.program, e))
+            })?;
+
+        if let (Some(data), Some(mut stdin)) = (parsed.stdin.clone(), child.stdin.take()) {
+            thread::spawn(move || {
+                // The child may exit without reading stdin; ignore broken pipes
+                let _ = stdin.write_all(data.as_bytes());
+            });
+        }
+
+        let stdout_reader = spawn_reader(child.stdout.take());
+        let stderr_reader = spawn_reader(child.stderr.take());
+
+        let (status, timed_out, max_rss_kb) = wait_with_rusage(&mut child, timeout)?;
+
+        let duration = start.elapsed();
+        let stdout = stdout_reader.join().unwrap_or_default();
+        let stderr = stderr_reader.join().unwrap_or_default();
+
+        Ok(Execution {
+            stdout,
+            stderr,
+            exit_code: if timed_out {
+                None
+            } else {
+                status.and_then(|s| s.code())
+            },
+            timed_out,
+            duration,
+            max_rss_kb,
+        })
+    }
+}
+
+/// Budget violations for a benchmarked test (empty if within budget)
+fn budget_violations(budget: &PerformanceBudget, metrics: &PerformanceMetrics) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    if let Some(max_ms) = budget.max_startup_ms {
+        if metrics.p95_ms > max_ms as f64 {
+            violations.push(format!(
+                "p95 startup time {:.1}ms exceeds budget of {}ms (mean {:.1}ms, max {:.1}ms)",
+                metrics.p95_ms, max_ms, metrics.mean_ms, metrics.max_ms
+            ));
+        }
+    }
+
+    if let (Some(max_mb), Some(rss_kb)) = (budget.max_memory_mb, metrics.max_rss_kb) {
+        if rss_kb > max_mb * 1024 {
+            violations.push(format!(
+                "peak RSS {:.1}MB exceeds budget of {}MB",
+                rss_kb as f64 / 1024.0,
+                max_mb
+            ));
+        }
+    }
+
+    violations
+}
+
+/// Whether tests are running in CI (the conventional `CI` variable is set and not "false")
+fn running_in_ci() -> bool {
+    std::env::var("CI").is_ok_and(|v| !v.is_empty() && v != "false" && v != "0")
+}
+
+/// Wait for the child, killing its process group on timeout, and report its peak RSS
+///
+/// Processes still left in the child's group once it exits are killed too.
+///
+/// wait4(2) runs on a helper thread so the resource usage is that of this child
+/// alone, unaffected by other tests running concurrently. Linux carries the
+/// high-water mark of the spawning process across exec, so `ru_maxrss` is at
+/// least the runner's own peak; a value that does not exceed it says nothing
+/// about the child and is reported as unavailable (see [`child_peak_rss_kb`]).
+#[cfg(unix)]
+pub(crate) fn wait_with_rusage(
+    child: &mut std::process::Child,
+    timeout: Duration,
+) -> Result<(Option<ExitStatus>, bool, Option<u64>)> {
+    use std::os::unix::process::ExitStatusExt;
+    use std::sync::mpsc;
+
+    let pid = child.id() as libc::pid_t;
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut status: libc::c_int = 0;
+        // SAFETY: rusage is plain old data, and wait4 only writes to the provided pointers
+        let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+        let reaped = loop {
+            let ret = unsafe { libc::wait4(pid, &mut status, 0, &mut usage) };
+            if ret == -1
+                && std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted
+            {
+                continue;
+            }
+            break ret == pid;
+        };
+        let _ = sender.send(reaped.then_some((status, usage.ru_maxrss)));
+    });
+
+    let (outcome, timed_out) = match receiver.recv_timeout(timeout) {
+        Ok(outcome) => (outcome, false),
+        Err(_) => {
+            kill_process_tree(child);
+            (receiver.recv().ok().flatten(), true)
+        }
+    };
+
+    let Some((status, max_rss)) = outcome else {
+        return Err(Error::ExecutionFailed(format!(
+            "Failed to wait for process {}",
+            pid
+        )));
+    };
+
+    // Background processes left in the group hold the output pipes open, so the
+    // readers would never see EOF
+    // SAFETY: kill(2) with a negative pid signals the process group created by process_group(0)
+    unsafe {
+        libc::kill(-pid, libc::SIGKILL);
+    }
+
+    // ru_maxrss is reported in bytes on macOS and in KiB elsewhere
+    let max_rss = max_rss.max(0) as u64;
+    let max_rss_kb = if cfg!(target_os = "macos") {
+        max_rss / 1024
+    } else {
+        max_rss
+    };
+
+    Ok((
+        Some(ExitStatus::from_raw(status)),
+        timed_out,
+        child_peak_rss_kb(max_rss_kb, inherited_rss_kb()),
+    ))
+}
+
+/// Peak RSS of a child, excluding what it inherited from the runner at spawn
+///
+/// `inherited_kb` bounds the high-water mark the child started with; read after
+/// the child exited, it is never lower than the value at exec. The child's own
+/// peak is only known when it went above that.
+#[cfg(unix)]
+fn child_peak_rss_kb(max_rss_kb: u64, inherited_kb: Option<u64>) -> Option<u64> {
+    match inherited_kb {
+        Some(inherited) if max_rss_kb <= inherited => None,
+        _ => Some(max_rss_kb),
+    }
+}
+
+/// High-water RSS of the runner itself in KiB (Linux only; `VmHWM` in /proc/self/status)
+#[cfg(unix)]
+fn inherited_rss_kb() -> Option<u64> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+
+    std::fs::read_to_string("/proc/self/status")
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(not(unix))]
+pub(crate) fn wait_with_rusage(
+    child: &mut std::process::Child,
+    timeout: Duration,
+) -> Result<(Option<ExitStatus>, bool, Option<u64>)> {
+    match child.wait_timeout(timeout)? {
+        Some(status) => Ok((Some(status), false, None)),
+        None => {
+            kill_process_tree(child);
+            Ok((child.wait().ok(), true, None))
+        }
+    }
+}
+
+/// Kill a timed-out child together with its process group
+pub(crate) fn kill_process_tree(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        // SAFETY: kill(2) with a negative pid signals the process group created by process_group(0)
+        unsafe {
+            libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+        }
+    }
+    let _ = child.kill();
+}
+
+/// Read a child pipe to completion on a background thread
+pub(crate) fn spawn_reader<R: Read + Send + 'static>(
+    pipe: Option<R>,
+) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        String::from_utf8_lossy(&buffer).to_string()
+    })
+}
+
+/// Whether `program` is an executable file on `PATH`
+fn is_installed(program: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+
+    std::env::split_paths(&path).any(|dir| {
+        let candidate = dir.join(program);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::metadata(&candidate)
+                .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+                .unwrap_or(false)
+        }
+        #[cfg(not(unix))]
+        {
+            candidate.is_file()
+        }
+    })
+}
+
+/// Create a private temporary directory for one test (`$TEST_TEMP_DIR`)
+fn create_test_temp_dir() -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!(
+        "cli-test-{}-{}",
+        std::process::id(),
+        TEMP_DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+    }
+
+    Ok(dir)
+}
+
+/// Check `expected_exit` and every assertion; returns failure descriptions
+fn evaluate(test: &TestCase, execution: &Execution, vars: &HashMap<String, String>) -> Vec<String> {
+    let mut failures = Vec::new();
+    let exit_code = execution.exit_code;
+
+    if let Some(codes) = test.allowed_exit_codes() {
+        match exit_code {
+            Some(actual) if codes.contains(&actual) => {}
+            Some(actual) => {
+                failures.push(format!("exit code {} is not one of {:?}", actual, codes))
+            }
+            None => failures.push("process was terminated by a signal".to_string()),
+        }
+    } else if test.checks_expected_exit() {
+        match (test.expected_exit, exit_code) {
+            (Some(expected), Some(actual)) if expected != actual => {
+                failures.push(format!("expected exit code {}, got {}", expected, actual))
+            }
+            (Some(expected), None) => failures.push(format!(
+                "expected exit code {}, process was terminated by a signal",
+                expected
+            )),
+            (None, Some(0)) => failures.push("expected non-zero exit code, got 0".to_string()),
+            _ => {}
+        }
+    }
+
+    let stdout = execution.stdout.as_str();
+    let stderr = execution.stderr.as_str();
+
+    for assertion in &test.assertions {
+        match assertion {
+            Assertion::ExitCode(code) => {
+                if exit_code != Some(*code) {
+                    failures.push(format!(
+                        "expected exit code {}, got {}",
+                        code,
+                        exit_code.map_or("none".to_string(), |c| c.to_string())
+                    ));
+                }
+            }
+            Assertion::OutputContains(text) => {
+                // "Usage:" also accepts lowercase "usage:" (Python argparse)
+                let found = if text == "Usage:" {
+                    [stdout, stderr]
+                        .iter()
+                        .any(|o| o.contains("Usage:") || o.contains("usage:"))
+                } else {
+                    stdout.contains(text.as_str()) || stderr.contains(text.as_str())
+                };
+                if !found {
+                    failures.push(format!("output does not contain '{}'", text));
+                }
+            }
+            Assertion::OutputMatches(pattern) => match Regex::new(pattern) {
+                Ok(re) => {
+                    if !re.is_match(stdout) && !re.is_match(stderr) {
+                        failures.push(format!("output does not match /{}/", pattern));
+                    }
+                }
+                Err(e) => failures.push(format!("invalid pattern /{}/: {}", pattern, e)),
+            },
+            Assertion::OutputNotContains(text) => {
+                if stdout.contains(text.as_str()) || stderr.contains(text.as_str()) {
+                    failures.push(format!("output unexpectedly contains '{}'", text));
+                }
+            }
+            Assertion::FileExists(path) => {
+                let path = expand_path(path, vars);
+                if !path.is_file() {
+                    failures.push(format!("file does not exist: {}", path.display()));
+                }
+            }
+            Assertion::FileNotExists(path) => {
+                let path = expand_path(path, vars);
+                if path.is_file() {
+                    failures.push(format!("file unexpectedly exists: {}", path.display()));
+                }
+            }
+            // Needs a second run; see NativeExecutor::compare_outcomes
+            Assertion::SameOutcomeAs(_) => {}
+            // Checked in place of expected_exit above
+            Assertion::ExitCodeIn(_) => {}
+            Assertion::ValueNotRejected { option, value } => match exit_code {
+                Some(0) => {}
+                Some(_) if value_rejected(stderr, option, value) => {
+                    failures.push(format!("value '{}' for {} was rejected", value, option))
+                }
+                Some(_) => {}
+                None => failures.push("process was terminated by a signal".to_string()),
+            },
+        }
+    }
+
+    failures
+}
+
+/// Expand `$VAR` references in an assertion path
+fn expand_path(path: &Path, vars: &HashMap<String, String>) -> PathBuf {
+    let raw = path.to_string_lossy();
+    match parse_command(&format!("\"{}\"", raw.replace('"', "\\\"")), vars) {
+        Ok(parsed) => PathBuf::from(parsed.program),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn executor() -> NativeExecutor {
+        NativeExecutor::new(PathBuf::from("/bin/sh"), "sh".to_string(), None).with_timeout(5)
+    }
+
+    fn test_case(id: &str, command: &str) -> TestCase {
+        TestCase::new(
+            id.to_string(),
+            format!("Test {}", id),
+            TestCategory::Basic,
+            command.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_run_passing_test() {
+        let test = test_case("basic-001", "\"$CLI_BINARY\" -c 'echo Usage: tool'")
+            .with_exit_code(0)
+            .with_assertion(Assertion::OutputContains("Usage:".to_string()))
+            .with_assertion(Assertion::OutputNotContains("error".to_string()))
+            .with_assertion(Assertion::OutputMatches("^Usage: [a-z]+".to_string()));
+
+        let result = executor().run_test(&test);
+
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(result.output, "Usage: tool\n");
+        assert_eq!(result.name, "[basic] Test basic-001");
+    }
+
+    #[test]
+    fn test_run_failing_exit_code() {
+        let test =
+            test_case("basic-002", "\"$CLI_BINARY\" -c 'echo oops >&2; exit 3'").with_exit_code(0);
+
+        let result = executor().run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Failed);
+        assert_eq!(result.exit_code, Some(3));
+        assert_eq!(result.stderr, "oops\n");
+        assert!(result
+            .error_message
+            .unwrap()
+            .contains("expected exit code 0, got 3"));
+    }
+
+    #[test]
+    fn test_value_not_rejected() {
+        let not_rejected = Assertion::ValueNotRejected {
+            option: "--jobs".to_string(),
+            value: "10".to_string(),
+        };
+
+        // A usage error about a missing positional does not reject the value
+        let test = test_case(
+            "input-run-jobs-valid",
+            "\"$CLI_BINARY\" -c 'echo \"error: <TEST_DIR> is required\" >&2; exit 2'",
+        )
+        .with_assertion(not_rejected.clone());
+        assert_eq!(executor().run_test(&test).status, TestStatus::Passed);
+
+        let test = test_case(
+            "input-run-jobs-valid",
+            "\"$CLI_BINARY\" -c 'echo \"error: invalid value 10 for --jobs\" >&2; exit 2'",
+        )
+        .with_assertion(not_rejected);
+        let result = executor().run_test(&test);
+        assert_eq!(result.status, TestStatus::Failed);
+        assert_eq!(
+            result.error_message.as_deref(),
+            Some("value '10' for --jobs was rejected")
+        );
+    }
+
+    #[test]
+    fn test_expect_nonzero_exit() {
+        let test = test_case("basic-003", "\"$CLI_BINARY\" -c 'exit 0'").expect_nonzero_exit();
+        assert_eq!(executor().run_test(&test).status, TestStatus::Failed);
+
+        let test = test_case("basic-004", "\"$CLI_BINARY\" -c 'exit 2'").expect_nonzero_exit();
+        assert_eq!(executor().run_test(&test).status, TestStatus::Passed);
+    }
+
+    #[test]
+    fn test_stdin_from_echo_pipe() {
+        let test = test_case(
+            "destructive-001",
+            "echo 'n' | \"$CLI_BINARY\" -c 'read a; echo got-$a'",
+        )
+        .with_exit_code(0)
+        .with_assertion(Assertion::OutputContains("got-n".to_string()));
+
+        let result = executor().run_test(&test);
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+    }
+
+    #[test]
+    fn test_env_prefix_and_temp_dir() {
+        let test = test_case(
+            "path-001",
+            "MARKER=\"hello\" \"$CLI_BINARY\" -c 'echo $MARKER > \"$TEST_TEMP_DIR/out\"; cat \"$TEST_TEMP_DIR/out\"'",
+        )
+        .with_exit_code(0)
+        .with_assertion(Assertion::OutputContains("hello".to_string()));
+
+        let result = executor().run_test(&test);
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+    }
+
+    #[test]
+    fn test_same_outcome_as_reference_command() {
+        let test = test_case(
+            "env-001-flag",
+            "env GREETING=hi \"$CLI_BINARY\" -c 'echo $GREETING'",
+        )
+        .with_assertion(Assertion::SameOutcomeAs(
+            "\"$CLI_BINARY\" -c 'echo hi'".to_string(),
+        ));
+        let result = executor().run_test(&test);
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+
+        let test = test_case(
+            "env-002-flag",
+            "env GREETING=hi \"$CLI_BINARY\" -c 'echo $GREETING'",
+        )
+        .with_assertion(Assertion::SameOutcomeAs(
+            "\"$CLI_BINARY\" -c 'echo bye; exit 0'".to_string(),
+        ));
+        let result = executor().run_test(&test);
+        assert_eq!(result.status, TestStatus::Failed);
+        assert!(result
+            .error_message
+            .unwrap()
+            .starts_with("output differs from"));
+
+        // A non-zero exit shared with the reference passes
+        let test = test_case(
+            "env-003-flag",
+            "env GREETING=hi \"$CLI_BINARY\" -c 'echo $GREETING; exit 2'",
+        )
+        .with_assertion(Assertion::SameOutcomeAs(
+            "\"$CLI_BINARY\" -c 'echo hi; exit 2'".to_string(),
+        ));
+        let result = executor().run_test(&test);
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+    }
+
+    #[test]
+    fn test_timeout_keeps_partial_output() {
+        let executor = executor().with_timeout(1);
+        let test =
+            test_case("perf-001", "\"$CLI_BINARY\" -c 'echo started; sleep 10'").with_exit_code(0);
+
+        let result = executor.run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Timeout);
+        assert_eq!(result.output, "started\n");
+        assert!(result.duration < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_background_process_does_not_hold_output() {
+        let executor = executor().with_timeout(30);
+        let test = test_case("basic-001", "\"$CLI_BINARY\" -c 'sleep 30 & echo started'")
+            .with_exit_code(0);
+
+        let start = Instant::now();
+        let result = executor.run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Passed);
+        assert_eq!(result.output, "started\n");
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_per_test_timeout_override() {
+        let executor = executor().with_timeout(30);
+        let test = test_case("perf-002", "\"$CLI_BINARY\" -c 'sleep 10'")
+            .with_exit_code(0)
+            .with_timeout(1);
+
+        let result = executor.run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Timeout);
+        assert_eq!(
+            result.error_message.as_deref(),
+            Some("Test timed out after 1s")
+        );
+        assert!(result.duration < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_retry_marks_flaky_test() {
+        let marker_dir = tempfile::TempDir::new().unwrap();
+        let marker = marker_dir.path().join("attempted");
+        let command = format!(
+            "\"$CLI_BINARY\" -c 'test -e {0} || {{ touch {0}; echo first; exit 1; }}'",
+            marker.display()
+        );
+        let test = test_case("basic-010", &command).with_exit_code(0);
+
+        let result = executor().with_retry_count(2).run_test_with_retries(&test);
+
+        assert_eq!(result.status, TestStatus::Passed);
+        assert!(result.flaky);
+        assert_eq!(result.attempts.len(), 2);
+        assert_eq!(result.attempts[0].status, TestStatus::Failed);
+        assert_eq!(result.attempts[0].output, "first\n");
+    }
+
+    #[test]
+    fn test_no_retry_without_retry_count() {
+        let test = test_case("basic-011", "\"$CLI_BINARY\" -c 'exit 1'").with_exit_code(0);
+        let result = executor().run_test_with_retries(&test);
+
+        assert_eq!(result.status, TestStatus::Failed);
+        assert!(!result.flaky);
+        assert!(result.attempts.is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_syntax_is_skipped() {
+        let test = test_case("basic-005", "\"$CLI_BINARY\" > /dev/null");
+        let result = executor().run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Skipped);
+        assert!(result.error_message.unwrap().contains("Unsupported"));
+    }
+
+    #[test]
+    fn test_missing_program_fails() {
+        let test = test_case("basic-006", "/nonexistent/binary --help").with_exit_code(0);
+        let result = executor().run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Failed);
+        assert!(result.error_message.unwrap().contains("Failed to spawn"));
+    }
+
+    #[test]
+    fn test_missing_required_program_skips() {
+        let test = test_case("multi-shell-nosuchshell", "nosuchshell -c 'true'")
+            .with_requirement("nosuchshell-xyz".to_string());
+        let result = executor().run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Skipped);
+        assert_eq!(
+            result.error_message.as_deref(),
+            Some("nosuchshell-xyz is not installed")
+        );
+
+        // Installed requirements run normally, with CLI_BINARY read by the inner shell
+        let test = test_case(
+            "multi-shell-sh",
+            "env SHELL_NAME='sh' sh -c '\"$CLI_BINARY\" -c \"echo $SHELL_NAME\"'",
+        )
+        .with_requirement("sh".to_string())
+        .with_assertion(Assertion::OutputContains("sh".to_string()));
+        let result = executor().run_test(&test);
+
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+    }
+
+    #[test]
+    fn test_performance_budget_records_metrics() {
+        let budget = PerformanceBudget {
+            iterations: 5,
+            max_startup_ms: Some(10_000),
+            max_memory_mb: Some(10_000),
+            skip_in_ci: false,
+        };
+        let test = test_case("perf-001", "\"$CLI_BINARY\" -c 'exit 0'").with_budget(budget.clone());
+
+        let result = executor().run_test(&test);
+
+        assert_eq!(
+            result.status,
+            TestStatus::Passed,
+            "{:?}",
+            result.error_message
+        );
+        let metrics = result.performance.unwrap();
+        assert_eq!(metrics.iterations, 5);
+        assert!(metrics.mean_ms <= metrics.max_ms);
+        assert!(metrics.p95_ms <= metrics.max_ms);
+        // On Linux a shell smaller than the test runner has no measurable peak
+        assert!(metrics.max_rss_kb.map_or(true, |kb| kb > 0));
+
+        // A zero budget is always exceeded
+        let test =
+            test_case("perf-002", "\"$CLI_BINARY\" -c 'exit 0'").with_budget(PerformanceBudget {
+                max_startup_ms: Some(0),
+                max_memory_mb: Some(0),
+                ..budget
+            });
+
+        let result = executor().run_test(&test);
+
+        assert_eq!(result.status, TestStatus::Failed);
+        let error = result.error_message.unwrap();
+        assert!(error.contains("p95 startup time"), "{}", error);
+        let metrics = result.performance.unwrap();
+        assert_eq!(
+            error.contains("exceeds budget of 0MB"),
+            metrics.max_rss_kb.is_some(),
+            "{}",
+            error
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_child_peak_rss_excludes_inherited() {
+        // The runner peaked at 50MB; a child reporting that much only inherited it
+        assert_eq!(child_peak_rss_kb(50 * 1024, Some(50 * 1024)), None);
+        assert_eq!(
+            child_peak_rss_kb(80 * 1024, Some(50 * 1024)),
+            Some(80 * 1024)
+        );
+        assert_eq!(child_peak_rss_kb(2048, None), Some(2048));
+    }
+
+    #[test]
+    fn test_budget_violations() {
+        let budget = PerformanceBudget {
+            iterations: 20,
+            max_startup_ms: Some(100),
+            max_memory_mb: Some(50),
+            skip_in_ci: false,
+        };
+        let metrics = PerformanceMetrics {
+            iterations: 20,
+            mean_ms: 40.0,
+            p95_ms: 90.0,
+            max_ms: 120.0,
+            max_rss_kb: Some(40 * 1024),
+            samples_ms: vec![],
+        };
+        assert!(budget_violations(&budget, &metrics).is_empty());
+
+        let metrics = PerformanceMetrics {
+            p95_ms: 150.0,
+            max_rss_kb: Some(60 * 1024),
+            ..metrics
+        };
+        assert_eq!(
+            budget_violations(&budget, &metrics),
+            vec![
+                "p95 startup time 150.0ms exceeds budget of 100ms (mean 40.0ms, max 120.0ms)",
+                "peak RSS 60.0MB exceeds budget of 50MB",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_tests_groups_by_category() {
+        let tests = vec![
+            test_case("basic-001", "\"$CLI_BINARY\" -c 'exit 0'").with_exit_code(0),
+            TestCase::new(
+                "security-001".to_string(),
+                "Injection".to_string(),
+                TestCategory::Security,
+                "\"$CLI_BINARY\" -c 'exit 1'".to_string(),
+            )
+            .expect_nonzero_exit(),
+            test_case("basic-002", "\"$CLI_BINARY\" -c 'exit 0'").with_exit_code(0),
+        ];
+
+        let report = executor().run_tests(&tests).unwrap();
+
+        assert_eq!(report.suites.len(), 2);
+        assert_eq!(report.suites[0].name, "basic");
+        assert_eq!(report.suites[0].tests.len(), 2);
+        assert_eq!(report.suites[1].name, "security");
+        assert!(report.all_passed());
+
+        let skipped = executor()
+            .with_skip_categories(vec!["security".to_string()])
+            .run_tests(&tests)
+            .unwrap();
+        assert_eq!(skipped.suites.len(), 1);
+    }
+
+    #[test]
+    fn test_parallel_run_keeps_generation_order() {
+        // Earlier tests sleep longer, so they finish last when run concurrently
+        let tests: Vec<TestCase> = (0..4)
+            .map(|i| {
+                test_case(
+                    &format!("basic-{:03}", i + 1),
+                    &format!("\"$CLI_BINARY\" -c 'sleep 0.{}'", (4 - i) * 2),
+                )
+                .with_exit_code(0)
+            })
+            .collect();
+
+        let report = executor().with_jobs(4).run_tests(&tests).unwrap();
+
+        assert_eq!(report.suites.len(), 1);
+        let names: Vec<_> = report.suites[0]
+            .tests
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        let expected: Vec<_> = tests
+            .iter()
+            .map(|t| format!("[basic] {}", t.name))
+            .collect();
+        assert_eq!(names, expected);
+        assert!(report.all_passed());
+    }
+}
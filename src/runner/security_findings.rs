@@ -0,0 +1,289 @@
+use crate::types::report::{SecurityFinding, SecuritySeverity};
+use crate::types::{TestResult, TestSuite};
+
+/// Summary and rule metadata of a vulnerability class
+pub(crate) struct VulnerabilityClass {
+    /// Test tag and finding category (e.g. "injection")
+    pub category: &'static str,
+
+    /// SARIF rule name
+    pub name: &'static str,
+
+    /// One-line finding description
+    pub summary: &'static str,
+
+    /// What the failed check means
+    pub full_description: &'static str,
+
+    /// How to fix it
+    pub help: &'static str,
+
+    /// Matching CWE identifier
+    pub cwe: &'static str,
+}
+
+/// Vulnerability classes recognised from test tags, most specific first
+pub(crate) const VULNERABILITY_CLASSES: &[VulnerabilityClass] = &[
+    VulnerabilityClass {
+        category: "null-byte",
+        name: "NullByteInjection",
+        summary: "Null byte in argument was not rejected",
+        full_description: "The CLI accepted an argument containing an embedded null byte, \
+            which can truncate strings passed to C APIs and bypass validation.",
+        help: "Reject arguments containing NUL characters before using them as paths or \
+            passing them to system calls.",
+        cwe: "CWE-158",
+    },
+    VulnerabilityClass {
+        category: "injection",
+        name: "CommandInjection",
+        summary: "Command injection payload was not rejected",
+        full_description: "The CLI accepted an argument containing shell metacharacters \
+            (e.g. `; rm -rf /`) instead of rejecting it.",
+        help: "Never pass user-supplied arguments through a shell. Validate option values \
+            and reject shell metacharacters where they are not expected.",
+        cwe: "CWE-78",
+    },
+    VulnerabilityClass {
+        category: "path-traversal",
+        name: "PathTraversal",
+        summary: "Path traversal sequence was not rejected",
+        full_description: "The CLI accepted a path containing `../` sequences that escape \
+            the intended directory.",
+        help: "Canonicalize paths and verify they stay within the allowed base directory \
+            before opening them.",
+        cwe: "CWE-22",
+    },
+    VulnerabilityClass {
+        category: "buffer-overflow",
+        name: "OversizedInput",
+        summary: "Oversized input was not handled safely",
+        full_description: "The CLI crashed or misbehaved when given an extremely long \
+            argument.",
+        help: "Bound the length of user-supplied input and fail with a clear error when \
+            it is exceeded.",
+        cwe: "CWE-120",
+    },
+];
+
+/// The vulnerability class of a finding category
+pub(crate) fn vulnerability_class(category: &str) -> Option<&'static VulnerabilityClass> {
+    VULNERABILITY_CLASSES
+        .iter()
+        .find(|class| class.category == category)
+}
+
+/// Extract security findings from failed `SecurityCheck` tests
+///
+/// Each failed (or timed out) test with [`TestPriority::SecurityCheck`] becomes one
+/// [`SecurityFinding`], in suite and test order. Severity and category are derived
+/// from the test's tags; the reproducing command and observed output are attached
+/// as evidence.
+///
+/// [`TestPriority::SecurityCheck`]: crate::types::TestPriority::SecurityCheck
+///
+/// # Examples
+///
+/// ```
+/// use cli_testing_specialist::runner::extract_security_findings;
+///
+/// let findings = extract_security_findings(&[]);
+/// assert!(findings.is_empty());
+/// ```
+pub fn extract_security_findings(suites: &[TestSuite]) -> Vec<SecurityFinding> {
+    suites
+        .iter()
+        .flat_map(|suite| &suite.tests)
+        .filter(|test| test.priority.is_security_check() && test.status.is_failure())
+        .map(to_finding)
+        .collect()
+}
+
+fn to_finding(test: &TestResult) -> SecurityFinding {
+    let category = vulnerability_category(&test.tags);
+    let summary = vulnerability_class(category)
+        .map(|class| class.summary)
+        .unwrap_or("Security check failed");
+
+    SecurityFinding {
+        test_name: test.name.clone(),
+        category: category.to_string(),
+        severity: severity_from_tags(&test.tags),
+        description: match &test.error_message {
+            Some(error) => format!("{} ({})", summary, error),
+            None => summary.to_string(),
+        },
+        evidence: evidence(test),
+        test_file: test.file_path.clone(),
+        test_id: test.id.clone().unwrap_or_default(),
+        command: test.command.clone().unwrap_or_default(),
+    }
+}
+
+/// Severity from an explicit severity tag, falling back to the vulnerability class
+fn severity_from_tags(tags: &[String]) -> SecuritySeverity {
+    for tag in tags {
+        match tag.as_str() {
+            "critical" => return SecuritySeverity::Critical,
+            "high" => return SecuritySeverity::High,
+            "medium" => return SecuritySeverity::Medium,
+            "low" => return SecuritySeverity::Low,
+            "info" | "informational" => return SecuritySeverity::Info,
+            _ => {}
+        }
+    }
+
+    match vulnerability_category(tags) {
+        "null-byte" | "injection" | "path-traversal" => SecuritySeverity::High,
+        _ => SecuritySeverity::Medium,
+    }
+}
+
+/// Vulnerability class from tags (e.g. "injection"), or the first descriptive tag
+fn vulnerability_category(tags: &[String]) -> &str {
+    if let Some(class) = VULNERABILITY_CLASSES
+        .iter()
+        .find(|class| tags.iter().any(|t| t == class.category))
+    {
+        return class.category;
+    }
+
+    tags.iter()
+        .map(String::as_str)
+        .find(|tag| {
+            !matches!(
+                *tag,
+                "critical" | "high" | "medium" | "low" | "info" | "informational" | "custom"
+            )
+        })
+        .unwrap_or("security")
+}
+
+/// Observed behaviour: exit status, stdout and stderr
+fn evidence(test: &TestResult) -> String {
+    let mut evidence = String::new();
+
+    if let Some(code) = test.exit_code {
+        evidence.push_str(&format!("exit status: {}\n", code));
+    }
+    evidence.push_str(test.output.trim_end());
+    if !test.stderr.is_empty() {
+        if !evidence.is_empty() && !evidence.ends_with('\n') {
+            evidence.push('\n');
+        }
+        evidence.push_str("stderr:\n");
+        evidence.push_str(test.stderr.trim_end());
+    }
+
+    evidence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TestPriority, TestStatus};
+    use chrono::Utc;
+    use std::time::Duration;
+
+    fn result(name: &str, status: TestStatus, priority: TestPriority, tags: &[&str]) -> TestResult {
+        TestResult {
+            name: name.to_string(),
+            status,
+            duration: Duration::from_millis(10),
+            output: "accepted".to_string(),
+            error_message: Some("expected non-zero exit".to_string()),
+            file_path: "security.bats".to_string(),
+            line_number: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            priority,
+            exit_code: Some(0),
+            stderr: "warning".to_string(),
+            flaky: false,
+            attempts: vec![],
+            id: Some("security-001".to_string()),
+            command: Some("\"$CLI_BINARY\" --name 'test; rm -rf /'".to_string()),
+            performance: None,
+        }
+    }
+
+    fn suite(tests: Vec<TestResult>) -> TestSuite {
+        TestSuite {
+            name: "security".to_string(),
+            file_path: "security.bats".to_string(),
+            tests,
+            duration: Duration::from_millis(10),
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_only_failed_security_checks_become_findings() {
+        let suites = vec![suite(vec![
+            result(
+                "injection",
+                TestStatus::Failed,
+                TestPriority::SecurityCheck,
+                &["injection", "critical"],
+            ),
+            result(
+                "passed check",
+                TestStatus::Passed,
+                TestPriority::SecurityCheck,
+                &["injection"],
+            ),
+            result(
+                "failed help",
+                TestStatus::Failed,
+                TestPriority::Important,
+                &["help"],
+            ),
+        ])];
+
+        let findings = extract_security_findings(&suites);
+
+        assert_eq!(findings.len(), 1);
+        let finding = &findings[0];
+        assert_eq!(finding.test_name, "injection");
+        assert_eq!(finding.test_id, "security-001");
+        assert_eq!(finding.category, "injection");
+        assert_eq!(finding.severity, SecuritySeverity::Critical);
+        assert_eq!(finding.command, "\"$CLI_BINARY\" --name 'test; rm -rf /'");
+        assert_eq!(
+            finding.description,
+            "Command injection payload was not rejected (expected non-zero exit)"
+        );
+        assert_eq!(
+            finding.evidence,
+            "exit status: 0\naccepted\nstderr:\nwarning"
+        );
+    }
+
+    #[test]
+    fn test_severity_and_category_from_tags() {
+        let tags = |t: &[&str]| t.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            severity_from_tags(&tags(&["path-traversal"])),
+            SecuritySeverity::High
+        );
+        assert_eq!(
+            severity_from_tags(&tags(&["custom", "secrets-leak"])),
+            SecuritySeverity::Medium
+        );
+        assert_eq!(
+            severity_from_tags(&tags(&["buffer-overflow", "informational"])),
+            SecuritySeverity::Info
+        );
+
+        assert_eq!(
+            vulnerability_category(&tags(&["injection", "null-byte"])),
+            "null-byte"
+        );
+        assert_eq!(
+            vulnerability_category(&tags(&["custom", "secrets-leak"])),
+            "secrets-leak"
+        );
+        assert_eq!(vulnerability_category(&tags(&["critical"])), "security");
+    }
+}
@@ -16,12 +16,32 @@ pub struct CliAnalysis {
     /// Raw help output from --help command
     pub help_output: String,
 
+    /// CLI framework detected from the help layout
+    #[serde(default)]
+    pub framework: CliFramework,
+
     /// Detected subcommands (recursive)
     pub subcommands: Vec<Subcommand>,
 
     /// Global options available for all subcommands
     pub global_options: Vec<CliOption>,
 
+    /// Positional arguments of the binary itself (from its usage line)
+    #[serde(default)]
+    pub positional_args: Vec<PositionalArg>,
+
+    /// Conflicts, requirements and exclusive groups among the global options
+    #[serde(default)]
+    pub option_relations: Vec<OptionRelation>,
+
+    /// Environment variables read by the CLI (from `[env: ...]` hints and ENVIRONMENT sections)
+    #[serde(default)]
+    pub environment_variables: Vec<EnvironmentVariable>,
+
+    /// Documented exit statuses (from the man page's EXIT STATUS section)
+    #[serde(default)]
+    pub exit_statuses: Vec<ExitStatus>,
+
     /// Analysis metadata
     pub metadata: AnalysisMetadata,
 }
@@ -38,10 +58,18 @@ pub struct Subcommand {
     /// Options specific to this subcommand
     pub options: Vec<CliOption>,
 
-    /// Required positional arguments (e.g., `<ID>`, `<FILE>`)
+    /// Names of the required positional arguments (e.g., `<ID>`, `<FILE>`)
     #[serde(default)]
     pub required_args: Vec<String>,
 
+    /// Positional arguments (from the usage line)
+    #[serde(default)]
+    pub positional_args: Vec<PositionalArg>,
+
+    /// Conflicts, requirements and exclusive groups among this subcommand's options
+    #[serde(default)]
+    pub option_relations: Vec<OptionRelation>,
+
     /// Nested subcommands (recursive structure)
     pub subcommands: Vec<Subcommand>,
 
@@ -69,6 +97,163 @@ pub struct CliOption {
 
     /// Default value (if specified)
     pub default_value: Option<String>,
+
+    /// Environment variable that can set this option (e.g., clap's `[env: NAME=]`)
+    #[serde(default)]
+    pub env_var: Option<String>,
+
+    /// Where the option was found
+    #[serde(default)]
+    pub source: OptionSource,
+}
+
+/// Source an option was discovered from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptionSource {
+    /// `--help` output (possibly enriched by other sources)
+    #[default]
+    Help,
+
+    /// Man page only (not listed in `--help`)
+    ManPage,
+
+    /// Shell completion script only (hidden or undocumented)
+    Completion,
+}
+
+/// Environment variable documented in help output
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EnvironmentVariable {
+    /// Variable name (e.g., "NO_COLOR")
+    pub name: String,
+
+    /// Description text (if available)
+    pub description: Option<String>,
+
+    /// Option set by this variable (e.g., "--config"), if any
+    pub option: Option<String>,
+}
+
+/// Positional argument from a usage line (`<FILE>`, `[OUTPUT]`, `<PATH>...`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PositionalArg {
+    /// Argument name as shown in the usage line (e.g., "FILE")
+    pub name: String,
+
+    /// Position among the command's positional arguments (0-based)
+    pub index: usize,
+
+    /// Whether the argument must be given (`<FILE>` rather than `[FILE]`)
+    pub required: bool,
+
+    /// Whether the argument accepts several values (`<FILE>...`)
+    pub variadic: bool,
+
+    /// Inferred value type (Enum for argparse choices like `{json,yaml}`)
+    pub arg_type: OptionType,
+}
+
+/// Relation between options of one command
+///
+/// Options are named by their long flag when they have one (`--json`), else by
+/// their short flag (`-j`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OptionRelation {
+    /// The options cannot be given together ("cannot be used with --yaml")
+    Conflicts { option: String, with: String },
+
+    /// `option` is only valid together with `requires` ("requires --output")
+    Requires { option: String, requires: String },
+
+    /// At most one of the options may be given (`[--json | --yaml]` in the usage line)
+    ExclusiveGroup { options: Vec<String> },
+}
+
+impl OptionRelation {
+    /// Options named by this relation
+    pub fn options(&self) -> Vec<&str> {
+        match self {
+            Self::Conflicts { option, with } => vec![option, with],
+            Self::Requires { option, requires } => vec![option, requires],
+            Self::ExclusiveGroup { options } => options.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Whether the relation forbids giving `a` and `b` together
+    pub fn excludes(&self, a: &str, b: &str) -> bool {
+        match self {
+            Self::Conflicts { option, with } => {
+                (option == a && with == b) || (option == b && with == a)
+            }
+            Self::Requires { .. } => false,
+            Self::ExclusiveGroup { options } => {
+                options.iter().any(|o| o == a) && options.iter().any(|o| o == b)
+            }
+        }
+    }
+}
+
+/// Exit status documented for the CLI
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExitStatus {
+    /// Exit code
+    pub code: i32,
+
+    /// Meaning of the exit code (e.g., "if minor problems")
+    pub description: String,
+}
+
+/// CLI framework that produced the help output
+///
+/// Each framework lays out its help differently (section names, option value
+/// syntax, where subcommands are listed), so parsing picks a strategy per framework.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CliFramework {
+    /// clap (Rust)
+    Clap,
+
+    /// argparse (Python)
+    Argparse,
+
+    /// Click (Python)
+    Click,
+
+    /// cobra (Go)
+    Cobra,
+
+    /// commander.js (Node.js)
+    Commander,
+
+    /// GNU getopt_long (C, coreutils style)
+    GnuGetopt,
+
+    /// No known fingerprint matched; generic heuristics are used
+    #[default]
+    Unknown,
+}
+
+impl CliFramework {
+    /// Get framework name as string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Clap => "clap",
+            Self::Argparse => "argparse",
+            Self::Click => "click",
+            Self::Cobra => "cobra",
+            Self::Commander => "commander",
+            Self::GnuGetopt => "gnu_getopt",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for CliFramework {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Option type with inferred constraints
@@ -117,8 +302,13 @@ impl CliAnalysis {
             binary_name,
             version: None,
             help_output,
+            framework: CliFramework::Unknown,
             subcommands: Vec::new(),
             global_options: Vec::new(),
+            positional_args: Vec::new(),
+            option_relations: Vec::new(),
+            environment_variables: Vec::new(),
+            exit_statuses: Vec::new(),
             metadata: AnalysisMetadata {
                 analyzed_at: chrono::Utc::now().to_rfc3339(),
                 analyzer_version: env!("CARGO_PKG_VERSION").to_string(),
@@ -138,7 +328,7 @@ impl CliAnalysis {
 }
 
 /// Count total subcommands recursively
-fn count_subcommands(subcommands: &[Subcommand]) -> usize {
+pub(crate) fn count_subcommands(subcommands: &[Subcommand]) -> usize {
     subcommands.len()
         + subcommands
             .iter()
@@ -147,7 +337,7 @@ fn count_subcommands(subcommands: &[Subcommand]) -> usize {
 }
 
 /// Count total options recursively
-fn count_options(subcommands: &[Subcommand]) -> usize {
+pub(crate) fn count_options(subcommands: &[Subcommand]) -> usize {
     subcommands
         .iter()
         .map(|s| s.options.len() + count_options(&s.subcommands))
@@ -183,6 +373,8 @@ mod tests {
             },
             required: false,
             default_value: Some("30".to_string()),
+            env_var: None,
+            source: OptionSource::Help,
         };
 
         let json = serde_json::to_string(&option).unwrap();
@@ -198,6 +390,8 @@ mod tests {
             description: None,
             options: vec![],
             required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
             subcommands: vec![],
             depth: 2,
         };
@@ -207,6 +401,8 @@ mod tests {
             description: None,
             options: vec![],
             required_args: vec![],
+            positional_args: vec![],
+            option_relations: vec![],
             subcommands: vec![nested],
             depth: 1,
         };
@@ -223,11 +419,15 @@ mod tests {
                 description: None,
                 options: vec![],
                 required_args: vec![],
+                positional_args: vec![],
+                option_relations: vec![],
                 subcommands: vec![Subcommand {
                     name: "subcmd1".to_string(),
                     description: None,
                     options: vec![],
                     required_args: vec![],
+                    positional_args: vec![],
+                    option_relations: vec![],
                     subcommands: vec![],
                     depth: 1,
                 }],
@@ -238,6 +438,8 @@ mod tests {
                 description: None,
                 options: vec![],
                 required_args: vec![],
+                positional_args: vec![],
+                option_relations: vec![],
                 subcommands: vec![],
                 depth: 0,
             },
@@ -202,6 +202,10 @@ pub struct MultiShellAdjustments {
     /// Zsh-specific environment variables
     #[serde(default)]
     pub zsh_env: HashMap<String, String>,
+
+    /// Environment variables for any shell, keyed by shell name (e.g. "fish")
+    #[serde(default)]
+    pub shell_env: HashMap<String, HashMap<String, String>>,
 }
 
 /// Performance test adjustments
@@ -221,10 +225,14 @@ pub struct PerformanceAdjustments {
 /// Global test settings
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct GlobalSettings {
-    /// Timeout for all tests in seconds
+    /// Default timeout for each test in seconds
     #[serde(default = "default_timeout")]
     pub timeout: u64,
 
+    /// Per-test timeout overrides in seconds, keyed by test ID (e.g. "perf-001")
+    #[serde(default)]
+    pub test_timeouts: HashMap<String, u64>,
+
     /// Retry count for failed tests
     #[serde(default)]
     pub retry_count: u32,
@@ -242,6 +250,7 @@ impl Default for GlobalSettings {
     fn default() -> Self {
         Self {
             timeout: default_timeout(),
+            test_timeouts: HashMap::new(),
             retry_count: 0,
             verbose: false,
             env_vars: HashMap::new(),
@@ -543,11 +552,14 @@ test_adjustments:
     cancel_exit_code: 2
 global:
   timeout: 60
+  test_timeouts:
+    perf-001: 120
 "#;
 
         let config: CliTestConfig = serde_yaml::from_str(yaml).unwrap();
         assert_eq!(config.version, "1.0");
         assert_eq!(config.global.timeout, 60);
+        assert_eq!(config.global.test_timeouts.get("perf-001"), Some(&120));
 
         let security = config.test_adjustments.security.unwrap();
         assert_eq!(security.skip_options.len(), 1);
@@ -703,6 +715,7 @@ create: true
             test_adjustments: TestAdjustments::default(),
             global: GlobalSettings {
                 timeout: 60,
+                test_timeouts: HashMap::new(),
                 retry_count: 3,
                 verbose: true,
                 env_vars: {
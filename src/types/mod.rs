@@ -6,9 +6,15 @@ pub mod test_case;
 pub mod test_priority;
 
 // Re-export commonly used types
-pub use analysis::{AnalysisMetadata, CliAnalysis, CliOption, OptionType, Subcommand};
+pub use analysis::{
+    AnalysisMetadata, CliAnalysis, CliFramework, CliOption, ExitStatus, OptionRelation,
+    OptionSource, OptionType, PositionalArg, Subcommand,
+};
 pub use config::CliTestConfig;
 pub use no_args_behavior::NoArgsBehavior;
-pub use report::{EnvironmentInfo, TestReport, TestResult, TestStatus, TestSuite};
-pub use test_case::{Assertion, TestCase, TestCategory};
+pub use report::{
+    EnumMatrix, EnumMatrixCell, EnvironmentInfo, PerformanceMetrics, PerformanceTrend, TestAttempt,
+    TestReport, TestResult, TestStatus, TestSuite,
+};
+pub use test_case::{Assertion, PerformanceBudget, TestCase, TestCategory};
 pub use test_priority::TestPriority;
@@ -16,7 +16,7 @@ pub struct TestResult {
     /// Duration of test execution
     pub duration: Duration,
 
-    /// Output from the test (stdout + stderr)
+    /// Output from the test (stdout; the BATS runner merges stderr into it)
     pub output: String,
 
     /// Error message if test failed
@@ -35,6 +35,234 @@ pub struct TestResult {
     /// Test priority (extracted from tags or metadata)
     #[serde(default)]
     pub priority: TestPriority,
+
+    /// Process exit code (`None` if unknown or terminated by a signal)
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+
+    /// Standard error output (native runner only)
+    #[serde(default)]
+    pub stderr: String,
+
+    /// Test failed at least once and then passed on retry
+    #[serde(default)]
+    pub flaky: bool,
+
+    /// Every execution attempt, in order, when the test was retried (empty if it ran once)
+    #[serde(default)]
+    pub attempts: Vec<TestAttempt>,
+
+    /// Generated test identifier (e.g. "security-001")
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Command line that was executed
+    #[serde(default)]
+    pub command: Option<String>,
+
+    /// Startup time and memory measurements (performance tests only)
+    #[serde(default)]
+    pub performance: Option<PerformanceMetrics>,
+}
+
+/// Significance level for performance regression tests
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Startup time of a performance test compared against a baseline run from the history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PerformanceTrend {
+    /// Generated test identifier (e.g. "perf-001")
+    pub test_id: String,
+
+    /// Test name
+    pub test_name: String,
+
+    /// Binary version of the baseline run (`None` if unknown)
+    pub baseline_version: Option<String>,
+
+    /// When the baseline run was recorded
+    pub baseline_recorded_at: DateTime<Utc>,
+
+    /// Mean wall time of the baseline run in milliseconds
+    pub baseline_mean_ms: f64,
+
+    /// Mean wall time of this run in milliseconds
+    pub current_mean_ms: f64,
+
+    /// Relative change of the mean in percent (positive means slower)
+    pub change_percent: f64,
+
+    /// One-sided Mann-Whitney U p-value that this run is slower than the baseline
+    /// (`None` when either run has no samples)
+    pub p_value: Option<f64>,
+
+    /// Mean wall time of recent runs in milliseconds, oldest first, ending with this run
+    pub history_ms: Vec<f64>,
+}
+
+impl PerformanceTrend {
+    /// This run is significantly slower than the baseline
+    pub fn is_significant(&self) -> bool {
+        self.change_percent > 0.0 && self.p_value.is_some_and(|p| p < SIGNIFICANCE_LEVEL)
+    }
+
+    /// Significantly slower by at least `threshold_percent`
+    ///
+    /// Without a p-value (the baseline has no samples) only the means are compared.
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        let slower = self.change_percent > 0.0 && self.change_percent >= threshold_percent;
+        match self.p_value {
+            Some(_) => slower && self.is_significant(),
+            None => slower,
+        }
+    }
+}
+
+/// Benchmark measurements from repeated runs of a performance test
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PerformanceMetrics {
+    /// Number of measured runs
+    pub iterations: usize,
+
+    /// Mean wall time in milliseconds
+    pub mean_ms: f64,
+
+    /// 95th percentile wall time in milliseconds (nearest rank)
+    pub p95_ms: f64,
+
+    /// Slowest run in milliseconds
+    pub max_ms: f64,
+
+    /// Peak resident set size of the child in KiB (`None` if unavailable)
+    #[serde(default)]
+    pub max_rss_kb: Option<u64>,
+
+    /// Wall time of every run in milliseconds, in run order (for regression tests)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub samples_ms: Vec<f64>,
+}
+
+impl PerformanceMetrics {
+    /// Compute statistics from wall-time samples; `None` if there are no samples
+    pub fn from_samples(samples: &[Duration], max_rss_kb: Option<u64>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let samples_ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        let mut millis = samples_ms.clone();
+        millis.sort_by(|a, b| a.total_cmp(b));
+
+        let rank = (millis.len() * 95).div_ceil(100);
+
+        Some(Self {
+            iterations: millis.len(),
+            mean_ms: millis.iter().sum::<f64>() / millis.len() as f64,
+            p95_ms: millis[rank.max(1) - 1],
+            max_ms: millis[millis.len() - 1],
+            max_rss_kb,
+            samples_ms,
+        })
+    }
+
+    /// Parse the `perf:` diagnostic line written by BATS performance tests
+    ///
+    /// Format: `perf: iterations=20 mean_ms=1.2 p95_ms=1.5 max_ms=2.0 max_rss_kb=4096 samples_ms=1.1,1.3,...`
+    /// (`max_rss_kb` is omitted when memory could not be measured).
+    pub fn parse_diagnostic(line: &str) -> Option<Self> {
+        let fields = line.trim().strip_prefix("perf:")?;
+
+        let mut iterations = None;
+        let (mut mean_ms, mut p95_ms, mut max_ms) = (None, None, None);
+        let mut max_rss_kb = None;
+        let mut samples_ms = Vec::new();
+
+        for field in fields.split_whitespace() {
+            let (key, value) = field.split_once('=')?;
+            match key {
+                "iterations" => iterations = value.parse().ok(),
+                "mean_ms" => mean_ms = value.parse().ok(),
+                "p95_ms" => p95_ms = value.parse().ok(),
+                "max_ms" => max_ms = value.parse().ok(),
+                "max_rss_kb" => max_rss_kb = value.parse().ok(),
+                "samples_ms" => {
+                    samples_ms = value.split(',').filter_map(|s| s.parse().ok()).collect()
+                }
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            iterations: iterations?,
+            mean_ms: mean_ms?,
+            p95_ms: p95_ms?,
+            max_ms: max_ms?,
+            max_rss_kb,
+            samples_ms,
+        })
+    }
+}
+
+/// A single execution attempt of a retried test
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestAttempt {
+    /// Status of this attempt
+    pub status: TestStatus,
+
+    /// Duration of this attempt
+    pub duration: Duration,
+
+    /// Output from this attempt
+    pub output: String,
+
+    /// Standard error output from this attempt
+    #[serde(default)]
+    pub stderr: String,
+
+    /// Error message if this attempt failed
+    pub error_message: Option<String>,
+
+    /// Process exit code of this attempt
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+}
+
+impl From<&TestResult> for TestAttempt {
+    fn from(result: &TestResult) -> Self {
+        Self {
+            status: result.status,
+            duration: result.duration,
+            output: result.output.clone(),
+            stderr: result.stderr.clone(),
+            error_message: result.error_message.clone(),
+            exit_code: result.exit_code,
+        }
+    }
+}
+
+impl TestResult {
+    /// Fold a retry into this result
+    ///
+    /// The retry becomes the reported outcome, every attempt (including this
+    /// one) is kept in `attempts`, and a pass after a failure marks the test flaky.
+    pub fn with_retry(mut self, retry: TestResult) -> TestResult {
+        if self.attempts.is_empty() {
+            self.attempts.push(TestAttempt::from(&self));
+        }
+        self.attempts.push(TestAttempt::from(&retry));
+
+        let failed_before = self.attempts.iter().any(|a| a.status.is_failure());
+
+        self.status = retry.status;
+        self.duration = retry.duration;
+        self.output = retry.output;
+        self.stderr = retry.stderr;
+        self.error_message = retry.error_message;
+        self.exit_code = retry.exit_code;
+        self.performance = retry.performance;
+        self.flaky = retry.status.is_success() && failed_before;
+        self
+    }
 }
 
 /// Test execution status
@@ -64,6 +292,16 @@ impl TestStatus {
     pub fn is_success(&self) -> bool {
         matches!(self, TestStatus::Passed)
     }
+
+    /// Lowercase status name (matches the serialized form)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TestStatus::Passed => "passed",
+            TestStatus::Failed => "failed",
+            TestStatus::Skipped => "skipped",
+            TestStatus::Timeout => "timeout",
+        }
+    }
 }
 
 /// Security vulnerability finding from security check tests
@@ -86,6 +324,57 @@ pub struct SecurityFinding {
 
     /// File path where the security test is defined
     pub test_file: String,
+
+    /// Generated test identifier (e.g. "security-001")
+    #[serde(default)]
+    pub test_id: String,
+
+    /// Command line that reproduces the finding
+    #[serde(default)]
+    pub command: String,
+}
+
+/// Values tried for one enum option and whether the tool accepted them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnumMatrix {
+    /// Option as invoked, subcommand path included (e.g. "remote add --format")
+    pub option: String,
+
+    /// One cell per tried value, in test order
+    pub cells: Vec<EnumMatrixCell>,
+}
+
+impl EnumMatrix {
+    /// Tag prefix naming the option an enum test exercises (`option:--format`)
+    pub const OPTION_TAG_PREFIX: &'static str = "option:";
+
+    /// Cells whose observed behaviour differs from the expectation
+    pub fn mismatches(&self) -> usize {
+        self.cells.iter().filter(|cell| !cell.matches()).count()
+    }
+}
+
+/// One value of an [`EnumMatrix`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnumMatrixCell {
+    /// Value passed to the option
+    pub value: String,
+
+    /// Whether the value should be accepted
+    pub expected_accepted: bool,
+
+    /// Whether the tool accepted it (exit status 0); `None` if skipped or timed out
+    pub accepted: Option<bool>,
+
+    /// Generated test identifier (e.g. "enum-001-uppercase")
+    pub test_id: String,
+}
+
+impl EnumMatrixCell {
+    /// Whether the tool behaved as expected
+    pub fn matches(&self) -> bool {
+        self.accepted == Some(self.expected_accepted)
+    }
 }
 
 /// Security severity levels
@@ -163,6 +452,11 @@ impl TestSuite {
         self.tests.iter().filter(|t| t.status.is_failure()).count()
     }
 
+    /// Count flaky tests (passed on retry)
+    pub fn flaky_count(&self) -> usize {
+        self.tests.iter().filter(|t| t.flaky).count()
+    }
+
     /// Count skipped tests
     pub fn skipped_count(&self) -> usize {
         self.tests
@@ -213,6 +507,14 @@ pub struct TestReport {
     /// Security vulnerabilities detected (extracted from SecurityCheck tests)
     #[serde(default)]
     pub security_findings: Vec<SecurityFinding>,
+
+    /// Startup-time comparison against the performance history baseline
+    #[serde(default)]
+    pub performance_trends: Vec<PerformanceTrend>,
+
+    /// Accepted and rejected values per enum option (from enum input-validation tests)
+    #[serde(default)]
+    pub enum_matrices: Vec<EnumMatrix>,
 }
 
 impl TestReport {
@@ -236,6 +538,24 @@ impl TestReport {
         self.suites.iter().map(|s| s.skipped_count()).sum()
     }
 
+    /// Tests that passed only after one or more retries
+    pub fn flaky_tests(&self) -> Vec<&TestResult> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.tests)
+            .filter(|test| test.flaky)
+            .collect()
+    }
+
+    /// Performance tests with benchmark measurements
+    pub fn performance_results(&self) -> Vec<&TestResult> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.tests)
+            .filter(|test| test.performance.is_some())
+            .collect()
+    }
+
     /// Overall success rate
     pub fn success_rate(&self) -> f64 {
         if self.total_tests() == 0 {
@@ -393,6 +713,50 @@ mod tests {
         assert!(!TestStatus::Timeout.is_success());
     }
 
+    fn result(status: TestStatus, output: &str) -> TestResult {
+        TestResult {
+            name: "retried".to_string(),
+            status,
+            duration: Duration::from_millis(10),
+            output: output.to_string(),
+            error_message: None,
+            file_path: String::new(),
+            line_number: None,
+            tags: vec![],
+            priority: TestPriority::Important,
+            exit_code: None,
+            stderr: String::new(),
+            flaky: false,
+            attempts: vec![],
+            id: None,
+            command: None,
+            performance: None,
+        }
+    }
+
+    #[test]
+    fn test_with_retry_marks_flaky() {
+        let result = result(TestStatus::Failed, "first")
+            .with_retry(result(TestStatus::Timeout, "second"))
+            .with_retry(result(TestStatus::Passed, "third"));
+
+        assert_eq!(result.status, TestStatus::Passed);
+        assert!(result.flaky);
+        assert_eq!(result.output, "third");
+        let outputs: Vec<_> = result.attempts.iter().map(|a| a.output.as_str()).collect();
+        assert_eq!(outputs, vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn test_with_retry_still_failing_is_not_flaky() {
+        let result =
+            result(TestStatus::Failed, "first").with_retry(result(TestStatus::Failed, "second"));
+
+        assert_eq!(result.status, TestStatus::Failed);
+        assert!(!result.flaky);
+        assert_eq!(result.attempts.len(), 2);
+    }
+
     #[test]
     fn test_suite_counts() {
         let suite = TestSuite {
@@ -409,6 +773,13 @@ mod tests {
                     line_number: Some(5),
                     tags: vec![],
                     priority: TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "test2".to_string(),
@@ -420,6 +791,13 @@ mod tests {
                     line_number: Some(10),
                     tags: vec![],
                     priority: TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "test3".to_string(),
@@ -431,6 +809,13 @@ mod tests {
                     line_number: Some(15),
                     tags: vec![],
                     priority: TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
             ],
             duration: Duration::from_millis(300),
@@ -461,6 +846,13 @@ mod tests {
                     line_number: Some(5),
                     tags: vec![],
                     priority: TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
                 TestResult {
                     name: "test2".to_string(),
@@ -472,6 +864,13 @@ mod tests {
                     line_number: Some(10),
                     tags: vec![],
                     priority: TestPriority::Important,
+                    exit_code: None,
+                    stderr: String::new(),
+                    flaky: false,
+                    attempts: vec![],
+                    id: None,
+                    command: None,
+                    performance: None,
                 },
             ],
             duration: Duration::from_millis(200),
@@ -492,6 +891,13 @@ mod tests {
                 line_number: Some(5),
                 tags: vec![],
                 priority: TestPriority::Important,
+                exit_code: None,
+                stderr: String::new(),
+                flaky: false,
+                attempts: vec![],
+                id: None,
+                command: None,
+                performance: None,
             }],
             duration: Duration::from_millis(150),
             started_at: Utc::now(),
@@ -507,6 +913,8 @@ mod tests {
             finished_at: Utc::now(),
             environment: EnvironmentInfo::default(),
             security_findings: vec![],
+            performance_trends: vec![],
+            enum_matrices: vec![],
         };
 
         assert_eq!(report.total_tests(), 3);
@@ -516,4 +924,44 @@ mod tests {
         assert!(!report.all_passed());
         assert!((report.success_rate() - 0.666).abs() < 0.01);
     }
+
+    #[test]
+    fn test_performance_metrics_from_samples() {
+        let samples: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+        let metrics = PerformanceMetrics::from_samples(&samples, Some(2048)).unwrap();
+
+        assert_eq!(metrics.iterations, 20);
+        assert!((metrics.mean_ms - 10.5).abs() < 1e-9);
+        assert!((metrics.p95_ms - 19.0).abs() < 1e-9);
+        assert!((metrics.max_ms - 20.0).abs() < 1e-9);
+        assert_eq!(metrics.max_rss_kb, Some(2048));
+        assert_eq!(metrics.samples_ms.len(), 20);
+        assert!((metrics.samples_ms[0] - 1.0).abs() < 1e-9);
+
+        let single = PerformanceMetrics::from_samples(&[Duration::from_millis(5)], None).unwrap();
+        assert!((single.p95_ms - 5.0).abs() < 1e-9);
+
+        assert!(PerformanceMetrics::from_samples(&[], None).is_none());
+    }
+
+    #[test]
+    fn test_performance_metrics_parse_diagnostic() {
+        let metrics = PerformanceMetrics::parse_diagnostic(
+            "perf: iterations=20 mean_ms=1.250 p95_ms=2.000 max_ms=3.500 max_rss_kb=4096 samples_ms=1.000,3.500",
+        )
+        .unwrap();
+        assert_eq!(metrics.iterations, 20);
+        assert_eq!(metrics.samples_ms, vec![1.0, 3.5]);
+        assert!((metrics.p95_ms - 2.0).abs() < 1e-9);
+        assert_eq!(metrics.max_rss_kb, Some(4096));
+
+        let metrics = PerformanceMetrics::parse_diagnostic(
+            "perf: iterations=5 mean_ms=1.0 p95_ms=1.0 max_ms=1.0",
+        )
+        .unwrap();
+        assert_eq!(metrics.max_rss_kb, None);
+
+        assert!(PerformanceMetrics::parse_diagnostic("perf: iterations=5").is_none());
+        assert!(PerformanceMetrics::parse_diagnostic("Usage: tool").is_none());
+    }
 }
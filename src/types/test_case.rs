@@ -32,6 +32,42 @@ pub struct TestCase {
     /// Test priority (default: Important)
     #[serde(default)]
     pub priority: TestPriority,
+
+    /// Per-test timeout override in seconds
+    /// - `Some(n)`: Kill the command after n seconds
+    /// - `None`: Use the runner's default timeout
+    #[serde(default)]
+    pub timeout: Option<u64>,
+
+    /// Programs that must be on `PATH`; the test is skipped when one is missing
+    #[serde(default)]
+    pub requires: Vec<String>,
+
+    /// Benchmark the command against startup-time and memory budgets
+    #[serde(default)]
+    pub budget: Option<PerformanceBudget>,
+}
+
+/// Startup-time and memory budget for a performance test
+///
+/// The command is run `iterations` times (after one warm-up run); the test fails
+/// when the p95 wall time or the peak RSS of the child exceeds its budget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceBudget {
+    /// Number of measured runs
+    pub iterations: u32,
+
+    /// Maximum p95 wall time in milliseconds
+    #[serde(default)]
+    pub max_startup_ms: Option<u64>,
+
+    /// Maximum peak resident set size in MB
+    #[serde(default)]
+    pub max_memory_mb: Option<u64>,
+
+    /// Skip the test when running in CI (the `CI` environment variable is set)
+    #[serde(default)]
+    pub skip_in_ci: bool,
 }
 
 /// Test category classification
@@ -63,6 +99,15 @@ pub enum TestCategory {
 
     /// Performance tests
     Performance,
+
+    /// Environment variable tests (variable vs. flag equivalence, invalid values)
+    Environment,
+
+    /// Option relation tests (conflicting and requirement-violating combinations)
+    OptionRelations,
+
+    /// Pairwise option combination tests (no crashes; opt-in)
+    Combinatorial,
 }
 
 /// Assertion types for test validation
@@ -85,6 +130,34 @@ pub enum Assertion {
 
     /// Assert file does not exist at path
     FileNotExists(PathBuf),
+
+    /// Assert exit code, stdout and stderr match those of another command
+    /// (replaces the `expected_exit` check)
+    SameOutcomeAs(String),
+
+    /// Assert exit code is one of these (replaces the `expected_exit` check)
+    ///
+    /// A process terminated by a signal never matches.
+    ExitCodeIn(Vec<i32>),
+
+    /// Assert the option parser did not reject `value` for `option` (replaces
+    /// the `expected_exit` check)
+    ///
+    /// The command may still fail for other reasons, such as placeholder
+    /// positional arguments; see [`value_rejected`]. A process terminated by a
+    /// signal never matches.
+    ValueNotRejected { option: String, value: String },
+}
+
+/// Whether error output rejects `value` for `option`
+///
+/// Parsers name both on one line when they reject a value: clap's
+/// `invalid value 'x' for '--jobs <JOBS>'`, argparse's `argument --jobs: invalid
+/// int value: 'x'`, Click's `Invalid value for '--jobs': 'x'`.
+pub fn value_rejected(stderr: &str, option: &str, value: &str) -> bool {
+    stderr
+        .lines()
+        .any(|line| line.contains(option) && line.contains(value))
 }
 
 impl TestCase {
@@ -99,6 +172,9 @@ impl TestCase {
             assertions: Vec::new(),
             tags: Vec::new(),
             priority: TestPriority::default(), // Default to Important
+            timeout: None,                     // Use runner default
+            requires: Vec::new(),
+            budget: None,
         }
     }
 
@@ -114,6 +190,18 @@ impl TestCase {
         self
     }
 
+    /// Require a program on `PATH` (the test is skipped when it is missing)
+    pub fn with_requirement(mut self, program: String) -> Self {
+        self.requires.push(program);
+        self
+    }
+
+    /// Benchmark this test against a performance budget
+    pub fn with_budget(mut self, budget: PerformanceBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
     /// Set expected exit code
     pub fn with_exit_code(mut self, code: i32) -> Self {
         self.expected_exit = Some(code);
@@ -131,6 +219,37 @@ impl TestCase {
         self.priority = priority;
         self
     }
+
+    /// Exit codes allowed by an [`Assertion::ExitCodeIn`], which replaces `expected_exit`
+    pub fn allowed_exit_codes(&self) -> Option<&[i32]> {
+        self.assertions
+            .iter()
+            .find_map(|assertion| match assertion {
+                Assertion::ExitCodeIn(codes) => Some(codes.as_slice()),
+                _ => None,
+            })
+    }
+
+    /// Whether `expected_exit` is checked
+    ///
+    /// [`Assertion::ExitCodeIn`], [`Assertion::SameOutcomeAs`] and
+    /// [`Assertion::ValueNotRejected`] bring their own exit code check instead.
+    pub fn checks_expected_exit(&self) -> bool {
+        !self.assertions.iter().any(|assertion| {
+            matches!(
+                assertion,
+                Assertion::ExitCodeIn(_)
+                    | Assertion::SameOutcomeAs(_)
+                    | Assertion::ValueNotRejected { .. }
+            )
+        })
+    }
+
+    /// Override the runner's timeout for this test (seconds)
+    pub fn with_timeout(mut self, seconds: u64) -> Self {
+        self.timeout = Some(seconds);
+        self
+    }
 }
 
 impl TestCategory {
@@ -146,6 +265,9 @@ impl TestCategory {
             Self::DestructiveOps => "destructive-ops",
             Self::DirectoryTraversal => "directory-traversal",
             Self::Performance => "performance",
+            Self::Environment => "environment",
+            Self::OptionRelations => "option-relations",
+            Self::Combinatorial => "combinatorial",
         }
     }
 
@@ -161,15 +283,30 @@ impl TestCategory {
             Self::DestructiveOps,
             Self::DirectoryTraversal,
             Self::Performance,
+            Self::Environment,
+            Self::OptionRelations,
+            Self::Combinatorial,
         ]
     }
 
-    /// Get standard test categories (excludes resource-intensive tests)
+    /// Get every category that "all" can select (excludes opt-in tests)
+    ///
+    /// This is "all" with `--include-intensive`; `all()` also lists the opt-in
+    /// categories, which must be requested by name.
+    pub fn default_categories() -> Vec<TestCategory> {
+        Self::all()
+            .into_iter()
+            .filter(|category| !Self::opt_in().contains(category))
+            .collect()
+    }
+
+    /// Get standard test categories (excludes resource-intensive and opt-in tests)
     ///
     /// Excludes:
     /// - DirectoryTraversal: Requires significant /tmp space (100MB+) and creates many files
+    /// - Combinatorial: Only generated when requested by name
     ///
-    /// Use `--include-intensive` flag to include these categories
+    /// Use `--include-intensive` flag to include the resource-intensive categories
     pub fn standard_categories() -> Vec<TestCategory> {
         vec![
             Self::Basic,
@@ -180,6 +317,8 @@ impl TestCategory {
             Self::InputValidation,
             Self::DestructiveOps,
             Self::Performance,
+            Self::Environment,
+            Self::OptionRelations,
         ]
     }
 
@@ -192,6 +331,14 @@ impl TestCategory {
     pub fn intensive() -> Vec<TestCategory> {
         vec![Self::DirectoryTraversal]
     }
+
+    /// Get opt-in test categories
+    ///
+    /// These are never part of "all" and must be requested by name
+    /// (`-c combinatorial`): the number of tests grows with the number of options.
+    pub fn opt_in() -> Vec<TestCategory> {
+        vec![Self::Combinatorial]
+    }
 }
 
 /// Error type for parsing TestCategory from string
@@ -221,6 +368,9 @@ impl FromStr for TestCategory {
             "destructive-ops" | "destructiveops" => Ok(Self::DestructiveOps),
             "directory-traversal" | "directorytraversal" => Ok(Self::DirectoryTraversal),
             "performance" => Ok(Self::Performance),
+            "environment" | "env" => Ok(Self::Environment),
+            "option-relations" | "optionrelations" | "relations" => Ok(Self::OptionRelations),
+            "combinatorial" | "pairwise" => Ok(Self::Combinatorial),
             _ => Err(ParseCategoryError),
         }
     }
@@ -277,10 +427,63 @@ mod tests {
     #[test]
     fn test_category_all() {
         let categories = TestCategory::all();
-        assert_eq!(categories.len(), 9);
+        assert_eq!(categories.len(), 12);
         assert!(categories.contains(&TestCategory::Security));
     }
 
+    #[test]
+    fn test_category_opt_in() {
+        assert_eq!(
+            "pairwise".parse::<TestCategory>().unwrap(),
+            TestCategory::Combinatorial
+        );
+        assert!(!TestCategory::standard_categories().contains(&TestCategory::Combinatorial));
+        assert!(!TestCategory::default_categories().contains(&TestCategory::Combinatorial));
+        assert!(TestCategory::default_categories().contains(&TestCategory::DirectoryTraversal));
+        assert_eq!(TestCategory::opt_in(), vec![TestCategory::Combinatorial]);
+    }
+
+    #[test]
+    fn test_allowed_exit_codes() {
+        let test = TestCase::new(
+            "combinatorial-001".to_string(),
+            "Pairwise combination".to_string(),
+            TestCategory::Combinatorial,
+            "cli-test -a -b".to_string(),
+        );
+        assert_eq!(test.allowed_exit_codes(), None);
+        assert!(test.checks_expected_exit());
+
+        let test = test.with_assertion(Assertion::ExitCodeIn(vec![0, 2]));
+        assert_eq!(test.allowed_exit_codes(), Some(&[0, 2][..]));
+        assert!(!test.checks_expected_exit());
+    }
+
+    #[test]
+    fn test_same_outcome_replaces_expected_exit() {
+        let test = TestCase::new(
+            "env-001-flag".to_string(),
+            "NO_COLOR has the same effect as --no-color".to_string(),
+            TestCategory::Environment,
+            "env NO_COLOR=1 cli-test".to_string(),
+        )
+        .with_assertion(Assertion::SameOutcomeAs("cli-test --no-color".to_string()));
+
+        assert_eq!(test.allowed_exit_codes(), None);
+        assert!(!test.checks_expected_exit());
+    }
+
+    #[test]
+    fn test_value_rejected() {
+        let clap =
+            "error: invalid value 'abc' for '--jobs <JOBS>': invalid digit found in string\n";
+        assert!(value_rejected(clap, "--jobs", "abc"));
+
+        // A usage error about something else does not reject the value
+        let missing = "error: the following required arguments were not provided:\n  <TEST_DIR>\n\nUsage: cli run --jobs <JOBS> [TEST_DIR]\n";
+        assert!(!value_rejected(missing, "--jobs", "10"));
+    }
+
     #[test]
     fn test_assertion_serialization() {
         let assertion = Assertion::OutputContains("test".to_string());
@@ -55,6 +55,16 @@ impl TestPriority {
         }
     }
 
+    /// Parse a priority from its [`as_str`](Self::as_str) name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "critical" => Some(Self::Critical),
+            "important" => Some(Self::Important),
+            "security_check" => Some(Self::SecurityCheck),
+            _ => None,
+        }
+    }
+
     /// Check if this is a security check
     pub fn is_security_check(&self) -> bool {
         matches!(self, Self::SecurityCheck)
@@ -90,6 +100,18 @@ mod tests {
         assert_eq!(TestPriority::SecurityCheck.as_str(), "security_check");
     }
 
+    #[test]
+    fn test_priority_from_name() {
+        for priority in [
+            TestPriority::Critical,
+            TestPriority::Important,
+            TestPriority::SecurityCheck,
+        ] {
+            assert_eq!(TestPriority::from_name(priority.as_str()), Some(priority));
+        }
+        assert_eq!(TestPriority::from_name("unknown"), None);
+    }
+
     #[test]
     fn test_is_security_check() {
         assert!(!TestPriority::Critical.is_security_check());
@@ -123,6 +123,73 @@ impl ResourceLimits {
         Ok(())
     }
 
+    /// Apply resource limits to a child process before it executes (Unix only)
+    ///
+    /// Limits are only ever lowered; errors are ignored since some systems do
+    /// not allow lowering them.
+    #[cfg(unix)]
+    pub fn limit_child(&self, command: &mut std::process::Command) {
+        use std::os::unix::process::CommandExt;
+
+        // Clone limits for use in pre_exec closure
+        let max_memory = self.max_memory_bytes;
+        let max_fds = self.max_file_descriptors;
+        let max_procs = self.max_processes;
+
+        unsafe {
+            command.pre_exec(move || {
+                use libc::{getrlimit, rlimit, setrlimit, RLIMIT_AS, RLIMIT_NOFILE, RLIMIT_NPROC};
+
+                // Set memory limit (only if lower than current)
+                let mut current_limit = rlimit {
+                    rlim_cur: 0,
+                    rlim_max: 0,
+                };
+
+                // Memory limit
+                if getrlimit(RLIMIT_AS, &mut current_limit) == 0 {
+                    // Only set if we're lowering the limit (or if unlimited)
+                    if current_limit.rlim_max == libc::RLIM_INFINITY
+                        || current_limit.rlim_max > max_memory
+                    {
+                        let mem_limit = rlimit {
+                            rlim_cur: max_memory,
+                            rlim_max: max_memory,
+                        };
+                        // Ignore error - some systems may not allow lowering limits
+                        let _ = setrlimit(RLIMIT_AS, &mem_limit);
+                    }
+                }
+
+                // File descriptor limit
+                if getrlimit(RLIMIT_NOFILE, &mut current_limit) == 0
+                    && (current_limit.rlim_max == libc::RLIM_INFINITY
+                        || current_limit.rlim_max > max_fds)
+                {
+                    let fd_limit = rlimit {
+                        rlim_cur: max_fds,
+                        rlim_max: max_fds,
+                    };
+                    let _ = setrlimit(RLIMIT_NOFILE, &fd_limit);
+                }
+
+                // Process limit
+                if getrlimit(RLIMIT_NPROC, &mut current_limit) == 0
+                    && (current_limit.rlim_max == libc::RLIM_INFINITY
+                        || current_limit.rlim_max > max_procs)
+                {
+                    let proc_limit = rlimit {
+                        rlim_cur: max_procs,
+                        rlim_max: max_procs,
+                    };
+                    let _ = setrlimit(RLIMIT_NPROC, &proc_limit);
+                }
+
+                Ok(())
+            });
+        }
+    }
+
     /// Apply resource limits using Windows Job Objects
     ///
     /// Windows uses Job Objects to enforce resource limits, which is more complex
@@ -113,65 +113,7 @@ pub fn execute_with_timeout_and_limits(
     // Apply resource limits in child process (Unix only)
     #[cfg(unix)]
     if let Some(resource_limits) = limits {
-        use std::os::unix::process::CommandExt;
-
-        // Clone limits for use in pre_exec closure
-        let max_memory = resource_limits.max_memory_bytes;
-        let max_fds = resource_limits.max_file_descriptors;
-        let max_procs = resource_limits.max_processes;
-
-        unsafe {
-            command.pre_exec(move || {
-                use libc::{getrlimit, rlimit, setrlimit, RLIMIT_AS, RLIMIT_NOFILE, RLIMIT_NPROC};
-
-                // Set memory limit (only if lower than current)
-                let mut current_limit = rlimit {
-                    rlim_cur: 0,
-                    rlim_max: 0,
-                };
-
-                // Memory limit
-                if getrlimit(RLIMIT_AS, &mut current_limit) == 0 {
-                    // Only set if we're lowering the limit (or if unlimited)
-                    if current_limit.rlim_max == libc::RLIM_INFINITY
-                        || current_limit.rlim_max > max_memory
-                    {
-                        let mem_limit = rlimit {
-                            rlim_cur: max_memory,
-                            rlim_max: max_memory,
-                        };
-                        // Ignore error - some systems may not allow lowering limits
-                        let _ = setrlimit(RLIMIT_AS, &mem_limit);
-                    }
-                }
-
-                // File descriptor limit
-                if getrlimit(RLIMIT_NOFILE, &mut current_limit) == 0
-                    && (current_limit.rlim_max == libc::RLIM_INFINITY
-                        || current_limit.rlim_max > max_fds)
-                {
-                    let fd_limit = rlimit {
-                        rlim_cur: max_fds,
-                        rlim_max: max_fds,
-                    };
-                    let _ = setrlimit(RLIMIT_NOFILE, &fd_limit);
-                }
-
-                // Process limit
-                if getrlimit(RLIMIT_NPROC, &mut current_limit) == 0
-                    && (current_limit.rlim_max == libc::RLIM_INFINITY
-                        || current_limit.rlim_max > max_procs)
-                {
-                    let proc_limit = rlimit {
-                        rlim_cur: max_procs,
-                        rlim_max: max_procs,
-                    };
-                    let _ = setrlimit(RLIMIT_NPROC, &proc_limit);
-                }
-
-                Ok(())
-            });
-        }
+        resource_limits.limit_child(&mut command);
     }
 
     // Spawn child process
@@ -5,8 +5,10 @@
 use cli_testing_specialist::analyzer::{
     apply_numeric_constraints, load_enum_values, CliParser, OptionInferrer, SubcommandDetector,
 };
-use cli_testing_specialist::types::analysis::OptionType;
-use std::path::Path;
+use cli_testing_specialist::types::analysis::{
+    CliFramework, OptionRelation, OptionSource, OptionType,
+};
+use std::path::{Path, PathBuf};
 
 #[cfg(unix)]
 #[test]
@@ -160,6 +162,8 @@ fn test_option_type_inference() {
         option_type: OptionType::String,
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     };
 
     let inferred_type = inferrer.infer_type(&timeout_opt);
@@ -187,6 +191,8 @@ fn test_option_type_inference() {
         option_type: OptionType::String,
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     };
 
     let inferred_type = inferrer.infer_type(&config_opt);
@@ -204,6 +210,8 @@ fn test_option_type_inference() {
         option_type: OptionType::String,
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     };
 
     let inferred_type = inferrer.infer_type(&format_opt);
@@ -223,3 +231,219 @@ fn test_option_type_inference() {
         println!("✓ Loaded enum values for format option: {:?}", values);
     }
 }
+
+/// Captured help output under tests/fixtures/help
+fn help_fixture(tool: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/help")
+        .join(tool)
+}
+
+#[test]
+fn test_help_dir_clap_fixture() {
+    let analysis = CliParser::new()
+        .analyze_help_dir(&help_fixture("cli-test"))
+        .expect("Failed to analyze captured help");
+
+    assert_eq!(analysis.binary_name, "cli-test");
+    assert_eq!(analysis.framework, CliFramework::Clap);
+    assert_eq!(analysis.version.as_deref(), Some("1.0.10"));
+
+    let globals: Vec<_> = analysis
+        .global_options
+        .iter()
+        .filter_map(|o| o.long.as_deref())
+        .collect();
+    assert_eq!(globals, vec!["--verbose", "--help", "--version"]);
+
+    // "help" has no captured help file and is skipped
+    let names: Vec<_> = analysis
+        .subcommands
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["analyze", "generate", "run", "validate", "completion"]
+    );
+
+    let run = &analysis.subcommands[2];
+    assert!(run
+        .options
+        .iter()
+        .any(|o| o.long.as_deref() == Some("--history-dir")));
+    assert_eq!(analysis.subcommands[3].required_args, vec!["FILE"]);
+
+    // `run <TEST_DIR>` is a required path; `analyze [BINARY]` is optional
+    let test_dir = &run.positional_args[0];
+    assert_eq!(test_dir.name, "TEST_DIR");
+    assert!(test_dir.required && !test_dir.variadic);
+    assert_eq!(test_dir.arg_type, OptionType::Path);
+    assert!(!analysis.subcommands[0].positional_args[0].required);
+}
+
+#[test]
+fn test_help_dir_argparse_fixture() {
+    let analysis = CliParser::new()
+        .analyze_help_dir(&help_fixture("deploy"))
+        .expect("Failed to analyze captured help");
+
+    assert_eq!(analysis.framework, CliFramework::Argparse);
+    assert_eq!(analysis.version.as_deref(), Some("2.3.1"));
+
+    let region = analysis
+        .global_options
+        .iter()
+        .find(|o| o.long.as_deref() == Some("--region"))
+        .expect("--region should be detected");
+    assert_eq!(
+        region.option_type,
+        OptionType::Enum {
+            values: vec!["us".to_string(), "eu".to_string(), "ap".to_string()]
+        }
+    );
+    assert_eq!(region.default_value.as_deref(), Some("us"));
+
+    let names: Vec<_> = analysis
+        .subcommands
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["push", "rollback"]);
+
+    // "see --dry-run" in the positional's description is not another option
+    let push: Vec<_> = analysis.subcommands[0]
+        .options
+        .iter()
+        .filter_map(|o| o.long.as_deref())
+        .collect();
+    assert_eq!(push, vec!["--help", "--dry-run", "--replicas"]);
+    assert!(analysis.subcommands[0].option_relations.is_empty());
+
+    // `[--to VERSION | --last]` and "(requires --to)"
+    assert_eq!(
+        analysis.subcommands[1].option_relations,
+        vec![
+            OptionRelation::ExclusiveGroup {
+                options: vec!["--to".to_string(), "--last".to_string()]
+            },
+            OptionRelation::Requires {
+                option: "--force".to_string(),
+                requires: "--to".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_help_dir_same_leaf_name_under_two_parents() {
+    let analysis = CliParser::new()
+        .analyze_help_dir(&help_fixture("vault"))
+        .expect("Failed to analyze captured help");
+
+    let nested = |parent: &str| {
+        let subcommand = analysis
+            .subcommands
+            .iter()
+            .find(|s| s.name == parent)
+            .unwrap_or_else(|| panic!("{} should be detected", parent));
+        assert_eq!(subcommand.subcommands.len(), 1, "{}", parent);
+        subcommand.subcommands[0].clone()
+    };
+
+    // Both `add` subcommands are read from their own help file
+    let remote_add = nested("remote");
+    assert_eq!(remote_add.name, "add");
+    assert_eq!(remote_add.required_args, vec!["URL"]);
+    let config_add = nested("config");
+    assert_eq!(config_add.name, "add");
+    assert_eq!(config_add.required_args, vec!["KEY", "VALUE"]);
+    assert!(config_add
+        .options
+        .iter()
+        .any(|o| o.long.as_deref() == Some("--global")));
+}
+
+#[test]
+fn test_help_dir_gnu_fixture() {
+    let analysis = CliParser::new()
+        .analyze_help_dir(&help_fixture("ls"))
+        .expect("Failed to analyze captured help");
+
+    assert_eq!(analysis.framework, CliFramework::GnuGetopt);
+    assert_eq!(analysis.version.as_deref(), Some("9.1"));
+    assert!(analysis.subcommands.is_empty());
+    assert!(analysis.global_options.len() > 50);
+}
+
+#[test]
+fn test_help_dir_requires_help_file() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = CliParser::new().analyze_help_dir(dir.path());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_help_dir_completion_script_matches_help() {
+    let analysis = CliParser::new()
+        .analyze_help_dir(&help_fixture("cli-test"))
+        .expect("Failed to analyze captured help");
+
+    // clap documents every option, so completions only confirm what --help lists
+    let generate = &analysis.subcommands[1];
+    assert!(generate
+        .options
+        .iter()
+        .chain(&analysis.global_options)
+        .all(|o| o.source == OptionSource::Help));
+
+    let format = generate
+        .options
+        .iter()
+        .find(|o| o.long.as_deref() == Some("--format"))
+        .expect("--format should be detected");
+    assert!(matches!(format.option_type, OptionType::Enum { ref values } if values.len() == 3));
+}
+
+#[test]
+fn test_help_dir_completion_script_flags_hidden_options() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("help.txt"),
+        "Usage: tool [OPTIONS]\n\nOptions:\n  -v, --verbose  Enable verbose output\n  -h, --help     Print help\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("completion.zsh"),
+        r#"#compdef tool
+_tool() {
+    _arguments "${_arguments_options[@]}" : \
+'-v[Enable verbose output]' \
+'--verbose[Enable verbose output]' \
+'--debug-internals=[Dump internal state]:WHAT:(parser planner)' \
+'-h[Print help]' \
+'--help[Print help]' \
+&& ret=0
+}
+"#,
+    )
+    .unwrap();
+
+    let analysis = CliParser::new()
+        .analyze_help_dir(dir.path())
+        .expect("Failed to analyze captured help");
+
+    assert_eq!(analysis.global_options.len(), 3);
+    let hidden = &analysis.global_options[2];
+    assert_eq!(hidden.long.as_deref(), Some("--debug-internals"));
+    assert_eq!(hidden.source, OptionSource::Completion);
+    assert_eq!(
+        hidden.option_type,
+        OptionType::Enum {
+            values: vec!["parser".to_string(), "planner".to_string()]
+        }
+    );
+    assert_eq!(analysis.global_options[0].source, OptionSource::Help);
+}
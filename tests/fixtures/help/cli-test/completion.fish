@@ -0,0 +1,78 @@
+# Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
+function __fish_cli_test_global_optspecs
+    string join \n v/verbose h/help V/version
+end
+
+function __fish_cli_test_needs_command
+    # Figure out if the current invocation already has a command.
+    set -l cmd (commandline -opc)
+    set -e cmd[1]
+    argparse -s (__fish_cli_test_global_optspecs) -- $cmd 2>/dev/null
+    or return
+    if set -q argv[1]
+        # Also print the command, so this can be used to figure out what it is.
+        echo $argv[1]
+        return 1
+    end
+    return 0
+end
+
+function __fish_cli_test_using_subcommand
+    set -l cmd (__fish_cli_test_needs_command)
+    test -z "$cmd"
+    and return 1
+    contains -- $cmd[1] $argv
+end
+
+complete -c cli-test -n "__fish_cli_test_needs_command" -s v -l verbose -d 'Enable verbose output'
+complete -c cli-test -n "__fish_cli_test_needs_command" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c cli-test -n "__fish_cli_test_needs_command" -s V -l version -d 'Print version'
+complete -c cli-test -n "__fish_cli_test_needs_command" -f -a "analyze" -d 'Analyze CLI tool structure and options'
+complete -c cli-test -n "__fish_cli_test_needs_command" -f -a "generate" -d 'Generate test suites from analysis (BATS, assert_cmd, or snapbox)'
+complete -c cli-test -n "__fish_cli_test_needs_command" -f -a "run" -d 'Execute tests (BATS or native runner) and generate reports'
+complete -c cli-test -n "__fish_cli_test_needs_command" -f -a "validate" -d 'Validate analysis JSON file structure'
+complete -c cli-test -n "__fish_cli_test_needs_command" -f -a "completion" -d 'Generate shell completion scripts'
+complete -c cli-test -n "__fish_cli_test_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -l from-help-dir -d 'Build the analysis from captured help text (help.txt, version.txt, sub/<name>/help.txt)' -r -F
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -s o -l output -d 'Output JSON file path' -r -F
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -s d -l depth -d 'Maximum recursion depth for subcommands' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -l parallel -d 'Enable parallel processing'
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -l completions -d 'Also parse the bash/zsh/fish completion scripts the binary can generate'
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -s v -l verbose -d 'Enable verbose output'
+complete -c cli-test -n "__fish_cli_test_using_subcommand analyze" -s h -l help -d 'Print help'
+complete -c cli-test -n "__fish_cli_test_using_subcommand generate" -s o -l output -d 'Output directory for test files' -r -F
+complete -c cli-test -n "__fish_cli_test_using_subcommand generate" -s c -l categories -d 'Test categories to generate (comma-separated or "all")' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand generate" -s f -l format -d 'Test framework format (bats, assert_cmd, snapbox)' -r -f -a "bats\t'BATS (Bash Automated Testing System)'
+assert_cmd\t'assert_cmd (Rust testing framework)'
+snapbox\t'snapbox (Rust snapshot testing)'"
+complete -c cli-test -n "__fish_cli_test_using_subcommand generate" -l include-intensive -d 'Include resource-intensive tests (directory-traversal, large-scale performance) These tests may require significant /tmp space and memory'
+complete -c cli-test -n "__fish_cli_test_using_subcommand generate" -s v -l verbose -d 'Enable verbose output'
+complete -c cli-test -n "__fish_cli_test_using_subcommand generate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s f -l format -d 'Report format to generate' -r -f -a "markdown\t'Markdown format'
+json\t'JSON format'
+html\t'HTML format'
+junit\t'JUnit XML format'
+sarif\t'SARIF 2.1.0 format (security findings)'
+all\t'All formats'"
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s o -l output -d 'Output directory for reports' -r -F
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s t -l timeout -d 'Timeout per test suite in seconds (BATS runner)' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -l test-timeout -d 'Default timeout per test in seconds (default: global.timeout from .cli-test-config.yml, or 30)' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s s -l skip -d 'Skip specific test categories (comma-separated)' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s r -l runner -d 'Test runner (bats: execute .bats files, native: run tests from analysis JSON without bash/BATS)' -r -f -a "bats\t'BATS (requires bash and bats-core)'
+native\t'Native Rust runner (executes generated test cases directly)'"
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s j -l jobs -d 'Number of concurrent workers (BATS: suites, native: tests)' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -l history-dir -d 'Directory holding per-binary performance history' -r -F
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -l baseline -d 'Compare performance against the latest run of this binary version (default: previous run)' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -l fail-on-regression -d 'Exit non-zero when mean startup time rises significantly by at least PERCENT' -r
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s v -l verbose -d 'Enable verbose output'
+complete -c cli-test -n "__fish_cli_test_using_subcommand run" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c cli-test -n "__fish_cli_test_using_subcommand validate" -s v -l verbose -d 'Enable verbose output'
+complete -c cli-test -n "__fish_cli_test_using_subcommand validate" -s h -l help -d 'Print help'
+complete -c cli-test -n "__fish_cli_test_using_subcommand completion" -s v -l verbose -d 'Enable verbose output'
+complete -c cli-test -n "__fish_cli_test_using_subcommand completion" -s h -l help -d 'Print help'
+complete -c cli-test -n "__fish_cli_test_using_subcommand help; and not __fish_seen_subcommand_from analyze generate run validate completion help" -f -a "analyze" -d 'Analyze CLI tool structure and options'
+complete -c cli-test -n "__fish_cli_test_using_subcommand help; and not __fish_seen_subcommand_from analyze generate run validate completion help" -f -a "generate" -d 'Generate test suites from analysis (BATS, assert_cmd, or snapbox)'
+complete -c cli-test -n "__fish_cli_test_using_subcommand help; and not __fish_seen_subcommand_from analyze generate run validate completion help" -f -a "run" -d 'Execute tests (BATS or native runner) and generate reports'
+complete -c cli-test -n "__fish_cli_test_using_subcommand help; and not __fish_seen_subcommand_from analyze generate run validate completion help" -f -a "validate" -d 'Validate analysis JSON file structure'
+complete -c cli-test -n "__fish_cli_test_using_subcommand help; and not __fish_seen_subcommand_from analyze generate run validate completion help" -f -a "completion" -d 'Generate shell completion scripts'
+complete -c cli-test -n "__fish_cli_test_using_subcommand help; and not __fish_seen_subcommand_from analyze generate run validate completion help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
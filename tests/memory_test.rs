@@ -1,7 +1,7 @@
 use cli_testing_specialist::analyzer::option_inferrer::{
     apply_numeric_constraints, load_enum_values, OptionInferrer,
 };
-use cli_testing_specialist::types::analysis::{CliOption, OptionType};
+use cli_testing_specialist::types::analysis::{CliOption, OptionSource, OptionType};
 
 #[test]
 fn test_yaml_config_memory_impact() {
@@ -28,6 +28,8 @@ fn test_yaml_config_memory_impact() {
         },
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     }];
     apply_numeric_constraints(&mut options);
     assert_eq!(
@@ -49,6 +51,8 @@ fn test_yaml_config_memory_impact() {
         option_type: OptionType::Enum { values: vec![] },
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     }];
     load_enum_values(&mut enum_options);
     if let OptionType::Enum { ref values } = enum_options[0].option_type {
@@ -92,6 +96,8 @@ fn test_yaml_config_caching() {
         },
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     }];
     apply_numeric_constraints(&mut options1);
 
@@ -108,6 +114,8 @@ fn test_yaml_config_caching() {
         },
         required: false,
         default_value: None,
+        env_var: None,
+        source: OptionSource::Help,
     }];
     apply_numeric_constraints(&mut options2);
 